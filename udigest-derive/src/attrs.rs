@@ -4,21 +4,37 @@ pub mod kw {
     syn::custom_keyword!(root);
     syn::custom_keyword!(tag);
     syn::custom_keyword!(as_bytes);
+    syn::custom_keyword!(as_bytes_iter);
     syn::custom_keyword!(bound);
     syn::custom_keyword!(skip);
     syn::custom_keyword!(rename);
     syn::custom_keyword!(with);
+    syn::custom_keyword!(untagged);
+    syn::custom_keyword!(rename_fields);
+    syn::custom_keyword!(compat);
+    syn::custom_keyword!(test_vector);
+    syn::custom_keyword!(value);
+    syn::custom_keyword!(sha256);
+    syn::custom_keyword!(expected);
+    syn::custom_keyword!(hash);
+    syn::custom_keyword!(from);
 }
 
 pub enum Attr {
     Root(Root),
     Tag(Tag),
     AsBytes(AsBytes),
+    AsBytesIter(AsBytesIter),
     Bound(Bound),
     Skip(Skip),
     Rename(Rename),
     With(With),
     As(As),
+    Untagged(Untagged),
+    RenameFields(RenameFields),
+    Compat(Compat),
+    TestVector(TestVector),
+    From(From),
 }
 
 impl Attr {
@@ -27,11 +43,17 @@ impl Attr {
             Attr::Root(attr) => attr.root.span,
             Attr::Tag(attr) => attr.tag.span,
             Attr::AsBytes(attr) => attr.as_bytes.span,
+            Attr::AsBytesIter(attr) => attr.as_bytes_iter.span,
             Attr::Bound(attr) => attr.bound.span,
             Attr::Skip(attr) => attr.skip.span,
             Attr::Rename(attr) => attr.rename.span,
             Attr::With(attr) => attr.with.span,
             Attr::As(attr) => attr.as_.span,
+            Attr::Untagged(attr) => attr.untagged.span,
+            Attr::RenameFields(attr) => attr.rename_fields.span,
+            Attr::Compat(attr) => attr.compat.span,
+            Attr::TestVector(attr) => attr.test_vector.span,
+            Attr::From(attr) => attr.from.span,
         }
     }
 }
@@ -45,6 +67,8 @@ impl syn::parse::Parse for Attr {
             Tag::parse(input).map(Attr::Tag)
         } else if lookahead.peek(kw::as_bytes) {
             AsBytes::parse(input).map(Attr::AsBytes)
+        } else if lookahead.peek(kw::as_bytes_iter) {
+            AsBytesIter::parse(input).map(Attr::AsBytesIter)
         } else if lookahead.peek(kw::bound) {
             Bound::parse(input).map(Attr::Bound)
         } else if lookahead.peek(kw::skip) {
@@ -55,6 +79,16 @@ impl syn::parse::Parse for Attr {
             With::parse(input).map(Attr::With)
         } else if lookahead.peek(syn::Token![as]) {
             As::parse(input).map(Attr::As)
+        } else if lookahead.peek(kw::untagged) {
+            Untagged::parse(input).map(Attr::Untagged)
+        } else if lookahead.peek(kw::rename_fields) {
+            RenameFields::parse(input).map(Attr::RenameFields)
+        } else if lookahead.peek(kw::compat) {
+            Compat::parse(input).map(Attr::Compat)
+        } else if lookahead.peek(kw::test_vector) {
+            TestVector::parse(input).map(Attr::TestVector)
+        } else if lookahead.peek(kw::from) {
+            From::parse(input).map(Attr::From)
         } else {
             Err(lookahead.error())
         }
@@ -121,6 +155,26 @@ impl syn::parse::Parse for AsBytes {
     }
 }
 
+pub struct AsBytesIter {
+    pub as_bytes_iter: kw::as_bytes_iter,
+    pub _eq: syn::Token![=],
+    pub value: syn::Expr,
+}
+
+impl syn::parse::Parse for AsBytesIter {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let as_bytes_iter = input.parse()?;
+        let _eq = input.parse()?;
+        let value = input.parse()?;
+
+        Ok(Self {
+            as_bytes_iter,
+            _eq,
+            value,
+        })
+    }
+}
+
 pub struct Bound {
     pub bound: kw::bound,
     pub _eq: syn::Token![=],
@@ -193,3 +247,148 @@ impl syn::parse::Parse for As {
         Ok(Self { as_, _eq, value })
     }
 }
+
+pub struct Untagged {
+    pub untagged: kw::untagged,
+}
+
+impl syn::parse::Parse for Untagged {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let untagged = input.parse()?;
+        Ok(Self { untagged })
+    }
+}
+
+pub struct RenameFields {
+    pub rename_fields: kw::rename_fields,
+    pub _eq: syn::Token![=],
+    pub _bracket: syn::token::Bracket,
+    pub names: syn::punctuated::Punctuated<syn::Expr, syn::Token![,]>,
+}
+
+impl syn::parse::Parse for RenameFields {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let rename_fields = input.parse()?;
+        let _eq = input.parse()?;
+        let content;
+        let _bracket = syn::bracketed!(content in input);
+        let names = syn::punctuated::Punctuated::parse_terminated(&content)?;
+        Ok(Self {
+            rename_fields,
+            _eq,
+            _bracket,
+            names,
+        })
+    }
+}
+
+pub struct Compat {
+    pub compat: kw::compat,
+    pub _eq: syn::Token![=],
+    pub value: syn::LitStr,
+}
+
+impl syn::parse::Parse for Compat {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let compat = input.parse()?;
+        let _eq = input.parse()?;
+        let value = input.parse()?;
+        Ok(Self { compat, _eq, value })
+    }
+}
+
+pub struct TestVector {
+    pub test_vector: kw::test_vector,
+    pub _paren: syn::token::Paren,
+    /// Expression that evaluates to the value being digested
+    pub value: syn::LitStr,
+    /// Expected hash in hex
+    pub expected: syn::LitStr,
+    /// Set if expected hash was specified via deprecated `sha256` key
+    pub deprecated_sha256: Option<kw::sha256>,
+    /// Path to the hash function, `sha2::Sha256` if not specified
+    pub hash: Option<syn::Path>,
+}
+
+impl syn::parse::Parse for TestVector {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let test_vector = input.parse::<kw::test_vector>()?;
+        let content;
+        let _paren = syn::parenthesized!(content in input);
+
+        let mut value = None;
+        let mut expected = None;
+        let mut deprecated_sha256 = None;
+        let mut hash = None;
+        while !content.is_empty() {
+            let lookahead = content.lookahead1();
+            if lookahead.peek(kw::value) {
+                let kw = content.parse::<kw::value>()?;
+                if value.is_some() {
+                    return Err(syn::Error::new(kw.span, "`value` is duplicated"));
+                }
+                content.parse::<syn::Token![=]>()?;
+                value = Some(content.parse()?);
+            } else if lookahead.peek(kw::expected) {
+                let kw = content.parse::<kw::expected>()?;
+                if expected.is_some() {
+                    return Err(syn::Error::new(kw.span, "`expected` is duplicated"));
+                }
+                content.parse::<syn::Token![=]>()?;
+                expected = Some(content.parse()?);
+            } else if lookahead.peek(kw::sha256) {
+                // deprecated alias to `expected`
+                let kw = content.parse::<kw::sha256>()?;
+                if expected.is_some() {
+                    return Err(syn::Error::new(kw.span, "`expected` is duplicated"));
+                }
+                content.parse::<syn::Token![=]>()?;
+                expected = Some(content.parse()?);
+                deprecated_sha256 = Some(kw);
+            } else if lookahead.peek(kw::hash) {
+                let kw = content.parse::<kw::hash>()?;
+                if hash.is_some() {
+                    return Err(syn::Error::new(kw.span, "`hash` is duplicated"));
+                }
+                content.parse::<syn::Token![=]>()?;
+                hash = Some(content.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<syn::Token![,]>()?;
+        }
+
+        let value =
+            value.ok_or_else(|| syn::Error::new(test_vector.span, "`value` must be specified"))?;
+        let expected = expected
+            .ok_or_else(|| syn::Error::new(test_vector.span, "`expected` must be specified"))?;
+
+        Ok(Self {
+            test_vector,
+            _paren,
+            value,
+            expected,
+            deprecated_sha256,
+            hash,
+        })
+    }
+}
+
+pub struct From {
+    pub from: kw::from,
+    pub _eq: syn::Token![=],
+    pub value: syn::Type,
+}
+
+impl syn::parse::Parse for From {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let from = input.parse()?;
+        let _eq = input.parse()?;
+        let value = input.parse()?;
+        Ok(Self { from, _eq, value })
+    }
+}
@@ -22,10 +22,7 @@ fn digestable_inner(input: syn::DeriveInput) -> Result<proc_macro2::TokenStream>
     let mut container_attrs = ContainerAttrs::default();
 
     // Parse container-level attributes
-    for attr in input.attrs {
-        let Some(attr) = parse_attribute(&attr)? else {
-            continue;
-        };
+    for attr in parse_attributes(&input.attrs)? {
         match attr {
             attrs::Attr::Root(_) if container_attrs.root.is_some() => {
                 return Err(Error::new(attr.kw_span(), "attribute is duplicated"))
@@ -45,15 +42,246 @@ fn digestable_inner(input: syn::DeriveInput) -> Result<proc_macro2::TokenStream>
             attrs::Attr::Bound(attr) => {
                 container_attrs.bound = Some(attr);
             }
+            attrs::Attr::With(_) if container_attrs.with.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"));
+            }
+            attrs::Attr::AsBytes(_) if container_attrs.as_bytes.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"));
+            }
+            attrs::Attr::From(_) if container_attrs.from.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"));
+            }
+            attrs::Attr::With(_) | attrs::Attr::AsBytes(_) | attrs::Attr::From(_)
+                if container_attrs.is_encoding_overridden() =>
+            {
+                return Err(Error::new(
+                    attr.kw_span(),
+                    "attributes `with`, `as_bytes` and `from` cannot be used together",
+                ));
+            }
+            attrs::Attr::With(attr) => {
+                container_attrs.with = Some(attr);
+            }
+            attrs::Attr::AsBytes(attr) => {
+                container_attrs.as_bytes = Some(attr);
+            }
+            attrs::Attr::From(attr) => {
+                container_attrs.from = Some(attr);
+            }
+            attrs::Attr::Untagged(_) if container_attrs.untagged.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"));
+            }
+            attrs::Attr::Untagged(attr) => {
+                container_attrs.untagged = Some(attr);
+            }
+            attrs::Attr::RenameFields(_) if container_attrs.rename_fields.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"));
+            }
+            attrs::Attr::RenameFields(attr) => {
+                container_attrs.rename_fields = Some(attr);
+            }
+            attrs::Attr::Compat(_) if container_attrs.compat.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"));
+            }
+            attrs::Attr::TestVector(attr) => {
+                container_attrs.test_vectors.push(attr);
+            }
+            attrs::Attr::Compat(attr) => {
+                if attr.value.value() != "0.2" {
+                    return Err(Error::new(
+                        attr.value.span(),
+                        "unsupported compatibility version, supported versions: \"0.2\"",
+                    ));
+                }
+                container_attrs.compat = Some(attr);
+            }
             _ => return Err(Error::new(attr.kw_span(), "attribute is not allowed here")),
         }
     }
 
-    match input.data {
-        syn::Data::Struct(s) => process_struct(&container_attrs, &input.ident, &input.generics, &s),
-        syn::Data::Enum(e) => process_enum(&container_attrs, &input.ident, &input.generics, &e),
-        syn::Data::Union(u) => Err(Error::new(u.union_token.span, "unions are not supported")),
+    if let Some(untagged) = &container_attrs.untagged {
+        if !matches!(input.data, syn::Data::Enum(_)) {
+            return Err(Error::new(
+                untagged.untagged.span,
+                "attribute `untagged` is only allowed for enums",
+            ));
+        }
+        if container_attrs.tag.is_some() || container_attrs.is_encoding_overridden() {
+            return Err(Error::new(
+                untagged.untagged.span,
+                "attribute `untagged` cannot be used together with `tag`, `with`, `as_bytes` or `from`",
+            ));
+        }
+    }
+
+    if let (Some(with), Some(_)) = (&container_attrs.with, &container_attrs.tag) {
+        return Err(Error::new(
+            with.with.span,
+            "attribute `with` cannot be used together with `tag`",
+        ));
+    }
+    if let (Some(from), Some(_)) = (&container_attrs.from, &container_attrs.tag) {
+        return Err(Error::new(
+            from.from.span,
+            "attribute `from` cannot be used together with `tag`",
+        ));
+    }
+
+    if let Some(compat) = &container_attrs.compat {
+        // v0.2 derive could not produce encoding specified by these attributes
+        if container_attrs.untagged.is_some()
+            || container_attrs.rename_fields.is_some()
+            || container_attrs.is_encoding_overridden()
+        {
+            return Err(Error::new(
+                compat.compat.span,
+                "attribute `compat` cannot be used together with `untagged`, `with`, `as_bytes`, `from` or `rename_fields`",
+            ));
+        }
+    }
+
+    if let Some(rename_fields) = &container_attrs.rename_fields {
+        if !matches!(input.data, syn::Data::Struct(_)) {
+            return Err(Error::new(
+                rename_fields.rename_fields.span,
+                "attribute `rename_fields` is only allowed for tuple structs",
+            ));
+        }
+    }
+
+    let test_vectors = generate_test_vectors(&container_attrs, &input.ident, &input.generics)?;
+
+    let digestable_impl = if container_attrs.is_encoding_overridden() {
+        generate_impl_for_container_override(&container_attrs, &input.ident, &input.generics)?
+    } else {
+        match input.data {
+            syn::Data::Struct(s) => {
+                process_struct(&container_attrs, &input.ident, &input.generics, &s)?
+            }
+            syn::Data::Enum(e) => {
+                process_enum(&container_attrs, &input.ident, &input.generics, &e)?
+            }
+            syn::Data::Union(u) => return Err(Error::new(
+                u.union_token.span,
+                "unions are only supported with `#[udigest(with = ...)]` or `#[udigest(as_bytes)]` attribute",
+            )),
+        }
+    };
+
+    Ok(quote! {
+        #digestable_impl
+        #test_vectors
+    })
+}
+
+/// Generates a test per each `test_vector` attribute
+///
+/// Each test constructs a value, digests it using specified hash function (sha2-256 by default),
+/// and asserts that hash matches the expected one, which must be as long as the hash output. Since `value` expression may refer to `Self`, it's evaluated within a hidden
+/// associated function.
+fn generate_test_vectors(
+    attrs: &ContainerAttrs,
+    name: &syn::Ident,
+    generics: &syn::Generics,
+) -> Result<proc_macro2::TokenStream> {
+    let Some(first) = attrs.test_vectors.first() else {
+        return Ok(quote! {});
+    };
+    if !generics.params.is_empty() {
+        return Err(Error::new(
+            first.test_vector.span,
+            "attribute `test_vector` is not supported for generic types",
+        ));
+    }
+
+    let root_path = attrs.get_root_path();
+    let tests = attrs
+        .test_vectors
+        .iter()
+        .enumerate()
+        .map(|(i, test_vector)| {
+            let value: proc_macro2::TokenStream = test_vector
+                .value
+                .value()
+                .parse()
+                .map_err(|err| Error::new(test_vector.value.span(), err))?;
+            let expected = decode_hex(&test_vector.expected)?;
+            let hash = match &test_vector.hash {
+                Some(hash) => quote! { #hash },
+                None => quote! { ::sha2::Sha256 },
+            };
+            // Proc macros can't emit warnings, so we make the generated code use a deprecated item
+            let deprecation = test_vector.deprecated_sha256.map(|kw| {
+                quote_spanned! {kw.span =>
+                    #[cfg(test)]
+                    const _: () = {
+                        #[deprecated(note = "`sha256` key of `test_vector` attribute is renamed to `expected`")]
+                        #[allow(non_upper_case_globals)]
+                        const sha256: () = ();
+                        sha256
+                    };
+                }
+            });
+
+            let value_fn = syn::Ident::new(
+                &format!("__udigest_test_vector_value_{i}"),
+                test_vector.test_vector.span,
+            );
+            let test_fn = syn::Ident::new(
+                &format!("udigest_test_vector_{name}_{i}"),
+                test_vector.test_vector.span,
+            );
+            let message = format!("digest of `{name}` doesn't match test vector #{i}");
+            let len_message = format!(
+                "test vector #{i} of `{name}` has {} bytes, but hash function outputs different number of bytes",
+                expected.len()
+            );
+
+            Ok(quote_spanned! {test_vector.test_vector.span =>
+                #deprecation
+
+                #[cfg(test)]
+                impl #name {
+                    #[doc(hidden)]
+                    fn #value_fn() -> Self {
+                        #value
+                    }
+                }
+
+                #[cfg(test)]
+                #[test]
+                #[allow(non_snake_case)]
+                fn #test_fn() {
+                    let value = #name::#value_fn();
+                    let hash = #root_path::hash::<#hash>(&value);
+                    let expected: &[u8] = &[#(#expected),*];
+                    assert_eq!(hash.len(), expected.len(), #len_message);
+                    assert_eq!(hash.as_slice(), expected, #message);
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! { #(#tests)* })
+}
+
+/// Decodes hex-encoded hash
+fn decode_hex(hex: &syn::LitStr) -> Result<Vec<u8>> {
+    let invalid = || Error::new(hex.span(), "expected hash encoded in hex");
+    let hex = hex.value();
+    if hex.is_empty() {
+        return Err(invalid());
     }
+    hex.as_bytes()
+        .chunks(2)
+        .map(|byte| {
+            if byte.len() != 2 || !byte.iter().all(u8::is_ascii_hexdigit) {
+                return Err(invalid());
+            }
+            let byte = core::str::from_utf8(byte).map_err(|_| invalid())?;
+            u8::from_str_radix(byte, 16).map_err(|_| invalid())
+        })
+        .collect()
 }
 
 fn process_enum(
@@ -65,40 +293,118 @@ fn process_enum(
     let variants = e
         .variants
         .iter()
-        .map(|v| {
-            Ok(Variant {
-                name: v.ident.clone(),
-                ty: match &v.fields {
-                    syn::Fields::Named(_) => VariantType::Named,
-                    syn::Fields::Unnamed(_) => VariantType::Unnamed,
-                    syn::Fields::Unit => VariantType::Unit,
-                },
-                fields: (0..)
-                    .zip(v.fields.iter())
-                    .map(|(i, f)| process_field(&attrs.get_root_path(), i, f))
-                    .collect::<Result<Vec<_>>>()?,
-            })
-        })
+        .map(|v| process_variant(attrs, v))
         .collect::<Result<Vec<_>>>()?;
 
     generate_impl_for_enum(attrs, name, generics, &variants)
 }
 
+fn process_variant(container_attrs: &ContainerAttrs, variant: &syn::Variant) -> Result<Variant> {
+    let mut variant_attrs = VariantAttrs::default();
+
+    let attrs = parse_attributes(&variant.attrs)?;
+    if let (Some(attr), Some(_)) = (attrs.first(), &container_attrs.compat) {
+        // v0.2 derive silently ignored variant attributes, so they would have no effect
+        return Err(Error::new(
+            attr.kw_span(),
+            "variant attributes cannot be used together with `compat` attribute",
+        ));
+    }
+
+    for attr in attrs {
+        match attr {
+            attrs::Attr::With(_) if variant_attrs.with.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"))
+            }
+            attrs::Attr::With(attr) => {
+                variant_attrs.with = Some(attr);
+            }
+            _ => return Err(Error::new(attr.kw_span(), "attribute is not allowed here")),
+        }
+    }
+
+    if variant_attrs.with.is_some() {
+        // Fields are encoded by the user function, so field-level attributes would be ignored
+        let field_attr = variant
+            .fields
+            .iter()
+            .flat_map(|f| &f.attrs)
+            .find(|attr| attr.path().is_ident("udigest"));
+        if let Some(field_attr) = field_attr {
+            return Err(Error::new(
+                field_attr.span(),
+                "field attributes cannot be used in a variant with `with` attribute",
+            ));
+        }
+    }
+
+    Ok(Variant {
+        name: variant.ident.clone(),
+        attrs: variant_attrs,
+        ty: match &variant.fields {
+            syn::Fields::Named(_) => VariantType::Named,
+            syn::Fields::Unnamed(_) => VariantType::Unnamed,
+            syn::Fields::Unit => VariantType::Unit,
+        },
+        fields: (0..)
+            .zip(variant.fields.iter())
+            .map(|(i, f)| process_field(container_attrs, i, f))
+            .collect::<Result<Vec<_>>>()?,
+    })
+}
+
 fn process_struct(
     container_attrs: &ContainerAttrs,
     name: &syn::Ident,
     generics: &syn::Generics,
     s: &syn::DataStruct,
 ) -> Result<proc_macro2::TokenStream> {
-    let struct_fields = (0..)
+    let mut struct_fields = (0..)
         .zip(s.fields.iter())
-        .map(|(i, f)| process_field(&container_attrs.get_root_path(), i, f))
+        .map(|(i, f)| process_field(container_attrs, i, f))
         .collect::<Result<Vec<_>>>()?;
 
+    if let Some(rename_fields) = &container_attrs.rename_fields {
+        if !matches!(s.fields, syn::Fields::Unnamed(_)) {
+            return Err(Error::new(
+                rename_fields.rename_fields.span,
+                "attribute `rename_fields` is only allowed for tuple structs",
+            ));
+        }
+        if rename_fields.names.len() != struct_fields.len() {
+            return Err(Error::new(
+                rename_fields._bracket.span.join(),
+                format!(
+                    "expected {} names (one per each field), found {}",
+                    struct_fields.len(),
+                    rename_fields.names.len()
+                ),
+            ));
+        }
+        for (field, name) in struct_fields.iter_mut().zip(&rename_fields.names) {
+            if let Some(rename) = &field.attrs.rename {
+                return Err(Error::new(
+                    rename.rename.span,
+                    "field is already renamed via `rename_fields` container attribute",
+                ));
+            }
+            field.attrs.rename = Some(attrs::Rename {
+                rename: attrs::kw::rename(name.span()),
+                _eq: Default::default(),
+                value: name.clone(),
+            });
+        }
+    }
+
     generate_impl_for_struct(container_attrs, name, generics, &struct_fields)
 }
 
-fn process_field(root_path: &attrs::RootPath, index: u32, field: &syn::Field) -> Result<Field> {
+fn process_field(
+    container_attrs: &ContainerAttrs,
+    index: u32,
+    field: &syn::Field,
+) -> Result<Field> {
+    let root_path = &container_attrs.get_root_path();
     // same_ty = <root_path>::as_::Same
     let same_ty = {
         let mut root = root_path.clone();
@@ -134,14 +440,23 @@ fn process_field(root_path: &attrs::RootPath, index: u32, field: &syn::Field) ->
             .into()
         });
 
-    for attr in &field.attrs {
-        let Some(attr) = parse_attribute(attr)? else {
-            continue;
-        };
+    for attr in parse_attributes(&field.attrs)? {
         match attr {
+            // v0.2 derive could not produce encoding specified by these attributes
+            attrs::Attr::AsBytesIter(_) | attrs::Attr::From(_)
+                if container_attrs.compat.is_some() =>
+            {
+                return Err(Error::new(
+                    attr.kw_span(),
+                    "attributes `as_bytes_iter` and `from` cannot be used together with `compat` attribute",
+                ));
+            }
             attrs::Attr::AsBytes(_) if field_attrs.as_bytes.is_some() => {
                 return Err(Error::new(attr.kw_span(), "attribute is duplicated"))
             }
+            attrs::Attr::AsBytesIter(_) if field_attrs.as_bytes_iter.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"))
+            }
             attrs::Attr::With(_) if field_attrs.with.is_some() => {
                 return Err(Error::new(attr.kw_span(), "attribute is duplicated"))
             }
@@ -154,25 +469,38 @@ fn process_field(root_path: &attrs::RootPath, index: u32, field: &syn::Field) ->
             attrs::Attr::As(_) if field_attrs.as_.is_some() => {
                 return Err(Error::new(attr.kw_span(), "attribute is duplicated"))
             }
+            attrs::Attr::From(_) if field_attrs.from.is_some() => {
+                return Err(Error::new(attr.kw_span(), "attribute is duplicated"))
+            }
             attrs::Attr::AsBytes(_)
+            | attrs::Attr::AsBytesIter(_)
             | attrs::Attr::With(_)
             | attrs::Attr::As(_)
+            | attrs::Attr::From(_)
             | attrs::Attr::Skip(_)
                 if count_trues([
                     field_attrs.as_bytes.is_some(),
+                    field_attrs.as_bytes_iter.is_some(),
                     field_attrs.with.is_some(),
                     field_attrs.as_.is_some(),
+                    field_attrs.from.is_some(),
                     field_attrs.skip.is_some(),
                 ]) > 0 =>
             {
                 return Err(Error::new(
                     attr.kw_span(),
-                    "attributes `with`, `as_bytes`, `as` and 'skip` cannot be used together",
+                    "attributes `with`, `as_bytes`, `as_bytes_iter`, `as`, `from` and 'skip` cannot be used together",
                 ));
             }
+            attrs::Attr::From(attr) => {
+                field_attrs.from = Some(attr);
+            }
             attrs::Attr::AsBytes(attr) => {
                 field_attrs.as_bytes = Some(attr);
             }
+            attrs::Attr::AsBytesIter(attr) => {
+                field_attrs.as_bytes_iter = Some(attr);
+            }
             attrs::Attr::With(attr) => {
                 field_attrs.with = Some(attr);
             }
@@ -366,6 +694,15 @@ fn generate_impl_for_enum(
         }
     });
 
+    let start_enum = if attrs.untagged.is_some() {
+        quote! { let #encoder_var = encoder; }
+    } else {
+        quote! {
+            let mut #encoder_var = encoder.encode_enum();
+            #specify_tag
+        }
+    };
+
     let match_expr = if !enum_variants.is_empty() {
         let match_branches = enum_variants.iter().map(|v| {
             let variant_name = &v.name;
@@ -391,6 +728,45 @@ fn generate_impl_for_enum(
                 }
             };
 
+            let variant_name_str = variant_name.to_string();
+
+            // Untagged variant is encoded as a struct without a variant name
+            let start_variant = if attrs.untagged.is_some() {
+                quote! { #encoder_var.encode_struct() }
+            } else {
+                quote! { #encoder_var.with_variant(#variant_name_str) }
+            };
+
+            if let Some(attrs::With { value: func, .. }) = &v.attrs.with {
+                return quote_spanned! {func.span() =>
+                    #enum_name::#variant_name #pattern => {
+                        let #encoder_var = #start_variant;
+                        #func(#(#field_bindings,)* #encoder_var);
+                    }
+                };
+            }
+
+            if let (Some(_), VariantType::Unnamed, [field]) =
+                (&attrs.untagged, &v.ty, v.fields.as_slice())
+            {
+                if field.attrs.skip.is_none() {
+                    // Untagged newtype variant is encoded exactly as its inner value
+                    let encode_value = encode_value(
+                        &root_path,
+                        &encoder_var,
+                        &field.attrs,
+                        field.span,
+                        &field.ty,
+                        &field_bindings[0],
+                    );
+                    return quote_spanned! {variant_name.span() =>
+                        #enum_name::#variant_name #pattern => {
+                            let _ = { #encode_value };
+                        }
+                    };
+                }
+            }
+
             let encode_fields = field_bindings.iter().zip(&v.fields).map(|(binding, f)| {
                 encode_field(
                     &root_path,
@@ -403,10 +779,9 @@ fn generate_impl_for_enum(
                 )
             });
 
-            let variant_name_str = variant_name.to_string();
             quote_spanned! {variant_name.span() =>
                 #enum_name::#variant_name #pattern => {
-                    let mut #encoder_var = #encoder_var.with_variant(#variant_name_str);
+                    let mut #encoder_var = #start_variant;
                     #(#encode_fields)*
                 }
             }
@@ -428,8 +803,7 @@ fn generate_impl_for_enum(
             where
                 B: #root_path::Buffer
             {
-                let mut #encoder_var = encoder.encode_enum();
-                #specify_tag
+                #start_enum
                 #match_expr
             }
         }
@@ -484,24 +858,113 @@ fn generate_impl_for_struct(
     })
 }
 
-fn parse_attribute(attr: &syn::Attribute) -> Result<Option<attrs::Attr>> {
-    let attr_tokens = match &attr.meta {
-        syn::Meta::List(meta) if meta.path.is_ident("udigest") => &meta.tokens,
-        syn::Meta::Path(path) if path.is_ident("udigest") => {
-            return Err(Error::new(
-                path.span(),
-                "empty attribute doesn't make sense",
-            ))
+/// Generates an implementation for the container which encoding is fully specified
+/// via `with`, `as_bytes`, or `from` container attribute
+///
+/// The data type is not traversed, so it works for unions as well as for structs and enums
+fn generate_impl_for_container_override(
+    attrs: &ContainerAttrs,
+    name: &syn::Ident,
+    generics: &syn::Generics,
+) -> Result<proc_macro2::TokenStream> {
+    let root_path = attrs.get_root_path();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let where_clause = make_where_clause(attrs, generics)?;
+
+    let specify_tag = attrs.tag.as_ref().map(|attrs::Tag { value, .. }| {
+        quote_spanned! {value.span() =>
+            let tag = #value;
+            let tag = AsRef::<[u8]>::as_ref(&tag);
+            let leaf = leaf.with_tag(tag);
         }
-        syn::Meta::NameValue(meta) if meta.path.is_ident("udigest") => {
+    });
+
+    let encode = match (&attrs.with, &attrs.as_bytes, &attrs.from) {
+        (Some(attrs::With { value: func, .. }), None, None) => quote_spanned! {func.span() =>
+            #func(self, encoder);
+        },
+        (None, None, Some(attrs::From { value: ty, .. })) => quote_spanned! {ty.span() =>
+            #[allow(clippy::clone_on_copy)]
+            let converted = <#ty as From<Self>>::from(Clone::clone(self));
+            #root_path::Digestable::unambiguously_encode(&converted, encoder);
+        },
+        (
+            None,
+            Some(attrs::AsBytes {
+                value, as_bytes, ..
+            }),
+            None,
+        ) => {
+            let bytes = match value {
+                Some(func) => quote_spanned! {func.span() =>
+                    let bytes = #func(self);
+                    let bytes = AsRef::<[u8]>::as_ref(&bytes);
+                },
+                None => quote_spanned! {as_bytes.span =>
+                    let bytes: &[u8] = AsRef::<[u8]>::as_ref(self);
+                },
+            };
+            quote! {
+                #bytes
+                let leaf = encoder.encode_leaf();
+                #specify_tag
+                leaf.chain(bytes).finish();
+            }
+        }
+        _ => unreachable!(
+            "it should have been validated that exactly one of `with`, `as_bytes`, `from` is used"
+        ),
+    };
+
+    Ok(quote! {
+        impl #impl_generics #root_path::Digestable for #name #ty_generics #where_clause {
+            fn unambiguously_encode<B>(&self, encoder: #root_path::encoding::EncodeValue<B>)
+            where
+                B: #root_path::Buffer
+            {
+                #encode
+            }
+        }
+    })
+}
+
+/// Parses all `#[udigest(...)]` attributes
+///
+/// Each attribute may contain several comma-separated items, e.g. `#[udigest(rename = "job", skip)]`.
+/// Items are returned in order they are specified. Attributes that are not `udigest` are ignored.
+fn parse_attributes(attrs: &[syn::Attribute]) -> Result<Vec<attrs::Attr>> {
+    let mut parsed = vec![];
+    for attr in attrs {
+        let attr_tokens = match &attr.meta {
+            syn::Meta::List(meta) if meta.path.is_ident("udigest") => &meta.tokens,
+            syn::Meta::Path(path) if path.is_ident("udigest") => {
+                return Err(Error::new(
+                    path.span(),
+                    "empty attribute doesn't make sense",
+                ))
+            }
+            syn::Meta::NameValue(meta) if meta.path.is_ident("udigest") => {
+                return Err(Error::new(
+                    meta.value.span(),
+                    "attribute needs to be specified in parentheses (e.g. `#[udigest(skip)]`)",
+                ));
+            }
+            _ => continue,
+        };
+        let items = syn::parse::Parser::parse2(
+            syn::punctuated::Punctuated::<attrs::Attr, syn::Token![,]>::parse_terminated,
+            attr_tokens.clone(),
+        )?;
+        if items.is_empty() {
             return Err(Error::new(
-                meta.value.span(),
-                "attribute needs to be specified in parentheses (e.g. `#[udigest(skip)]`)",
+                attr.span(),
+                "empty attribute doesn't make sense",
             ));
         }
-        _ => return Ok(None),
-    };
-    syn::parse2(attr_tokens.clone()).map(Some)
+        parsed.extend(items);
+    }
+    Ok(parsed)
 }
 
 /// Takes the generics defined for the data type, produces a where clause that should
@@ -574,36 +1037,92 @@ fn encode_field(
         Some(attrs::Rename { rename, value, .. }) => quote_spanned! { rename.span => #value },
     };
 
-    match (&field_attrs.as_bytes, &field_attrs.with, &field_attrs.as_) {
-        (Some(attr), None, None) => match &attr.value {
-            Some(func) => quote_spanned! {field_span => {
-                let field_encoder = #encoder_var.add_field(#field_name);
+    let field_encoder = syn::Ident::new("field_encoder", proc_macro2::Span::call_site());
+    let encode_value = encode_value(
+        root_path,
+        &field_encoder,
+        field_attrs,
+        field_span,
+        field_type,
+        field_ref,
+    );
+    quote_spanned! {field_span =>
+        #encoder_var.add_field_with(#field_name, |#field_encoder| { #encode_value });
+    }
+}
+
+/// Generates a code that encodes a field value into `value_encoder_var` which
+/// is `EncodeValue`
+///
+/// Field-level attributes that specify how to encode the value are respected, other
+/// attributes like `skip` or `rename` are ignored. Generated code is a sequence of
+/// statements followed by an expression evaluating to `Encoded` proof.
+fn encode_value(
+    root_path: &attrs::RootPath,
+    value_encoder_var: &syn::Ident,
+    field_attrs: &FieldAttrs,
+    field_span: proc_macro2::Span,
+    field_type: &syn::Type,
+    field_ref: &impl quote::ToTokens,
+) -> proc_macro2::TokenStream {
+    match (
+        &field_attrs.as_bytes,
+        &field_attrs.as_bytes_iter,
+        &field_attrs.with,
+        &field_attrs.as_,
+        &field_attrs.from,
+    ) {
+        (Some(attr), None, None, None, None) => match &attr.value {
+            Some(func) => quote_spanned! {field_span =>
                 let field_bytes = #func(#field_ref);
                 let field_bytes = AsRef::<[u8]>::as_ref(&field_bytes);
-                field_encoder.encode_leaf_value(field_bytes);
-            }},
-            None => quote_spanned!(field_span => {
-                let field_encoder = #encoder_var.add_field(#field_name);
+                #value_encoder_var.encode_leaf_with(|leaf| leaf.update(field_bytes))
+            },
+            None => quote_spanned! {field_span =>
                 let field_bytes: &[u8] = AsRef::<[u8]>::as_ref(#field_ref);
-                field_encoder.encode_leaf_value(field_bytes);
-            }),
+                #value_encoder_var.encode_leaf_with(|leaf| leaf.update(field_bytes))
+            },
+        },
+        (None, Some(attrs::AsBytesIter { value: func, .. }), None, None, None) => {
+            quote_spanned! {field_span =>
+                let field_chunks = #func(#field_ref);
+                #value_encoder_var.encode_leaf_with(|leaf| {
+                    for chunk in IntoIterator::into_iter(field_chunks) {
+                        leaf.update(AsRef::<[u8]>::as_ref(&chunk));
+                    }
+                })
+            }
+        }
+        (None, None, Some(attrs::With { value: func, .. }), None, None) => {
+            quote_spanned! {field_span =>
+                #[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
+                let encoded = #value_encoder_var.__encode_with(|value_encoder| {
+                    #func(#field_ref, value_encoder)
+                });
+                encoded
+            }
+        }
+        (None, None, None, Some(attrs::As { value: ty, .. }), None) => {
+            quote_spanned! {field_span =>
+                #[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
+                let encoded = #value_encoder_var.__encode_with(|value_encoder| {
+                    <#ty as #root_path::DigestAs<#field_type>>::digest_as(#field_ref, value_encoder)
+                });
+                encoded
+            }
+        }
+        (None, None, None, None, Some(attrs::From { value: ty, .. })) => {
+            quote_spanned! {field_span =>
+                #[allow(clippy::clone_on_copy)]
+                let converted = <#ty as From<#field_type>>::from(Clone::clone(#field_ref));
+                #value_encoder_var.encode(&converted)
+            }
+        }
+        (None, None, None, None, None) => quote_spanned! {field_span =>
+            #value_encoder_var.encode(#field_ref)
         },
-        (None, Some(attrs::With { value: func, .. }), None) => quote_spanned! {field_span => {
-            let field_encoder = #encoder_var.add_field(#field_name);
-            #[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
-            #func(#field_ref, field_encoder);
-        }},
-        (None, None, Some(attrs::As { value: ty, .. })) => quote_spanned! {field_span => {
-            let field_encoder = #encoder_var.add_field(#field_name);
-            #[allow(clippy::needless_borrow, clippy::needless_borrows_for_generic_args)]
-            <#ty as #root_path::DigestAs<#field_type>>::digest_as(#field_ref, field_encoder)
-        }},
-        (None, None, None) => quote_spanned! {field_span => {
-            let field_encoder = #encoder_var.add_field(#field_name);
-            #root_path::Digestable::unambiguously_encode(#field_ref, field_encoder);
-        }},
         _ => {
-            unreachable!("it should have been validated that `with`, `as_bytes`, `as` are not used in the same time")
+            unreachable!("it should have been validated that `with`, `as_bytes`, `as_bytes_iter`, `as`, `from` are not used in the same time")
         }
     }
 }
@@ -613,9 +1132,22 @@ struct ContainerAttrs {
     root: Option<attrs::Root>,
     tag: Option<attrs::Tag>,
     bound: Option<attrs::Bound>,
+    with: Option<attrs::With>,
+    as_bytes: Option<attrs::AsBytes>,
+    untagged: Option<attrs::Untagged>,
+    rename_fields: Option<attrs::RenameFields>,
+    compat: Option<attrs::Compat>,
+    test_vectors: Vec<attrs::TestVector>,
+    from: Option<attrs::From>,
 }
 
 impl ContainerAttrs {
+    /// Indicates that encoding of the container is specified via `with`, `as_bytes` or `from`
+    /// attribute, so the data type is not traversed
+    pub fn is_encoding_overridden(&self) -> bool {
+        self.with.is_some() || self.as_bytes.is_some() || self.from.is_some()
+    }
+
     pub fn get_root_path(&self) -> attrs::RootPath {
         self.root
             .as_ref()
@@ -631,10 +1163,12 @@ impl ContainerAttrs {
 #[derive(Default)]
 struct FieldAttrs {
     as_bytes: Option<attrs::AsBytes>,
+    as_bytes_iter: Option<attrs::AsBytesIter>,
     skip: Option<attrs::Skip>,
     rename: Option<attrs::Rename>,
     with: Option<attrs::With>,
     as_: Option<attrs::As>,
+    from: Option<attrs::From>,
 }
 
 struct Field {
@@ -653,8 +1187,14 @@ impl Field {
     }
 }
 
+#[derive(Default)]
+struct VariantAttrs {
+    with: Option<attrs::With>,
+}
+
 struct Variant {
     name: syn::Ident,
+    attrs: VariantAttrs,
     fields: Vec<Field>,
     ty: VariantType,
 }
@@ -88,6 +88,306 @@ where
     }
 }
 
+/// Digests any type `T` as a string produced by its [`Display`](core::fmt::Display) implementation
+///
+/// Encoding is the same as encoding of `value.to_string()`, however, it doesn't require an
+/// allocation: the string is written directly to the encoder. For instance, it can be used to
+/// digest a ULID in its canonical string form:
+/// ```rust
+/// # #[cfg(feature = "ulid")] {
+/// #[derive(udigest::Digestable)]
+/// pub struct Event {
+///     #[udigest(as = udigest::as_::Display)]
+///     id: ulid::Ulid,
+/// }
+/// # }
+/// ```
+///
+/// If `Display` implementation returns an error, the string written so far is digested as
+/// a leaf tagged with `udigest.display_error`, so it never collides with a successfully
+/// formatted string.
+pub struct Display;
+
+impl<T> DigestAs<T> for Display
+where
+    T: core::fmt::Display + ?Sized,
+{
+    fn digest_as<B: Buffer>(value: &T, encoder: encoding::EncodeValue<B>) {
+        struct Writer<'a, 'b, B: Buffer>(&'a mut encoding::EncodeLeaf<'b, B>);
+        impl<B: Buffer> core::fmt::Write for Writer<'_, '_, B> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.0.update(s.as_bytes());
+                Ok(())
+            }
+        }
+
+        let mut leaf = encoder.encode_leaf();
+        // writer never fails, an error can only be returned by `Display` impl
+        if core::fmt::write(&mut Writer(&mut leaf), format_args!("{value}")).is_err() {
+            leaf.set_tag(b"udigest.display_error");
+        }
+    }
+}
+
+/// Digests elliptic curve points via their SEC1 compressed encoding
+///
+/// Curve points (like `AffinePoint`) are defined as associated types of the curve, so
+/// [`Digestable`] trait can't be implemented for them generically. This rule works for
+/// any point of curve `C`, encoding is a bytestring containing SEC1 compressed point:
+/// ```rust
+/// # #[cfg(feature = "elliptic-curve")] {
+/// #[derive(udigest::Digestable)]
+/// pub struct Commitment {
+///     #[udigest(as = udigest::as_::Sec1<k256::Secp256k1>)]
+///     point: k256::AffinePoint,
+/// }
+/// # }
+/// ```
+///
+/// Encoding matches encoding of [`PublicKey<C>`](elliptic_curve::PublicKey) holding the
+/// same point.
+#[cfg(feature = "elliptic-curve")]
+pub struct Sec1<C>(core::marker::PhantomData<C>);
+
+#[cfg(feature = "elliptic-curve")]
+impl<C, T> DigestAs<T> for Sec1<C>
+where
+    C: elliptic_curve::Curve,
+    T: elliptic_curve::sec1::ToEncodedPoint<C>,
+    elliptic_curve::FieldBytesSize<C>: elliptic_curve::sec1::ModulusSize,
+{
+    fn digest_as<B: Buffer>(value: &T, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(value.to_encoded_point(true).as_bytes())
+    }
+}
+
+/// Digests prime field elements via their canonical byte representation
+///
+/// Scalars (like `Scalar`) are defined as associated types of the curve, so [`Digestable`]
+/// trait can't be implemented for them generically. This rule works for any type implementing
+/// [`PrimeField`](elliptic_curve::ff::PrimeField), encoding is a bytestring containing
+/// [`to_repr()`](elliptic_curve::ff::PrimeField::to_repr) of the element:
+/// ```rust
+/// # #[cfg(feature = "elliptic-curve")] {
+/// #[derive(udigest::Digestable)]
+/// pub struct Challenge {
+///     #[udigest(as = udigest::as_::FieldRepr)]
+///     scalar: k256::Scalar,
+/// }
+/// # }
+/// ```
+///
+/// For elliptic curve scalars, encoding matches encoding of
+/// [`NonZeroScalar<C>`](elliptic_curve::NonZeroScalar) holding the same value.
+#[cfg(feature = "elliptic-curve")]
+pub struct FieldRepr;
+
+#[cfg(feature = "elliptic-curve")]
+impl<T> DigestAs<T> for FieldRepr
+where
+    T: elliptic_curve::ff::PrimeField,
+{
+    fn digest_as<B: Buffer>(value: &T, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(value.to_repr())
+    }
+}
+
+/// Digests values via their DER encoding
+///
+/// This rule works for any type implementing [`der::Encode`], encoding is a bytestring
+/// containing the DER-encoded value. It's useful for ASN.1 types that do not implement
+/// [`Digestable`] trait:
+/// ```rust
+/// # #[cfg(feature = "der")] {
+/// #[derive(udigest::Digestable)]
+/// pub struct Record {
+///     #[udigest(as = udigest::as_::Der)]
+///     issued_at: der::asn1::GeneralizedTime,
+/// }
+/// # }
+/// ```
+///
+/// DER bytes are written directly to the buffer, no allocation is made. DER encoding of
+/// a well-formed value never fails. If it does, the value is digested as a leaf tagged with
+/// `udigest.der_error`, so it never collides with a successfully encoded value. The leaf
+/// is empty if length of the encoding can't be computed, otherwise it contains the bytes
+/// written before the failure.
+#[cfg(feature = "der")]
+pub struct Der;
+
+#[cfg(feature = "der")]
+impl<T: der::Encode> DigestAs<T> for Der {
+    fn digest_as<B: Buffer>(value: &T, encoder: encoding::EncodeValue<B>) {
+        struct Writer<'a, 'b, B: Buffer>(&'a mut encoding::EncodeLeaf<'b, B>);
+        impl<B: Buffer> der::Writer for Writer<'_, '_, B> {
+            fn write(&mut self, slice: &[u8]) -> der::Result<()> {
+                self.0.update(slice);
+                Ok(())
+            }
+        }
+
+        let mut leaf = encoder.encode_leaf();
+        if value.encoded_len().is_err() {
+            leaf.set_tag(b"udigest.der_error");
+            return;
+        }
+        // writer never fails, an error can only be returned by `Encode` impl
+        if value.encode(&mut Writer(&mut leaf)).is_err() {
+            leaf.set_tag(b"udigest.der_error");
+        }
+    }
+}
+
+/// Digests flags generated by [`bitflags!`](bitflags::bitflags) macro via their
+/// [`bits()`](bitflags::Flags::bits) value
+///
+/// Encoding is the same as encoding of the underlying integer (e.g. `u32`), so flags are
+/// digested exactly as if the field held `flags.bits()`:
+/// ```rust
+/// # #[cfg(feature = "bitflags")] {
+/// bitflags::bitflags! {
+///     pub struct Permissions: u8 {
+///         const READ = 0b001;
+///         const WRITE = 0b010;
+///         const EXECUTE = 0b100;
+///     }
+/// }
+///
+/// #[derive(udigest::Digestable)]
+/// pub struct File {
+///     name: String,
+///     #[udigest(as = udigest::as_::Bits)]
+///     permissions: Permissions,
+/// }
+/// # }
+/// ```
+///
+/// Unknown bits (if the flags type retains them) are digested as well.
+#[cfg(feature = "bitflags")]
+pub struct Bits;
+
+#[cfg(feature = "bitflags")]
+impl<T> DigestAs<T> for Bits
+where
+    T: bitflags::Flags,
+    T::Bits: Digestable,
+{
+    fn digest_as<B: Buffer>(value: &T, encoder: encoding::EncodeValue<B>) {
+        value.bits().unambiguously_encode(encoder)
+    }
+}
+
+/// Digests plain-old-data values via their raw byte representation
+///
+/// This rule works for any type implementing [`bytemuck::NoUninit`] (which includes all
+/// [`Pod`](bytemuck::Pod) types), encoding is a bytestring containing the value's bytes
+/// in memory. It's the fastest way to digest large arrays of numbers, as no per-element
+/// encoding is performed:
+/// ```rust
+/// # #[cfg(feature = "bytemuck")] {
+/// #[derive(udigest::Digestable)]
+/// pub struct Frame {
+///     #[udigest(as = udigest::as_::PodBytes)]
+///     pixels: [[u8; 4]; 64],
+/// }
+/// # }
+/// ```
+///
+/// Use [`PodSlice`] to digest `Vec<T>` and other containers of plain-old-data values.
+///
+/// Note that the raw byte representation of multi-byte numbers depends on the platform
+/// endianness, so the same value may have different encodings on different platforms.
+#[cfg(feature = "bytemuck")]
+pub struct PodBytes;
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::NoUninit> DigestAs<T> for PodBytes {
+    fn digest_as<B: Buffer>(value: &T, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(bytemuck::bytes_of(value))
+    }
+}
+
+/// Digests a container of plain-old-data values via raw byte representation of its elements
+///
+/// This rule works for any type that dereferences to a slice `[T]` where `T` implements
+/// [`bytemuck::NoUninit`], like `Vec<T>` or `Box<[T]>`. Encoding is a bytestring containing
+/// bytes of all elements, i.e. the same as encoding of an array `[T; N]` with the same
+/// elements digested via [`PodBytes`] rule:
+/// ```rust
+/// # #[cfg(feature = "bytemuck")] {
+/// #[derive(udigest::Digestable)]
+/// pub struct Samples {
+///     #[udigest(as = udigest::as_::PodSlice)]
+///     values: Vec<u32>,
+/// }
+/// # }
+/// ```
+///
+/// Similarly to [`PodBytes`], encoding depends on the platform endianness.
+#[cfg(feature = "bytemuck")]
+pub struct PodSlice;
+
+#[cfg(feature = "bytemuck")]
+impl<T, S> DigestAs<S> for PodSlice
+where
+    T: bytemuck::NoUninit,
+    S: core::ops::Deref<Target = [T]>,
+{
+    fn digest_as<B: Buffer>(value: &S, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(bytemuck::cast_slice::<T, u8>(value))
+    }
+}
+
+/// Digests values via their raw byte representation provided by [`zerocopy`]
+///
+/// This rule works for any type implementing [`zerocopy::IntoBytes`] and
+/// [`zerocopy::Immutable`], including slices `[T]`. Encoding is a bytestring containing
+/// the value's bytes in memory, which matches encoding of `PodBytes` and `PodSlice` rules:
+/// ```rust
+/// # #[cfg(feature = "zerocopy")] {
+/// #[derive(udigest::Digestable)]
+/// pub struct Samples {
+///     #[udigest(as = udigest::as_::ZerocopyBytes)]
+///     values: [u32; 16],
+/// }
+/// # }
+/// ```
+///
+/// Use [`ZerocopySlice`] to digest `Vec<T>` and other containers. Similarly to `PodBytes`,
+/// encoding depends on the platform endianness.
+#[cfg(feature = "zerocopy")]
+pub struct ZerocopyBytes;
+
+#[cfg(feature = "zerocopy")]
+impl<T> DigestAs<T> for ZerocopyBytes
+where
+    T: zerocopy::IntoBytes + zerocopy::Immutable + ?Sized,
+{
+    fn digest_as<B: Buffer>(value: &T, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(value.as_bytes())
+    }
+}
+
+/// Digests a container of values via raw byte representation of its elements provided
+/// by [`zerocopy`]
+///
+/// This rule works for any type that dereferences to a slice `[T]` where `T` implements
+/// [`zerocopy::IntoBytes`] and [`zerocopy::Immutable`], like `Vec<T>` or `Box<[T]>`.
+/// Encoding is the same as encoding of [`ZerocopyBytes`] applied to the slice.
+#[cfg(feature = "zerocopy")]
+pub struct ZerocopySlice;
+
+#[cfg(feature = "zerocopy")]
+impl<T, S> DigestAs<S> for ZerocopySlice
+where
+    T: zerocopy::IntoBytes + zerocopy::Immutable,
+    S: core::ops::Deref<Target = [T]>,
+{
+    fn digest_as<B: Buffer>(value: &S, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(zerocopy::IntoBytes::as_bytes(&**value))
+    }
+}
+
 pub use crate::Bytes;
 
 impl<T> DigestAs<T> for Bytes
@@ -125,6 +425,27 @@ where
     }
 }
 
+impl<B, BAs, C, CAs> DigestAs<core::ops::ControlFlow<B, C>> for core::ops::ControlFlow<BAs, CAs>
+where
+    BAs: DigestAs<B>,
+    CAs: DigestAs<C>,
+{
+    fn digest_as<Buf: Buffer>(
+        value: &core::ops::ControlFlow<B, C>,
+        encoder: encoding::EncodeValue<Buf>,
+    ) {
+        let value = match value {
+            core::ops::ControlFlow::Continue(value) => {
+                core::ops::ControlFlow::Continue(As::<&C, &CAs>::new(value))
+            }
+            core::ops::ControlFlow::Break(value) => {
+                core::ops::ControlFlow::Break(As::<&B, &BAs>::new(value))
+            }
+        };
+        value.unambiguously_encode(encoder)
+    }
+}
+
 impl<T, U> DigestAs<[T]> for [U]
 where
     U: DigestAs<T>,
@@ -253,6 +574,26 @@ where
     }
 }
 
+/// Digests `BinaryHeap` as a list sorted in ascending order
+///
+/// Encoding is the same as encoding of [`BinaryHeap::into_sorted_vec`](alloc::collections::BinaryHeap::into_sorted_vec).
+#[cfg(feature = "alloc")]
+impl<T, U> DigestAs<alloc::collections::BinaryHeap<T>> for alloc::vec::Vec<U>
+where
+    U: DigestAs<T>,
+    T: core::cmp::Ord,
+{
+    fn digest_as<B: Buffer>(
+        value: &alloc::collections::BinaryHeap<T>,
+        encoder: encoding::EncodeValue<B>,
+    ) {
+        let mut sorted = value.iter().collect::<alloc::vec::Vec<_>>();
+        // heap iteration order is unspecified, sorting makes it deterministic
+        sorted.sort();
+        crate::unambiguously_encode_iter(encoder, sorted.into_iter().map(As::<&T, &U>::new))
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<T, U> DigestAs<alloc::boxed::Box<T>> for alloc::boxed::Box<U>
 where
@@ -342,4 +683,20 @@ mod tuples {
     impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13);
     impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14);
     impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23, T24, As24);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23, T24, As24, T25, As25);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23, T24, As24, T25, As25, T26, As26);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23, T24, As24, T25, As25, T26, As26, T27, As27);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23, T24, As24, T25, As25, T26, As26, T27, As27, T28, As28);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23, T24, As24, T25, As25, T26, As26, T27, As27, T28, As28, T29, As29);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23, T24, As24, T25, As25, T26, As26, T27, As27, T28, As28, T29, As29, T30, As30);
+    impl_for_tuples!(T0, As0, T1, As1, T2, As2, T3, As3, T4, As4, T5, As5, T6, As6, T7, As7, T8, As8, T9, As9, T10, As10, T11, As11, T12, As12, T13, As13, T14, As14, T15, As15, T16, As16, T17, As17, T18, As18, T19, As19, T20, As20, T21, As21, T22, As22, T23, As23, T24, As24, T25, As25, T26, As26, T27, As27, T28, As28, T29, As29, T30, As30, T31, As31);
 }
@@ -0,0 +1,131 @@
+//! # Export to CBOR
+//!
+//! Converts the [encoding](crate::encoding) tree of a value into [CBOR] so it can be handed to
+//! verifiers written in other languages. The tree is mapped onto CBOR data items as follows:
+//!
+//! * Leaf is a byte string (major type 2)
+//! * List is an array of values (major type 4)
+//! * Value tagged with domain separation tag is a map (major type 5) with a single entry: the
+//!   key is the tag as a byte string, and the value is the leaf or list itself
+//!
+//! The output is deterministic: it follows [core deterministic encoding requirements] of
+//! RFC 8949, i.e. all lengths are definite and encoded in the shortest form. Every map contains
+//! a single entry, so there's no need to sort keys. Therefore, the same value always produces
+//! the same CBOR bytes, and two values have the same CBOR export if and only if they have the
+//! same [unambiguous encoding](crate::encoding).
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")] {
+//! #[derive(udigest::Digestable)]
+//! #[udigest(tag = "person")]
+//! struct Person {
+//!     name: &'static str,
+//! }
+//!
+//! let cbor = udigest::cbor::encode_to_vec(&Person { name: "Alice" });
+//! assert_eq!(
+//!     cbor,
+//!     [
+//!         0xa1, // map(1)
+//!         0x46, b'p', b'e', b'r', b's', b'o', b'n', // bytes(6) "person"
+//!         0x82, // array(2)
+//!         0x44, b'n', b'a', b'm', b'e', // bytes(4) "name"
+//!         0x45, b'A', b'l', b'i', b'c', b'e', // bytes(5) "Alice"
+//!     ],
+//! );
+//! # }
+//! ```
+//!
+//! [CBOR]: https://www.rfc-editor.org/rfc/rfc8949.html
+//! [core deterministic encoding requirements]: https://www.rfc-editor.org/rfc/rfc8949.html#section-4.2.1
+
+use alloc::vec::Vec;
+
+use crate::decoding::Value;
+use crate::{Buffer, Digestable};
+
+/// Major type of byte strings
+const BYTES: u8 = 2;
+/// Major type of arrays
+const ARRAY: u8 = 4;
+/// Major type of maps
+const MAP: u8 = 5;
+
+/// Exports the encoding tree of a `value` to CBOR and writes it to the `buffer`
+pub fn encode(value: &impl Digestable, buffer: &mut impl Buffer) {
+    let encoding = crate::encode_to_vec(value);
+    #[allow(clippy::expect_used)]
+    let value = crate::decoding::decode(&encoding).expect("encoders always produce valid encoding");
+    encode_decoded(&value, buffer)
+}
+
+/// Exports the encoding tree of a `value` to CBOR
+pub fn encode_to_vec(value: &impl Digestable) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    encode(value, &mut buffer);
+    buffer
+}
+
+/// Exports already [decoded](crate::decoding) value to CBOR and writes it to the `buffer`
+pub fn encode_decoded(value: &Value, buffer: &mut impl Buffer) {
+    // Values are written in pre-order without recursion, so deeply nested values
+    // can't overflow the stack
+    let mut pending = Vec::new();
+    if let Some(items) = write_header(buffer, value) {
+        pending.push(items.iter());
+    }
+    while let Some(items) = pending.last_mut() {
+        match items.next() {
+            Some(item) => {
+                if let Some(items) = write_header(buffer, item) {
+                    pending.push(items.iter());
+                }
+            }
+            None => {
+                pending.pop();
+            }
+        }
+    }
+}
+
+/// Writes the tag (if any) and the head of the value. For leaves, also writes the bytestring.
+/// For lists, returns the items that need to be written next.
+fn write_header<'v, 'a>(buffer: &mut impl Buffer, value: &'v Value<'a>) -> Option<&'v [Value<'a>]> {
+    if let Some(tag) = value.tag() {
+        write_head(buffer, MAP, 1);
+        write_head(buffer, BYTES, tag.len());
+        buffer.write(tag);
+    }
+    match value {
+        Value::Leaf(leaf) => {
+            write_head(buffer, BYTES, leaf.bytes().len());
+            buffer.write(leaf.bytes());
+            None
+        }
+        Value::List(list) => {
+            write_head(buffer, ARRAY, list.items().len());
+            Some(list.items())
+        }
+    }
+}
+
+/// Writes head of a data item with given major type and length in the shortest form
+fn write_head(buffer: &mut impl Buffer, major: u8, len: usize) {
+    let major = major << 5;
+    // `usize` is never wider than 64 bits on supported platforms
+    let len = len as u64;
+    if len < 24 {
+        buffer.write(&[major | len as u8]);
+    } else if let Ok(len) = u8::try_from(len) {
+        buffer.write(&[major | 24, len]);
+    } else if let Ok(len) = u16::try_from(len) {
+        buffer.write(&[major | 25]);
+        buffer.write(&len.to_be_bytes());
+    } else if let Ok(len) = u32::try_from(len) {
+        buffer.write(&[major | 26]);
+        buffer.write(&len.to_be_bytes());
+    } else {
+        buffer.write(&[major | 27]);
+        buffer.write(&len.to_be_bytes());
+    }
+}
@@ -0,0 +1,168 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::{encoding, Buffer, Digestable};
+
+/// Size of the window of the encoding kept on the stack
+const WINDOW: usize = 256;
+
+/// Checks whether two values have identical encoding
+///
+/// Equal encoding implies equal digests, but comparing encodings is much cheaper than digesting
+/// both values. Values may have different types: for instance, `Vec<u8>` and `[u8; N]` have the
+/// same encoding if they contain the same elements.
+///
+/// Comparison doesn't allocate, so it's available in `no_std` environments. Encodings are
+/// compared window by window: both values are encoded once per window, only bytes of the
+/// current window are kept on the stack, and comparison stops at the first window that
+/// differs. Encodings of different lengths are told apart after the first window. Values
+/// which encoding spans many windows are re-encoded many times, so it's advised to use it
+/// for small values or values that likely differ early in the encoding.
+///
+/// ```rust
+/// assert!(udigest::eq(&[1_u8, 2, 3], &[1_u8, 2, 3]));
+/// assert!(udigest::eq(&("Alice", 24_u32), &("Alice", 24_u64)));
+/// assert!(!udigest::eq(&("Alice", 24_u32), &("Alice", 25_u32)));
+/// # #[cfg(feature = "alloc")]
+/// assert!(udigest::eq(&vec![1_u8, 2, 3], &[1_u8, 2, 3]));
+/// ```
+pub fn eq(a: &impl Digestable, b: &impl Digestable) -> bool {
+    compare(a, b, true) == Ordering::Equal
+}
+
+/// Compares two values by their encoding
+///
+/// Encodings are compared lexicographically as bytestrings. It gives a canonical order
+/// that doesn't depend on types of the values, e.g. to sort values before putting them
+/// into a Merkle tree. Note that it's unrelated to [`Ord`] implementation of the values.
+///
+/// Same as [`eq`], comparison doesn't allocate and re-encodes both values once per window.
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// assert_eq!(udigest::cmp(&"Alice", &"Bob"), Ordering::Less);
+/// assert_eq!(udigest::cmp(&[1_u8, 2, 3], &[1_u8, 2, 3]), Ordering::Equal);
+/// ```
+pub fn cmp(a: &impl Digestable, b: &impl Digestable) -> Ordering {
+    compare(a, b, false)
+}
+
+/// Compares encodings window by window
+///
+/// If `only_eq` is set, any ordering other than `Equal` means that encodings differ, which
+/// allows to stop as soon as lengths of the encodings differ
+fn compare(a: &impl Digestable, b: &impl Digestable, only_eq: bool) -> Ordering {
+    let mut offset = 0;
+    loop {
+        let a = Window::capture(a, offset);
+        let b = Window::capture(b, offset);
+        if only_eq && a.total != b.total {
+            return Ordering::Less;
+        }
+        match a.filled().cmp(b.filled()) {
+            Ordering::Equal if a.len == WINDOW => offset += WINDOW,
+            // either windows differ, or both encodings end within the window
+            ordering => return ordering,
+        }
+    }
+}
+
+/// Captures bytes `offset..offset + WINDOW` of the encoding
+struct Window {
+    offset: usize,
+    bytes: [u8; WINDOW],
+    len: usize,
+    /// Length of the whole encoding
+    total: usize,
+}
+
+impl Window {
+    fn capture(value: &impl Digestable, offset: usize) -> Self {
+        let mut window = Self {
+            offset,
+            bytes: [0; WINDOW],
+            len: 0,
+            total: 0,
+        };
+        value.unambiguously_encode(encoding::EncodeValue::new(&mut window));
+        window
+    }
+
+    fn filled(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Buffer for Window {
+    fn write(&mut self, bytes: &[u8]) {
+        let start = self.total;
+        self.total = self.total.saturating_add(bytes.len());
+
+        // intersection of `start..self.total` with the window
+        let from = start.max(self.offset);
+        let to = self.total.min(self.offset.saturating_add(WINDOW));
+        if from < to {
+            self.bytes[from - self.offset..to - self.offset]
+                .copy_from_slice(&bytes[from - start..to - start]);
+            self.len = to - self.offset;
+        }
+    }
+}
+
+/// Wrapper that compares values by their encoding
+///
+/// Implements [`Eq`] and [`Ord`] via [`eq`] and [`cmp`], so values can be sorted in canonical
+/// order, or put into `BTreeSet`. Implements [`Hash`] by feeding the encoding into the hasher
+/// via [`StdHashAdapter`](crate::StdHashAdapter), so values can be put into `HashSet` as well.
+/// Wrapper is digested exactly as the value it contains.
+///
+/// Each comparison encodes both values, so it's advised to use it for small values only.
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use udigest::ByEncoding;
+///
+/// let mut names = vec![ByEncoding("Bob"), ByEncoding("Alice"), ByEncoding("Carol")];
+/// names.sort();
+/// assert_eq!(names, [ByEncoding("Alice"), ByEncoding("Bob"), ByEncoding("Carol")]);
+///
+/// let mut set = std::collections::HashSet::new();
+/// set.insert(ByEncoding((String::from("Alice"), 24_u32)));
+/// assert!(!set.insert(ByEncoding((String::from("Alice"), 24_u32))));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByEncoding<T>(pub T);
+
+impl<T: Digestable> PartialEq for ByEncoding<T> {
+    fn eq(&self, other: &Self) -> bool {
+        eq(&self.0, &other.0)
+    }
+}
+
+impl<T: Digestable> Eq for ByEncoding<T> {}
+
+impl<T: Digestable> PartialOrd for ByEncoding<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Digestable> Ord for ByEncoding<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp(&self.0, &other.0)
+    }
+}
+
+impl<T: Digestable> Hash for ByEncoding<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::StdHashAdapter::new(state).write_value(&self.0)
+    }
+}
+
+impl<T: Digestable> Digestable for ByEncoding<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.0.unambiguously_encode(encoder)
+    }
+}
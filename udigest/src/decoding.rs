@@ -0,0 +1,485 @@
+//! # Decoding the unambiguous encoding
+//!
+//! This module parses bytes produced by the [encoders](crate::encoding) back into a tree
+//! of leaves and lists. It's not needed to digest the data, but it's useful for debugging
+//! digest mismatches or verifying that a third-party implementation produces the same
+//! encoding.
+//!
+//! Encoding is suffix-based: metadata (length and type) is written after the value, so the
+//! parser reads the input from the end. See [`encoding` module](crate::encoding#encoding-lists-into-bytes)
+//! for the grammar.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")] {
+//! use udigest::decoding;
+//!
+//! #[derive(udigest::Digestable)]
+//! struct Person {
+//!     name: &'static str,
+//!     skills: Vec<&'static str>,
+//! }
+//! let alice = Person {
+//!     name: "Alice",
+//!     skills: vec!["math", "crypto"],
+//! };
+//!
+//! let encoding = udigest::encode_to_vec(&alice);
+//! let decoded = decoding::decode(&encoding)?;
+//!
+//! let fields = decoded.as_list().unwrap().items();
+//! assert_eq!(fields[0].as_leaf().unwrap().bytes(), b"name");
+//! assert_eq!(fields[1].as_leaf().unwrap().bytes(), b"Alice");
+//! assert_eq!(fields[3].as_list().unwrap().items().len(), 2);
+//! # }
+//! # Ok::<_, udigest::decoding::DecodeError>(())
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::encoding::{BIGLEN, LEAF, LEAF_CTX, LEN_32, LIST, LIST_CTX};
+
+/// Decoded value: either a leaf (bytestring) or a list of values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value<'a> {
+    /// Bytestring
+    Leaf(Leaf<'a>),
+    /// List of values
+    List(List<'a>),
+}
+
+impl<'a> Value<'a> {
+    /// Returns domain separation tag of the value, if any
+    pub fn tag(&self) -> Option<&'a [u8]> {
+        match self {
+            Value::Leaf(leaf) => leaf.tag,
+            Value::List(list) => list.tag,
+        }
+    }
+
+    /// Returns the leaf if the value is a leaf
+    pub fn as_leaf(&self) -> Option<&Leaf<'a>> {
+        match self {
+            Value::Leaf(leaf) => Some(leaf),
+            Value::List(_) => None,
+        }
+    }
+
+    /// Returns the list if the value is a list
+    pub fn as_list(&self) -> Option<&List<'a>> {
+        match self {
+            Value::Leaf(_) => None,
+            Value::List(list) => Some(list),
+        }
+    }
+}
+
+/// Decoded leaf (bytestring)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaf<'a> {
+    bytes: &'a [u8],
+    tag: Option<&'a [u8]>,
+}
+
+impl<'a> Leaf<'a> {
+    pub(crate) fn new(bytes: &'a [u8], tag: Option<&'a [u8]>) -> Self {
+        Self { bytes, tag }
+    }
+
+    /// Returns the bytestring
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns domain separation tag, if any
+    pub fn tag(&self) -> Option<&'a [u8]> {
+        self.tag
+    }
+}
+
+/// Decoded list of values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct List<'a> {
+    items: Vec<Value<'a>>,
+    tag: Option<&'a [u8]>,
+}
+
+impl<'a> List<'a> {
+    pub(crate) fn new(items: Vec<Value<'a>>, tag: Option<&'a [u8]>) -> Self {
+        Self { items, tag }
+    }
+
+    /// Returns items of the list
+    pub fn items(&self) -> &[Value<'a>] {
+        &self.items
+    }
+
+    /// Returns domain separation tag, if any
+    pub fn tag(&self) -> Option<&'a [u8]> {
+        self.tag
+    }
+}
+
+impl Drop for List<'_> {
+    fn drop(&mut self) {
+        // Default drop is recursive and would overflow the stack on deeply nested lists
+        let mut items = core::mem::take(&mut self.items);
+        while let Some(item) = items.pop() {
+            if let Value::List(mut list) = item {
+                items.append(&mut list.items);
+            }
+        }
+    }
+}
+
+/// Renders the value as a human-readable tree
+///
+/// Leaves are rendered as strings if they contain printable UTF-8 text, and as hex (e.g.
+/// `0x2a`) otherwise. Lists that look like structures (i.e. every even item is a leaf
+/// containing an identifier-like field name) are rendered as `{ name: value, ... }`, other
+/// lists are rendered as `[value, ...]`. Domain separation tags are rendered as `#"tag"`
+/// prefix.
+///
+/// Alternate form (`{:#}`) renders the tree with indentation. Note that encoding doesn't
+/// preserve types, so the rendering is a best-effort guess. For instance, a list that
+/// consists only of identifier-like strings is always rendered as a list, so a structure
+/// `{ first: "Alice", last: "Smith" }` is rendered as `["first", "Alice", "last", "Smith"]`.
+impl core::fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&Inspect(self), f)
+    }
+}
+
+/// Encodes the value and renders its encoding as an indented human-readable tree
+///
+/// It's useful for debugging why two values have different digests:
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// #[derive(udigest::Digestable)]
+/// struct Person {
+///     name: &'static str,
+///     skills: Vec<&'static str>,
+/// }
+/// let alice = Person {
+///     name: "Alice",
+///     skills: vec!["math", "crypto"],
+/// };
+///
+/// assert_eq!(
+///     udigest::decoding::inspect(&alice),
+///     "{\n    name: \"Alice\",\n    skills: [\n        \"math\",\n        \"crypto\",\n    ],\n}",
+/// );
+/// # }
+/// ```
+///
+/// See [`Value`'s `Display` implementation](Value#impl-Display-for-Value<'_>) for the details
+/// of rendering.
+pub fn inspect(value: &impl crate::Digestable) -> alloc::string::String {
+    let encoding = crate::encode_to_vec(value);
+    let rendered = match decode(&encoding) {
+        Ok(decoded) => alloc::format!("{decoded:#}"),
+        // can only happen if `Digestable` impl produced a malformed encoding
+        Err(err) => alloc::format!("<malformed encoding: {err}>"),
+    };
+    rendered
+}
+
+/// Renders a value via [`Debug`](core::fmt::Debug) trait, which gives us
+/// formatting helpers that support indentation
+struct Inspect<'v, 'a>(&'v Value<'a>);
+
+impl core::fmt::Debug for Inspect<'_, '_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(tag) = self.0.tag() {
+            write!(f, "#")?;
+            fmt_bytes(tag, f)?;
+            write!(f, " ")?;
+        }
+        match self.0 {
+            Value::Leaf(leaf) => fmt_bytes(leaf.bytes, f),
+            Value::List(list) if looks_like_struct(&list.items) => {
+                let mut s = f.debug_map();
+                for field in list.items.chunks_exact(2) {
+                    s.entry(&FieldName(&field[0]), &Inspect(&field[1]));
+                }
+                s.finish()
+            }
+            Value::List(list) => f
+                .debug_list()
+                .entries(list.items.iter().map(Inspect))
+                .finish(),
+        }
+    }
+}
+
+/// Renders a field name without quotes
+struct FieldName<'v, 'a>(&'v Value<'a>);
+
+impl core::fmt::Debug for FieldName<'_, '_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = self
+            .0
+            .as_leaf()
+            .and_then(|leaf| core::str::from_utf8(leaf.bytes).ok())
+            .unwrap_or_default();
+        f.write_str(name)
+    }
+}
+
+pub(crate) fn fmt_bytes(bytes: &[u8], f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match core::str::from_utf8(bytes) {
+        Ok(s) if !s.chars().any(char::is_control) => write!(f, "{s:?}"),
+        _ => {
+            write!(f, "0x")?;
+            bytes.iter().try_for_each(|b| write!(f, "{b:02x}"))
+        }
+    }
+}
+
+fn looks_like_struct(items: &[Value]) -> bool {
+    !items.is_empty()
+        && items.chunks_exact(2).remainder().is_empty()
+        && items.iter().step_by(2).all(is_identifier)
+        // a list of identifiers, like `["math", "crypto"]`, is more likely a list than a struct
+        && !items.iter().skip(1).step_by(2).all(is_identifier)
+}
+
+fn is_identifier(value: &Value) -> bool {
+    value.as_leaf().is_some_and(|leaf| {
+        leaf.tag.is_none()
+            && !leaf.bytes.is_empty()
+            && leaf
+                .bytes
+                .iter()
+                .all(|b| b.is_ascii_alphanumeric() || *b == b'_')
+    })
+}
+
+/// Decodes a single value from `bytes`
+///
+/// The whole input must be consumed by the value, otherwise an error is returned.
+pub fn decode(bytes: &[u8]) -> Result<Value<'_>, DecodeError> {
+    // Lists are decoded without recursion, so deeply nested input can't overflow the stack
+    struct PendingList<'a> {
+        remaining: usize,
+        items: Vec<Value<'a>>,
+        tag: Option<&'a [u8]>,
+    }
+
+    let mut input = Input(bytes);
+    let mut pending: Vec<PendingList> = Vec::new();
+
+    loop {
+        let mut value = match input.read_header()? {
+            Header::Leaf { bytes, tag } => Value::Leaf(Leaf { bytes, tag }),
+            Header::List { len: 0, tag } => Value::List(List {
+                items: Vec::new(),
+                tag,
+            }),
+            Header::List { len, tag } => {
+                pending.push(PendingList {
+                    remaining: len,
+                    items: Vec::new(),
+                    tag,
+                });
+                continue;
+            }
+        };
+
+        // Value is complete, we put it into the parent list. If it completes the parent
+        // list as well, we continue with the grandparent, and so on.
+        loop {
+            let Some(parent) = pending.last_mut() else {
+                if !input.0.is_empty() {
+                    return Err(DecodeError::UnexpectedBytes { len: input.0.len() });
+                }
+                return Ok(value);
+            };
+            parent.items.push(value);
+            parent.remaining -= 1;
+            if parent.remaining > 0 {
+                break;
+            }
+
+            #[allow(clippy::expect_used)]
+            let mut list = pending.pop().expect("parent list is present");
+            // items were read from the end
+            list.items.reverse();
+            value = Value::List(List {
+                items: list.items,
+                tag: list.tag,
+            });
+        }
+    }
+}
+
+/// Metadata of the value that's being decoded
+enum Header<'a> {
+    Leaf {
+        bytes: &'a [u8],
+        tag: Option<&'a [u8]>,
+    },
+    List {
+        len: usize,
+        tag: Option<&'a [u8]>,
+    },
+}
+
+/// Input that is being consumed from the end
+struct Input<'a>(&'a [u8]);
+
+impl<'a> Input<'a> {
+    /// Reads the value metadata. For leaves, also reads the bytestring.
+    fn read_header(&mut self) -> Result<Header<'a>, DecodeError> {
+        let offset = self.offset();
+        match self.read_byte()? {
+            LEAF => {
+                let bytes = self.read_bytestring()?;
+                Ok(Header::Leaf { bytes, tag: None })
+            }
+            LEAF_CTX => {
+                let tag = self.read_bytestring()?;
+                let bytes = self.read_bytestring()?;
+                Ok(Header::Leaf {
+                    bytes,
+                    tag: Some(tag),
+                })
+            }
+            LIST => {
+                let len = self.read_len()?;
+                Ok(Header::List { len, tag: None })
+            }
+            LIST_CTX => {
+                let tag = self.read_bytestring()?;
+                let len = self.read_len()?;
+                Ok(Header::List {
+                    len,
+                    tag: Some(tag),
+                })
+            }
+            symbol => Err(DecodeError::UnknownControlSymbol { offset, symbol }),
+        }
+    }
+
+    /// Reads length followed by the bytestring of that length
+    fn read_bytestring(&mut self) -> Result<&'a [u8], DecodeError> {
+        let len = self.read_len()?;
+        self.read_bytes(len)
+    }
+
+    /// Reads length encoded by [`encode_len`](crate::encoding::encode_len)
+    fn read_len(&mut self) -> Result<usize, DecodeError> {
+        let offset = self.offset();
+        match self.read_byte()? {
+            LEN_32 => {
+                let len = self.read_bytes(4)?;
+                let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]);
+                usize::try_from(len).map_err(|_| DecodeError::LengthOverflow { offset })
+            }
+            BIGLEN => {
+                let len_of_len = self.read_byte()?;
+                let len = self.read_bytes(len_of_len.into())?;
+                if len.first() == Some(&0) {
+                    // leading zeroes must be stripped
+                    return Err(DecodeError::NonCanonicalLength { offset });
+                }
+                if len.len() > core::mem::size_of::<usize>() {
+                    return Err(DecodeError::LengthOverflow { offset });
+                }
+                let len = len
+                    .iter()
+                    .fold(0usize, |acc, byte| acc << 8 | usize::from(*byte));
+                if u32::try_from(len).is_ok() {
+                    // lengths that fit into `u32` must be encoded as `LEN_32`
+                    return Err(DecodeError::NonCanonicalLength { offset });
+                }
+                Ok(len)
+            }
+            symbol => Err(DecodeError::UnknownControlSymbol { offset, symbol }),
+        }
+    }
+
+    fn read_byte(&mut self) -> Result<u8, DecodeError> {
+        let (byte, rest) = self.0.split_last().ok_or(DecodeError::UnexpectedEnd)?;
+        self.0 = rest;
+        Ok(*byte)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        let split_at = self
+            .0
+            .len()
+            .checked_sub(len)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        let (rest, bytes) = self.0.split_at(split_at);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    /// Offset of the last unread byte in the original input
+    fn offset(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+}
+
+/// Error returned by [`decode`] and [`prefixed::decode`](crate::prefixed::decode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// Input ended while more bytes were expected
+    UnexpectedEnd,
+    /// Byte at `offset` is expected to be a control symbol, but it's not a known one
+    UnknownControlSymbol {
+        /// Offset of the byte in the input
+        offset: usize,
+        /// The byte
+        symbol: u8,
+    },
+    /// Length ending at `offset` is not encoded in canonical form
+    NonCanonicalLength {
+        /// Offset of the length control symbol in the input
+        offset: usize,
+    },
+    /// Length ending at `offset` doesn't fit into `usize`
+    LengthOverflow {
+        /// Offset of the length control symbol in the input
+        offset: usize,
+    },
+    /// Value is decoded, but it's preceded by `len` bytes that don't belong to it
+    UnexpectedBytes {
+        /// Amount of bytes
+        len: usize,
+    },
+    /// Value is decoded, but it's followed by `len` bytes that don't belong to it
+    ///
+    /// Only returned by [`prefixed::decode`](crate::prefixed::decode)
+    TrailingBytes {
+        /// Amount of bytes
+        len: usize,
+    },
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of input"),
+            Self::UnknownControlSymbol { offset, symbol } => {
+                write!(f, "unknown control symbol {symbol} at offset {offset}")
+            }
+            Self::NonCanonicalLength { offset } => {
+                write!(f, "length at offset {offset} is not encoded canonically")
+            }
+            Self::LengthOverflow { offset } => {
+                write!(f, "length at offset {offset} overflows usize")
+            }
+            Self::UnexpectedBytes { len } => {
+                write!(f, "value is preceded by {len} unexpected bytes")
+            }
+            Self::TrailingBytes { len } => {
+                write!(f, "value is followed by {len} unexpected bytes")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
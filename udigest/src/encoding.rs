@@ -75,6 +75,15 @@
 //! Signed integers are encoded as concatenation of their sign and byte representation of their absolute
 //! value.
 //!
+//! ### Floats
+//! Floats are not digestable by default as the same number may have several representations.
+//! With `float` feature enabled, `f32` and `f64` are encoded as a bytestring containing big-endian
+//! IEEE-754 binary64 representation of the canonicalized value:
+//!
+//! * `f32` is losslessly converted to `f64`, so the encoding doesn't depend on the width
+//! * Any NaN is replaced with the quiet NaN `0x7ff8_0000_0000_0000`
+//! * `-0.0` is replaced with `+0.0`
+//!
 //! ### Domain separation
 //! When value is encoded into bytes, it loses its type. For instance, "abcd" bytestring may correspond to
 //! `Vec<u8>`, `String`, `u32` and so on. When it's required to distinguish one type from another, domain
@@ -157,6 +166,31 @@
 //! ```
 //!
 //! where `LEAF`, `LIST`, and `LEN_32` are constants [defined above](#encoding-lists-into-bytes).
+//!
+//! # Compact format (v2)
+//!
+//! The format described above (v1) spends 5 bytes on every length. [`Format::V2`] is an opt-in
+//! alternative with compact lengths: a length takes 1 byte for empty values, and 2 bytes for values
+//! shorter than 256 bytes. The grammar is the same as above, except lengths and control symbols:
+//!
+//! ```text
+//! len(n) ::=
+//!   let len_n = n.len().to_be_bytes().strip();
+//!   len_n (len_n.len() as u8)
+//!
+//! LIST     ::= 17
+//! LIST_CTX ::= 18
+//! LEAF     ::= 19
+//! LEAF_CTX ::= 20
+//! ```
+//!
+//! where `strip()` removes all leading zeroes. Any encoding ends with a control symbol, and the
+//! formats have distinct control symbols, so v2 encoding of a value never collides with v1
+//! encoding of any value.
+//!
+//! Format is selected when the encoder is constructed, e.g. via [`EncodeValue::new_with_format`],
+//! and it's inherited by all nested encoders. [`hash_v2`](crate::hash_v2) digests a value using v2
+//! format.
 
 /// Control symbol
 ///
@@ -183,6 +217,99 @@ pub const LEN_32: u8 = 5;
 /// See [module level](self) docs
 pub const BIGLEN: u8 = 6;
 
+/// Control symbol of compact format
+///
+/// See [module level](self#compact-format-v2) docs
+pub const V2_LIST: u8 = 17;
+/// Control symbol of compact format
+///
+/// See [module level](self#compact-format-v2) docs
+pub const V2_LIST_CTX: u8 = 18;
+/// Control symbol of compact format
+///
+/// See [module level](self#compact-format-v2) docs
+pub const V2_LEAF: u8 = 19;
+/// Control symbol of compact format
+///
+/// See [module level](self#compact-format-v2) docs
+pub const V2_LEAF_CTX: u8 = 20;
+
+/// Encoding format
+///
+/// See [module level](self#compact-format-v2) docs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Format {
+    /// Original format with lengths taking at least 5 bytes
+    #[default]
+    V1,
+    /// Compact format with lengths taking at least 1 byte
+    V2,
+}
+
+impl Format {
+    /// Encodes length of list or leaf in this format
+    ///
+    /// Same as [`encode_len`], normally you don't need to call it directly
+    pub fn encode_len(self, buffer: &mut impl Buffer, len: usize) {
+        match self {
+            Format::V1 => encode_len(buffer, len),
+            Format::V2 => {
+                let len = len.to_be_bytes();
+                let leading_zeroes = len.iter().take_while(|b| **b == 0).count();
+                let len = &len[leading_zeroes..];
+
+                #[allow(clippy::expect_used)]
+                let len_of_len = u8::try_from(len.len())
+                    .expect("it's impossible that usize is more than 256 bytes long");
+
+                buffer.write(len);
+                buffer.write(&[len_of_len]);
+            }
+        }
+    }
+
+    /// Returns control symbols `(LIST, LIST_CTX, LEAF, LEAF_CTX)` of this format
+    fn symbols(self) -> (u8, u8, u8, u8) {
+        match self {
+            Format::V1 => (LIST, LIST_CTX, LEAF, LEAF_CTX),
+            Format::V2 => (V2_LIST, V2_LIST_CTX, V2_LEAF, V2_LEAF_CTX),
+        }
+    }
+
+    /// Writes metadata that follows the bytes of a leaf
+    fn write_leaf_metadata(self, buffer: &mut impl Buffer, len: usize, tag: Option<&[u8]>) {
+        let (_, _, leaf, leaf_ctx) = self.symbols();
+        self.write_metadata(buffer, len, tag, leaf, leaf_ctx)
+    }
+
+    /// Writes metadata that follows the items of a list
+    fn write_list_metadata(self, buffer: &mut impl Buffer, len: usize, tag: Option<&[u8]>) {
+        let (list, list_ctx, _, _) = self.symbols();
+        self.write_metadata(buffer, len, tag, list, list_ctx)
+    }
+
+    fn write_metadata(
+        self,
+        buffer: &mut impl Buffer,
+        len: usize,
+        tag: Option<&[u8]>,
+        symbol: u8,
+        symbol_ctx: u8,
+    ) {
+        self.encode_len(buffer, len);
+
+        if let Some(tag) = tag {
+            buffer.write(tag);
+            self.encode_len(buffer, tag.len());
+
+            buffer.write(&[symbol_ctx]);
+        } else {
+            buffer.write(&[symbol]);
+        }
+    }
+}
+
 /// A buffer that exposes append-only access
 ///
 /// Out of box, it's implemented for any hashing algorithm that implements
@@ -192,6 +319,890 @@ pub trait Buffer {
     ///
     /// Method must never panic
     fn write(&mut self, bytes: &[u8]);
+
+    /// Notifies the buffer about the structure of the value being encoded
+    ///
+    /// Encoders call this method when they start and finish encoding a leaf or a list. It lets
+    /// buffers like [`LimitedBuffer`] observe the structure without parsing the bytes. Default
+    /// implementation does nothing. Buffers that wrap other buffers should forward events to
+    /// them.
+    ///
+    /// Method must never panic
+    fn on_event(&mut self, event: Event<'_>) {
+        let _ = event;
+    }
+}
+
+/// Operation of the encoder reported to the buffer via [`Buffer::on_event`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Event<'a> {
+    /// Encoding of a leaf has started, its bytes will be written next
+    LeafStart,
+    /// Encoding of a leaf has finished, its metadata will be written next
+    LeafEnd {
+        /// Length of the leaf in bytes
+        len: usize,
+        /// Domain separation tag
+        tag: Option<&'a [u8]>,
+    },
+    /// Encoding of a list has started, its items will be written next
+    ListStart,
+    /// Encoding of a list has finished, its metadata will be written next
+    ListEnd {
+        /// Amount of items in the list
+        len: usize,
+        /// Domain separation tag
+        tag: Option<&'a [u8]>,
+    },
+    /// Structure field is added to the list, field name and value will be written next
+    /// as two list items
+    Field {
+        /// Name of the field
+        name: &'a [u8],
+    },
+}
+
+/// Appends bytes to the vector
+#[cfg(feature = "alloc")]
+impl Buffer for alloc::vec::Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes)
+    }
+}
+
+/// Growable buffer for secret data that never leaves copies of the bytes in freed memory
+///
+/// When [`Vec<u8>`](alloc::vec::Vec) runs out of capacity, it moves its contents into a bigger
+/// allocation and frees the old one without wiping it. This buffer instead copies the
+/// contents into a new allocation itself and zeroizes the old one. The buffer is zeroized
+/// on drop.
+///
+/// ```rust
+/// use udigest::{encoding::{EncodeValue, ZeroizingVec}, Bytes, Digestable};
+///
+/// let key_share = zeroize::Zeroizing::new([42u8; 32]);
+///
+/// let mut buffer = ZeroizingVec::new();
+/// Bytes(&*key_share).unambiguously_encode(EncodeValue::new(&mut buffer));
+/// let encoding = buffer.into_zeroizing();
+/// # assert_eq!(*encoding, udigest::encode_to_vec(&Bytes(&*key_share)));
+/// ```
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+#[derive(Default, Clone)]
+pub struct ZeroizingVec(alloc::vec::Vec<u8>);
+
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+impl ZeroizingVec {
+    /// Constructs an empty buffer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs an empty buffer with at least the specified capacity
+    ///
+    /// If the size of the encoding is known in advance (e.g. via
+    /// [`encoded_len`](crate::encoded_len)), it avoids reallocations altogether.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(alloc::vec::Vec::with_capacity(capacity))
+    }
+
+    /// Returns bytes written to the buffer
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns bytes written to the buffer wrapped into [`Zeroizing`](zeroize::Zeroizing)
+    pub fn into_zeroizing(mut self) -> zeroize::Zeroizing<alloc::vec::Vec<u8>> {
+        // moving the vector doesn't move the heap allocation
+        zeroize::Zeroizing::new(core::mem::take(&mut self.0))
+    }
+}
+
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+impl Buffer for ZeroizingVec {
+    fn write(&mut self, bytes: &[u8]) {
+        if self.0.capacity() - self.0.len() < bytes.len() {
+            let required = self.0.len().saturating_add(bytes.len());
+            let capacity = required.max(self.0.capacity().saturating_mul(2));
+            let mut new = alloc::vec::Vec::with_capacity(capacity);
+            new.extend_from_slice(&self.0);
+            zeroize::Zeroize::zeroize(&mut self.0);
+            self.0 = new;
+        }
+        self.0.extend_from_slice(bytes)
+    }
+}
+
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+impl core::fmt::Debug for ZeroizingVec {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ZeroizingVec")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+impl zeroize::Zeroize for ZeroizingVec {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0)
+    }
+}
+
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+impl Drop for ZeroizingVec {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0)
+    }
+}
+
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+impl zeroize::ZeroizeOnDrop for ZeroizingVec {}
+
+/// A buffer that exposes append-only access, and may fail to append the bytes
+///
+/// Useful for fixed-capacity or I/O-backed buffers. Use [`try_encode`](crate::try_encode)
+/// to encode a value into `TryBuffer`.
+pub trait TryBuffer {
+    /// Error returned when bytes couldn't be appended
+    type Error;
+
+    /// Appends `bytes` to the buffer
+    ///
+    /// Method must never panic
+    fn try_write(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// Wraps [`TryBuffer`] and implements [`Buffer`]
+///
+/// Encoders are infallible, so `BufferFallible` keeps track of the first error instead: once
+/// any write fails, all subsequent writes are ignored, and the error is returned by
+/// [`BufferFallible::finish`].
+pub struct BufferFallible<'b, B: TryBuffer> {
+    buffer: &'b mut B,
+    error: Option<B::Error>,
+}
+
+impl<'b, B: TryBuffer> BufferFallible<'b, B> {
+    /// Wraps the buffer
+    pub fn new(buffer: &'b mut B) -> Self {
+        Self {
+            buffer,
+            error: None,
+        }
+    }
+
+    /// Returns the first error occurred while writing, if any
+    pub fn error(&self) -> Option<&B::Error> {
+        self.error.as_ref()
+    }
+
+    /// Returns the first error occurred while writing, if any
+    pub fn finish(self) -> Result<(), B::Error> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<B: TryBuffer> Buffer for BufferFallible<'_, B> {
+    fn write(&mut self, bytes: &[u8]) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(err) = self.buffer.try_write(bytes) {
+            self.error = Some(err)
+        }
+    }
+}
+
+/// Buffer that forwards every write to both underlying buffers
+///
+/// Both buffers receive exactly the same bytes. To write into more than two buffers, `Tee`s
+/// can be nested: `Tee(&mut a, &mut Tee(&mut b, &mut c))`.
+///
+/// ```rust
+/// # #[cfg(all(feature = "digest", feature = "alloc"))] {
+/// use sha2::Digest;
+/// use udigest::encoding::{BufferDigest, EncodeValue, Tee};
+///
+/// let value = ("Alice", 24_u32);
+///
+/// let mut hash = BufferDigest(sha2::Sha256::new());
+/// let mut audit_log = Vec::new();
+/// udigest::Digestable::unambiguously_encode(
+///     &value,
+///     EncodeValue::new(&mut Tee(&mut hash, &mut audit_log)),
+/// );
+///
+/// assert_eq!(hash.0.finalize(), udigest::hash::<sha2::Sha256>(&value));
+/// assert_eq!(audit_log, udigest::encode_to_vec(&value));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Tee<'a, A: ?Sized, B: ?Sized>(pub &'a mut A, pub &'a mut B);
+
+impl<A: Buffer + ?Sized, B: Buffer + ?Sized> Buffer for Tee<'_, A, B> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+        self.1.write(bytes);
+    }
+
+    fn on_event(&mut self, event: Event<'_>) {
+        self.0.on_event(event);
+        self.1.on_event(event);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<A: zeroize::Zeroize + ?Sized, B: zeroize::Zeroize + ?Sized> zeroize::Zeroize
+    for Tee<'_, A, B>
+{
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(self.0);
+        zeroize::Zeroize::zeroize(self.1);
+    }
+}
+
+/// Buffer that doesn't store the bytes, but only counts them
+///
+/// Counter saturates at `usize::MAX`. See also [`encoded_len`](crate::encoded_len).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LengthCounter(pub usize);
+
+impl Buffer for LengthCounter {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = self.0.saturating_add(bytes.len())
+    }
+}
+
+/// Fixed-capacity buffer that writes into a caller-provided byte slice
+///
+/// Implements [`TryBuffer`]: a write that doesn't fit into remaining capacity is rejected with
+/// [`BufferOverflow`] error, and nothing is written. Doesn't require any allocations, so it's
+/// suitable for `no_std` environments.
+///
+/// ```rust
+/// use udigest::encoding::{BufferOverflow, SliceBuffer};
+///
+/// let mut bytes = [0u8; 16];
+/// let mut buffer = SliceBuffer::new(&mut bytes);
+/// udigest::try_encode(&"abc", &mut buffer)?;
+/// assert_eq!(buffer.filled(), b"abc\x00\x00\x00\x03\x05\x03");
+///
+/// let mut buffer = SliceBuffer::new(&mut bytes);
+/// assert_eq!(udigest::try_encode(&"a long string", &mut buffer), Err(BufferOverflow));
+/// # Ok::<_, BufferOverflow>(())
+/// ```
+pub struct SliceBuffer<'a> {
+    bytes: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceBuffer<'a> {
+    /// Constructs an empty buffer that writes into `bytes`
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, len: 0 }
+    }
+
+    /// Returns amount of bytes written to the buffer
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether nothing was written to the buffer
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns amount of bytes that can still be written to the buffer
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.len
+    }
+
+    /// Returns bytes written to the buffer
+    pub fn filled(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Returns bytes written to the buffer, consuming the buffer
+    pub fn into_filled(self) -> &'a mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+}
+
+impl TryBuffer for SliceBuffer<'_> {
+    type Error = BufferOverflow;
+    fn try_write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        let dest = self
+            .bytes
+            .get_mut(self.len..)
+            .and_then(|remaining| remaining.get_mut(..bytes.len()))
+            .ok_or(BufferOverflow)?;
+        dest.copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+}
+
+/// Wipes bytes written to the buffer, and makes the buffer empty
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SliceBuffer<'_> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bytes[..self.len]);
+        self.len = 0;
+    }
+}
+
+/// Error returned by [`SliceBuffer`] when bytes don't fit into the buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferOverflow;
+
+impl core::fmt::Display for BufferOverflow {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("buffer capacity exceeded")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferOverflow {}
+
+/// Error returned by [`encode_into`](crate::encode_into) when encoding doesn't fit into the
+/// output slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    pub(crate) required: usize,
+    pub(crate) available: usize,
+}
+
+impl BufferTooSmall {
+    /// Returns length of the encoding in bytes
+    pub fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns length of the output slice
+    pub fn available(&self) -> usize {
+        self.available
+    }
+}
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "buffer too small: encoding takes {} bytes, but only {} bytes are available",
+            self.required, self.available
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+/// Buffer that enforces limits on the size and nesting depth of the encoding
+///
+/// Useful when digesting values that are influenced by untrusted party. Once a limit is exceeded,
+/// the buffer stops forwarding bytes to the underlying buffer, and the error is returned by
+/// [`finish`](Self::finish). Size of the encoding is the total amount of bytes written. Nesting
+/// depth is the amount of lists containing each other, which is tracked via
+/// [events](Buffer::on_event) reported by the encoders.
+///
+/// Note that the buffer can't interrupt the encoding: the [`Digestable`](crate::Digestable)
+/// implementation still traverses the whole value, only the work done by the underlying buffer
+/// (e.g. hashing) is bounded.
+///
+/// ```rust
+/// # #[cfg(all(feature = "digest", feature = "alloc"))] {
+/// use sha2::Digest;
+/// use udigest::encoding::{BufferDigest, EncodeValue, LimitExceeded, LimitedBuffer};
+/// use udigest::Digestable;
+///
+/// let nested = vec![vec![vec!["too deep"]]];
+///
+/// let mut buffer = LimitedBuffer::new(BufferDigest(sha2::Sha256::new()))
+///     .with_max_len(1024)
+///     .with_max_depth(2);
+/// nested.unambiguously_encode(EncodeValue::new(&mut buffer));
+/// assert_eq!(
+///     buffer.finish().err(),
+///     Some(LimitExceeded::Depth { limit: 2 }),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LimitedBuffer<B> {
+    buffer: B,
+    max_len: usize,
+    max_depth: usize,
+    len: usize,
+    depth: usize,
+    error: Option<LimitExceeded>,
+}
+
+impl<B: Buffer> LimitedBuffer<B> {
+    /// Wraps a buffer
+    ///
+    /// Buffer has no limits until they're set via [`with_max_len`](Self::with_max_len) and
+    /// [`with_max_depth`](Self::with_max_depth).
+    pub fn new(buffer: B) -> Self {
+        Self {
+            buffer,
+            max_len: usize::MAX,
+            max_depth: usize::MAX,
+            len: 0,
+            depth: 0,
+            error: None,
+        }
+    }
+
+    /// Limits the size of the encoding to `max_len` bytes
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Limits the nesting depth to `max_depth` lists
+    ///
+    /// Leaves don't count towards depth, so `"leaf"` has depth 0, and `["leaf"]` has depth 1.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Returns the exceeded limit, if any
+    pub fn error(&self) -> Option<&LimitExceeded> {
+        self.error.as_ref()
+    }
+
+    /// Returns the underlying buffer if no limits were exceeded
+    pub fn finish(self) -> Result<B, LimitExceeded> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.buffer),
+        }
+    }
+}
+
+impl<B: Buffer> Buffer for LimitedBuffer<B> {
+    fn write(&mut self, bytes: &[u8]) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.len.checked_add(bytes.len()) {
+            Some(len) if len <= self.max_len => {
+                self.len = len;
+                self.buffer.write(bytes)
+            }
+            _ => {
+                self.error = Some(LimitExceeded::Len {
+                    limit: self.max_len,
+                })
+            }
+        }
+    }
+
+    fn on_event(&mut self, event: Event<'_>) {
+        if self.error.is_some() {
+            return;
+        }
+        match event {
+            Event::ListStart => {
+                self.depth = self.depth.saturating_add(1);
+                if self.depth > self.max_depth {
+                    self.error = Some(LimitExceeded::Depth {
+                        limit: self.max_depth,
+                    });
+                    return;
+                }
+            }
+            Event::ListEnd { .. } => self.depth = self.depth.saturating_sub(1),
+            Event::LeafStart | Event::LeafEnd { .. } | Event::Field { .. } => {}
+        }
+        self.buffer.on_event(event)
+    }
+}
+
+/// Error returned by [`LimitedBuffer`] when a limit is exceeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LimitExceeded {
+    /// Encoding is longer than `limit` bytes
+    Len {
+        /// Maximum size of the encoding in bytes
+        limit: usize,
+    },
+    /// Encoding is nested deeper than `limit` lists
+    Depth {
+        /// Maximum nesting depth
+        limit: usize,
+    },
+}
+
+impl core::fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Len { limit } => write!(f, "encoding is longer than {limit} bytes"),
+            Self::Depth { limit } => write!(f, "encoding is nested deeper than {limit} lists"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LimitExceeded {}
+
+/// Buffer that records a trace of every leaf and list written by the encoders
+///
+/// The trace lists values in the order they appear in the value (parents before children)
+/// along with their paths, lengths, tags, and byte offsets within the encoding. It makes it
+/// easy to find the first field in which two encodings diverge, which is hard to spot in the raw
+/// bytes as lengths are written after the values. Every write and [event](Buffer::on_event) is
+/// forwarded to the underlying buffer.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// use udigest::encoding::Trace;
+///
+/// #[derive(udigest::Digestable)]
+/// struct Person {
+///     name: &'static str,
+///     skills: Vec<&'static str>,
+/// }
+///
+/// let alice = Trace::of(&Person { name: "Alice", skills: vec!["math", "crypto"] });
+/// let bob = Trace::of(&Person { name: "Alice", skills: vec!["math", "cooking"] });
+///
+/// let (a, b) = alice.first_difference(&bob).unwrap();
+/// assert_eq!(a.unwrap().path, "$.skills[1]");
+/// assert_eq!(a.unwrap().bytes, b"crypto");
+/// assert_eq!(b.unwrap().bytes, b"cooking");
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct TraceBuffer<B> {
+    buffer: B,
+    offset: usize,
+    trace: Trace,
+    /// Lists that are being encoded
+    lists: alloc::vec::Vec<TraceFrame>,
+    /// Leaf that is being encoded. `Some(None)` means that leaf is a field name, which is
+    /// not recorded.
+    leaf: Option<Option<usize>>,
+    /// Entry which metadata is being written, so its end offset is not known yet
+    unfinished: Option<usize>,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+struct TraceFrame {
+    entry: usize,
+    items: usize,
+    field: Option<alloc::string::String>,
+    field_name_pending: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<B: Buffer> TraceBuffer<B> {
+    /// Wraps a buffer
+    pub fn new(buffer: B) -> Self {
+        Self {
+            buffer,
+            offset: 0,
+            trace: Trace::default(),
+            lists: alloc::vec::Vec::new(),
+            leaf: None,
+            unfinished: None,
+        }
+    }
+
+    /// Returns the trace recorded so far
+    ///
+    /// The end offset of the value that was finished last may not be known yet.
+    pub fn trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    /// Returns the underlying buffer and the recorded trace
+    pub fn finish(mut self) -> (B, Trace) {
+        self.finish_entry();
+        (self.buffer, self.trace)
+    }
+
+    fn finish_entry(&mut self) {
+        if let Some(i) = self.unfinished.take() {
+            self.trace.entries[i].end = self.offset;
+        }
+    }
+
+    /// Returns the path of the next value, or `None` if it's a name of the field
+    fn next_path(&mut self, is_leaf: bool) -> Option<alloc::string::String> {
+        let Some(frame) = self.lists.last_mut() else {
+            return Some("$".into());
+        };
+        let index = frame.items;
+        frame.items = frame.items.saturating_add(1);
+        if core::mem::take(&mut frame.field_name_pending) && is_leaf {
+            return None;
+        }
+        let parent = &self.trace.entries[frame.entry].path;
+        Some(match frame.field.take() {
+            Some(field) => alloc::format!("{parent}.{field}"),
+            None => alloc::format!("{parent}[{index}]"),
+        })
+    }
+
+    fn start_entry(&mut self, kind: TraceKind, path: alloc::string::String) -> usize {
+        self.trace.entries.push(TraceEntry {
+            path,
+            kind,
+            len: 0,
+            tag: None,
+            bytes: alloc::vec::Vec::new(),
+            start: self.offset,
+            end: self.offset,
+        });
+        self.trace.entries.len() - 1
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: Buffer> Buffer for TraceBuffer<B> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.offset = self.offset.saturating_add(bytes.len());
+        if let Some(Some(i)) = self.leaf {
+            self.trace.entries[i].bytes.extend_from_slice(bytes);
+        }
+        self.buffer.write(bytes)
+    }
+
+    fn on_event(&mut self, event: Event<'_>) {
+        self.finish_entry();
+        match event {
+            Event::LeafStart => {
+                let entry = self
+                    .next_path(true)
+                    .map(|path| self.start_entry(TraceKind::Leaf, path));
+                self.leaf = Some(entry);
+            }
+            Event::LeafEnd { len, tag } => {
+                if let Some(Some(i)) = self.leaf.take() {
+                    let entry = &mut self.trace.entries[i];
+                    entry.len = len;
+                    entry.tag = tag.map(<[u8]>::to_vec);
+                    self.unfinished = Some(i);
+                }
+            }
+            Event::ListStart => {
+                let path = self.next_path(false).unwrap_or_default();
+                let entry = self.start_entry(TraceKind::List, path);
+                self.lists.push(TraceFrame {
+                    entry,
+                    items: 0,
+                    field: None,
+                    field_name_pending: false,
+                });
+            }
+            Event::ListEnd { len, tag } => {
+                if let Some(frame) = self.lists.pop() {
+                    let entry = &mut self.trace.entries[frame.entry];
+                    entry.len = len;
+                    entry.tag = tag.map(<[u8]>::to_vec);
+                    self.unfinished = Some(frame.entry);
+                }
+            }
+            Event::Field { name } => {
+                if let Some(frame) = self.lists.last_mut() {
+                    frame.field = Some(alloc::string::String::from_utf8_lossy(name).into_owned());
+                    frame.field_name_pending = true;
+                }
+            }
+        }
+        self.buffer.on_event(event)
+    }
+}
+
+/// Trace of the encoding recorded by [`TraceBuffer`]
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    entries: alloc::vec::Vec<TraceEntry>,
+}
+
+#[cfg(feature = "alloc")]
+impl Trace {
+    /// Encodes the value and records its trace
+    pub fn of(value: &impl crate::Digestable) -> Self {
+        let mut buffer = TraceBuffer::new(LengthCounter::default());
+        value.unambiguously_encode(EncodeValue::new(&mut buffer));
+        buffer.finish().1
+    }
+
+    /// Returns recorded values, parents go before their children
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    /// Returns the first pair of values that differ in the two traces
+    ///
+    /// Values are compared by everything but byte offsets. If one trace is a prefix of another,
+    /// the missing value is `None`. Returns `None` if traces are identical.
+    pub fn first_difference<'t>(
+        &'t self,
+        other: &'t Trace,
+    ) -> Option<(Option<&'t TraceEntry>, Option<&'t TraceEntry>)> {
+        let len = self.entries.len().max(other.entries.len());
+        (0..len)
+            .map(|i| (self.entries.get(i), other.entries.get(i)))
+            .find(|(a, b)| match (a, b) {
+                (Some(a), Some(b)) => !a.same_value(b),
+                _ => true,
+            })
+    }
+}
+
+/// Renders every value on a separate line
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for Trace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.entries
+            .iter()
+            .try_for_each(|entry| writeln!(f, "{entry}"))
+    }
+}
+
+/// Value recorded in the [`Trace`]
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TraceEntry {
+    /// Path to the value: `$` is the root value, `.name` is a field of the structure, and `[i]`
+    /// is an item of the list, e.g. `$.skills[1]`
+    ///
+    /// Note that names of the fields take a place in the list too, so the second field of
+    /// a structure is its item `[3]`.
+    pub path: alloc::string::String,
+    /// Whether the value is a leaf or a list
+    pub kind: TraceKind,
+    /// Length of the leaf in bytes, or amount of items in the list
+    pub len: usize,
+    /// Domain separation tag
+    pub tag: Option<alloc::vec::Vec<u8>>,
+    /// Bytes of the leaf. Empty for lists.
+    pub bytes: alloc::vec::Vec<u8>,
+    /// Offset of the first byte of the value in the encoding
+    pub start: usize,
+    /// Offset right after the last byte of the value (including its metadata) in the encoding
+    pub end: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl TraceEntry {
+    fn same_value(&self, other: &Self) -> bool {
+        self.path == other.path
+            && self.kind == other.kind
+            && self.len == other.len
+            && self.tag == other.tag
+            && self.bytes == other.bytes
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        if let Some(tag) = &self.tag {
+            write!(f, "#")?;
+            crate::decoding::fmt_bytes(tag, f)?;
+            write!(f, " ")?;
+        }
+        match self.kind {
+            TraceKind::Leaf => crate::decoding::fmt_bytes(&self.bytes, f)?,
+            TraceKind::List => write!(f, "list of {} items", self.len)?,
+        }
+        write!(f, " @ {}..{}", self.start, self.end)
+    }
+}
+
+/// Kind of the value recorded in the [`Trace`]
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceKind {
+    /// Leaf (bytestring)
+    Leaf,
+    /// List of values
+    List,
+}
+
+/// Wraps [`std::io::Write`] and implements [`Buffer`]
+///
+/// [`Buffer::write`] can't fail, so the writer keeps track of the first I/O error instead:
+/// once any write fails, all subsequent writes are ignored, and the error is returned by
+/// [`IoWriter::finish`]. Make sure to always call `finish` to learn whether the encoding
+/// was fully written.
+///
+/// Writes are not buffered, so it's advised to wrap files and sockets into
+/// [`std::io::BufWriter`].
+///
+/// ```rust,no_run
+/// # fn main() -> std::io::Result<()> {
+/// let file = std::fs::File::create("encoding.bin")?;
+/// let mut writer = udigest::encoding::IoWriter::new(std::io::BufWriter::new(file));
+///
+/// let value = ("Alice", 24_u32);
+/// udigest::Digestable::unambiguously_encode(
+///     &value,
+///     udigest::encoding::EncodeValue::new(&mut writer),
+/// );
+///
+/// let mut file = writer.finish()?;
+/// std::io::Write::flush(&mut file)?;
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "std")]
+pub struct IoWriter<W: std::io::Write> {
+    writer: W,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> IoWriter<W> {
+    /// Wraps the writer
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Returns the first error occurred while writing, if any
+    pub fn error(&self) -> Option<&std::io::Error> {
+        self.error.as_ref()
+    }
+
+    /// Returns the underlying writer, or the first error occurred while writing
+    ///
+    /// Note that the writer is not flushed.
+    pub fn finish(self) -> std::io::Result<W> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.writer),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Buffer for IoWriter<W> {
+    fn write(&mut self, bytes: &[u8]) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(err) = self.writer.write_all(bytes) {
+            self.error = Some(err)
+        }
+    }
 }
 
 /// Wraps [`digest::Digest`] and implements [`Buffer`]
@@ -205,6 +1216,13 @@ impl<D: digest::Digest> Buffer for BufferDigest<D> {
     }
 }
 
+#[cfg(all(feature = "digest", feature = "zeroize"))]
+impl<D: digest::Digest + zeroize::Zeroize> zeroize::Zeroize for BufferDigest<D> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0)
+    }
+}
+
 /// Wraps [`digest::Update`] and implements [`Buffer`]
 #[cfg(feature = "digest")]
 pub struct BufferUpdate<D: digest::Update>(pub D);
@@ -216,32 +1234,82 @@ impl<D: digest::Update> Buffer for BufferUpdate<D> {
     }
 }
 
+#[cfg(all(feature = "digest", feature = "zeroize"))]
+impl<D: digest::Update + zeroize::Zeroize> zeroize::Zeroize for BufferUpdate<D> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0)
+    }
+}
+
+/// Wraps [`digest::Mac`] and implements [`Buffer`]
+#[cfg(feature = "mac")]
+pub struct BufferMac<M: digest::Mac>(pub M);
+
+#[cfg(feature = "mac")]
+impl<M: digest::Mac> Buffer for BufferMac<M> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes)
+    }
+}
+
+#[cfg(all(feature = "mac", feature = "zeroize"))]
+impl<M: digest::Mac + zeroize::Zeroize> zeroize::Zeroize for BufferMac<M> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0)
+    }
+}
+
+#[cfg(feature = "blake3")]
+impl Buffer for blake3::Hasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
 /// Encodes a value
 ///
 /// Can be used to encode (only) a single value. Value can be a leaf (bytestring) or a list of values.
 #[must_use = "encoder must be used to encode a value"]
 pub struct EncodeValue<'b, B: Buffer> {
     buffer: Option<&'b mut B>,
+    format: Format,
 }
 
 impl<'b, B: Buffer> EncodeValue<'b, B> {
     /// Constructs an encoder
     pub fn new(buffer: &'b mut B) -> Self {
+        Self::new_with_format(buffer, Format::V1)
+    }
+
+    /// Constructs an encoder that uses specified encoding format
+    pub fn new_with_format(buffer: &'b mut B, format: Format) -> Self {
         Self {
             buffer: Some(buffer),
+            format,
         }
     }
 
+    /// Returns encoding format
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
     /// Encodes a list
     pub fn encode_list(mut self) -> EncodeList<'b, B> {
         #[allow(clippy::expect_used)]
-        EncodeList::new(self.buffer.take().expect("buffer must be available"))
+        EncodeList::new_with_format(
+            self.buffer.take().expect("buffer must be available"),
+            self.format,
+        )
     }
 
     /// Encodes a leaf (bytestring)
     pub fn encode_leaf(mut self) -> EncodeLeaf<'b, B> {
         #[allow(clippy::expect_used)]
-        EncodeLeaf::new(self.buffer.take().expect("buffer must be available"))
+        EncodeLeaf::new_with_format(
+            self.buffer.take().expect("buffer must be available"),
+            self.format,
+        )
     }
 
     /// Encodes a leaf value
@@ -251,12 +1319,68 @@ impl<'b, B: Buffer> EncodeValue<'b, B> {
         self.encode_leaf().chain(value);
     }
 
+    /// Encodes a digestable value
+    ///
+    /// Alias to `value.unambiguously_encode(self)`. Returns [`Encoded`] proof, which is
+    /// useful within [`EncodeStruct::add_field_with`].
+    pub fn encode(self, value: &(impl crate::Digestable + ?Sized)) -> Encoded<'b> {
+        value.unambiguously_encode(self);
+        Encoded::new()
+    }
+
+    /// Encodes a leaf (bytestring) using `encode`
+    ///
+    /// Leaf is finalized once `encode` returns. Returns [`Encoded`] proof, which is useful
+    /// within [`EncodeStruct::add_field_with`].
+    pub fn encode_leaf_with(self, encode: impl FnOnce(&mut EncodeLeaf<'b, B>)) -> Encoded<'b> {
+        let mut leaf = self.encode_leaf();
+        encode(&mut leaf);
+        leaf.finish();
+        Encoded::new()
+    }
+
+    /// Encodes a list using `encode`
+    ///
+    /// List is finalized once `encode` returns. Returns [`Encoded`] proof, which is useful
+    /// within [`EncodeStruct::add_field_with`].
+    pub fn encode_list_with(self, encode: impl FnOnce(&mut EncodeList<'b, B>)) -> Encoded<'b> {
+        let mut list = self.encode_list();
+        encode(&mut list);
+        list.finish();
+        Encoded::new()
+    }
+
+    /// Encodes a struct using `encode`
+    ///
+    /// Struct is finalized once `encode` returns. Returns [`Encoded`] proof, which is useful
+    /// within [`EncodeStruct::add_field_with`].
+    pub fn encode_struct_with(self, encode: impl FnOnce(&mut EncodeStruct<'b, B>)) -> Encoded<'b> {
+        let mut s = self.encode_struct();
+        encode(&mut s);
+        s.finish();
+        Encoded::new()
+    }
+
+    /// Encodes a value using `encode`, and returns [`Encoded`] proof
+    ///
+    /// Used by derive macro to encode fields with `#[udigest(with = ...)]` and
+    /// `#[udigest(as = ...)]` attributes. Unlike other methods returning the proof, it doesn't
+    /// guarantee that the encoder is used: if `encode` drops it, an empty leaf is encoded.
+    #[doc(hidden)]
+    pub fn __encode_with(self, encode: impl FnOnce(Self)) -> Encoded<'b> {
+        encode(self);
+        Encoded::new()
+    }
+
     /// Encodes a struct
     ///
     /// Struct is represented as a list: `[field_name1, field_value1, ...]`
     pub fn encode_struct(mut self) -> EncodeStruct<'b, B> {
         #[allow(clippy::expect_used)]
-        EncodeStruct::new(self.buffer.take().expect("buffer must be available"))
+        EncodeStruct::new_with_format(
+            self.buffer.take().expect("buffer must be available"),
+            self.format,
+        )
     }
 
     /// Encodes an enum
@@ -264,7 +1388,10 @@ impl<'b, B: Buffer> EncodeValue<'b, B> {
     /// Enum is represented as a list: `["variant", variant_name, field_name1, field_value1, ...]`
     pub fn encode_enum(mut self) -> EncodeEnum<'b, B> {
         #[allow(clippy::expect_used)]
-        EncodeEnum::new(self.buffer.take().expect("buffer must be available"))
+        EncodeEnum::new_with_format(
+            self.buffer.take().expect("buffer must be available"),
+            self.format,
+        )
     }
 }
 
@@ -272,7 +1399,28 @@ impl<'b, B: Buffer> Drop for EncodeValue<'b, B> {
     fn drop(&mut self) {
         if let Some(buffer) = &mut self.buffer {
             // buffer is not consumed -- we write an empty leaf
-            EncodeLeaf::new(*buffer).finish()
+            EncodeLeaf::new_with_format(*buffer, self.format).finish()
+        }
+    }
+}
+
+/// Proof that a value has been encoded
+///
+/// Can only be obtained by consuming an [`EncodeValue<'b, _>`](EncodeValue) via
+/// [`encode`](EncodeValue::encode) or one of `encode_*_with` methods. The proof is bound to
+/// the lifetime `'b` of the encoder it was obtained from, so proof produced by an encoder of
+/// a different value (or buffer) can't be passed off as a proof for this one. Requiring the
+/// proof (as [`EncodeStruct::add_field_with`] does) makes forgetting to encode a value
+/// a compile-time error.
+#[derive(Debug)]
+pub struct Encoded<'b> {
+    _lifetime: core::marker::PhantomData<fn(&'b ()) -> &'b ()>,
+}
+
+impl Encoded<'_> {
+    fn new() -> Self {
+        Self {
+            _lifetime: core::marker::PhantomData,
         }
     }
 }
@@ -285,20 +1433,30 @@ impl<'b, B: Buffer> Drop for EncodeValue<'b, B> {
 pub struct EncodeEnum<'b, B: Buffer> {
     buffer: &'b mut B,
     tag: Option<&'b [u8]>,
+    format: Format,
 }
 
 impl<'b, B: Buffer> EncodeEnum<'b, B> {
     /// Constructs an encoder
     pub fn new(buffer: &'b mut B) -> Self {
-        Self { buffer, tag: None }
+        Self::new_with_format(buffer, Format::V1)
+    }
+
+    /// Constructs an encoder that uses specified encoding format
+    pub fn new_with_format(buffer: &'b mut B, format: Format) -> Self {
+        Self {
+            buffer,
+            tag: None,
+            format,
+        }
     }
 
     /// Encodes a variant name
     ///
     /// Returns a structure encoder that can be used to encode any fields the variant may have
     pub fn with_variant(self, variant_name: impl AsRef<[u8]>) -> EncodeStruct<'b, B> {
-        let mut s = EncodeStruct::new(self.buffer);
-        s.add_field("variant").encode_leaf().chain(variant_name);
+        let mut s = EncodeStruct::new_with_format(self.buffer, self.format);
+        s.add_leaf_field("variant", variant_name);
         if let Some(tag) = self.tag {
             s.set_tag(tag)
         }
@@ -329,8 +1487,13 @@ pub struct EncodeStruct<'b, B: Buffer> {
 impl<'b, B: Buffer> EncodeStruct<'b, B> {
     /// Constructs an encoder
     pub fn new(buffer: &'b mut B) -> Self {
+        Self::new_with_format(buffer, Format::V1)
+    }
+
+    /// Constructs an encoder that uses specified encoding format
+    pub fn new_with_format(buffer: &'b mut B, format: Format) -> Self {
         Self {
-            list: EncodeList::new(buffer),
+            list: EncodeList::new_with_format(buffer, format),
         }
     }
 
@@ -351,12 +1514,122 @@ impl<'b, B: Buffer> EncodeStruct<'b, B> {
 
     /// Adds a fields to the structure
     ///
-    /// Returns an encoder that shall be used to encode the fields value
-    pub fn add_field(&mut self, field_name: impl AsRef<[u8]>) -> EncodeValue<B> {
+    /// Returns an encoder that shall be used to encode the fields value. If the encoder is
+    /// dropped without being used, the value is encoded as an empty leaf. Use
+    /// [`add_field_with`](Self::add_field_with) to make sure that the value is always encoded.
+    #[deprecated(
+        since = "0.2.3",
+        note = "forgetting to use returned encoder silently encodes an empty leaf, use \
+            `add_field_with`, `add_leaf_field`, `add_struct_field`, or `add_list_field` instead"
+    )]
+    pub fn add_field(&mut self, field_name: impl AsRef<[u8]>) -> EncodeValue<'_, B> {
+        self.field(field_name)
+    }
+
+    fn field(&mut self, field_name: impl AsRef<[u8]>) -> EncodeValue<'_, B> {
+        let field_name = field_name.as_ref();
+        self.list.buffer.on_event(Event::Field { name: field_name });
         self.list.add_leaf().chain(field_name);
         self.list.add_item()
     }
 
+    /// Adds a field to the structure, and encodes its value using `encode`
+    ///
+    /// Closure must return [`Encoded`] proof, which can only be obtained by consuming the
+    /// encoder of this field, so forgetting to encode the field value doesn't compile.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use udigest::encoding::{EncodeValue, Encoded};
+    ///
+    /// struct Person {
+    ///     name: String,
+    ///     nickname: Option<String>,
+    ///     age: u32,
+    /// }
+    ///
+    /// impl udigest::Digestable for Person {
+    ///     fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: EncodeValue<B>) {
+    ///         let mut s = encoder.encode_struct();
+    ///         s.add_field_with("name", |value| {
+    ///             value.encode_leaf_with(|leaf| leaf.update(self.name.as_bytes()))
+    ///         });
+    ///         s.add_field_with("nickname", |value| value.encode(&self.nickname));
+    ///         s.add_field_with("age", |value| value.encode(&self.age));
+    ///     }
+    /// }
+    /// # let _ = udigest::encode_to_vec(&Person { name: "Alice".into(), nickname: None, age: 24 });
+    /// # }
+    /// ```
+    ///
+    /// Value encoder that was not used doesn't compile:
+    ///
+    /// ```rust,compile_fail
+    /// # use udigest::encoding::EncodeValue;
+    /// # struct Person { age: u32 }
+    /// impl udigest::Digestable for Person {
+    ///     fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: EncodeValue<B>) {
+    ///         let mut s = encoder.encode_struct();
+    ///         s.add_field_with("age", |_value| {});
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Neither does a proof obtained from an encoder of another value:
+    ///
+    /// ```rust,compile_fail
+    /// # use udigest::encoding::EncodeValue;
+    /// # struct Person { age: u32 }
+    /// # struct Other;
+    /// # impl udigest::Buffer for Other { fn write(&mut self, _: &[u8]) {} }
+    /// impl udigest::Digestable for Person {
+    ///     fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: EncodeValue<B>) {
+    ///         let mut other = Other;
+    ///         let mut s = encoder.encode_struct();
+    ///         s.add_field_with("age", |_value| EncodeValue::new(&mut other).encode(&self.age));
+    ///     }
+    /// }
+    /// ```
+    pub fn add_field_with(
+        &mut self,
+        field_name: impl AsRef<[u8]>,
+        encode: impl for<'a> FnOnce(EncodeValue<'a, B>) -> Encoded<'a>,
+    ) {
+        let _: Encoded = encode(self.field(field_name));
+    }
+
+    /// Adds a field to the structure which value is a leaf (bytestring)
+    ///
+    /// Alias to `.add_field_with(field_name, |value| value.encode_leaf_with(...))`
+    pub fn add_leaf_field(&mut self, field_name: impl AsRef<[u8]>, bytes: impl AsRef<[u8]>) {
+        self.field(field_name).encode_leaf_value(bytes)
+    }
+
+    /// Adds a field to the structure which value is a structure
+    ///
+    /// Alias to `.add_field_with(field_name, |value| value.encode_struct_with(...))`
+    pub fn add_struct_field(&mut self, field_name: impl AsRef<[u8]>) -> EncodeStruct<'_, B> {
+        self.field(field_name).encode_struct()
+    }
+
+    /// Adds a field to the structure which value is a list
+    ///
+    /// Alias to `.add_field_with(field_name, |value| value.encode_list_with(...))`
+    pub fn add_list_field(&mut self, field_name: impl AsRef<[u8]>) -> EncodeList<'_, B> {
+        self.field(field_name).encode_list()
+    }
+
+    /// Adds a field to the structure which value is a list of all items of `iter`
+    ///
+    /// Alias to `.add_list_field(field_name).extend(iter)`
+    pub fn add_iter_field(
+        &mut self,
+        field_name: impl AsRef<[u8]>,
+        iter: impl IntoIterator<Item = impl crate::Digestable>,
+    ) {
+        self.add_list_field(field_name).extend(iter)
+    }
+
     /// Finalizes the encoding, puts the necessary metadata to the buffer
     ///
     /// It's an alias to dropping the encoder
@@ -368,15 +1641,23 @@ pub struct EncodeLeaf<'b, B: Buffer> {
     buffer: &'b mut B,
     len: usize,
     tag: Option<&'b [u8]>,
+    format: Format,
 }
 
 impl<'b, B: Buffer> EncodeLeaf<'b, B> {
     /// Constructs a leaf
     pub fn new(buffer: &'b mut B) -> Self {
+        Self::new_with_format(buffer, Format::V1)
+    }
+
+    /// Constructs a leaf that uses specified encoding format
+    pub fn new_with_format(buffer: &'b mut B, format: Format) -> Self {
+        buffer.on_event(Event::LeafStart);
         Self {
             buffer,
             len: 0,
             tag: None,
+            format,
         }
     }
 
@@ -419,6 +1700,73 @@ impl<'b, B: Buffer> EncodeLeaf<'b, B> {
             .expect("leaf length overflows `usize`")
     }
 
+    /// Chains all bytes read from `reader`
+    ///
+    /// Reads the whole `reader` until EOF in chunks, so large inputs like file contents can be
+    /// included into the leaf without loading them into memory. Encoded value is the same as if
+    /// all the bytes were chained at once.
+    ///
+    /// If reading fails, the error is returned and the encoder is dropped, so the leaf is
+    /// finalized with the bytes read so far. The encoding is incomplete in this case and must
+    /// be discarded.
+    ///
+    /// ```rust,no_run
+    /// struct File {
+    ///     name: String,
+    ///     path: std::path::PathBuf,
+    /// }
+    ///
+    /// impl File {
+    ///     fn digest(&self) -> std::io::Result<sha2::digest::Output<sha2::Sha256>> {
+    ///         use sha2::Digest;
+    ///         use udigest::encoding::{BufferDigest, EncodeStruct};
+    ///
+    ///         let contents = std::fs::File::open(&self.path)?;
+    ///
+    ///         let mut hash = BufferDigest(sha2::Sha256::new());
+    ///         let mut s = EncodeStruct::new(&mut hash);
+    ///         s.add_leaf_field("name", &self.name);
+    ///         s.add_field("contents").encode_leaf().chain_reader(contents)?;
+    ///         s.finish();
+    ///         Ok(hash.0.finalize())
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn chain_reader(mut self, reader: impl std::io::Read) -> std::io::Result<Self> {
+        self.update_from_reader(reader)?;
+        Ok(self)
+    }
+
+    /// Appends all bytes read from `reader`, returns amount of bytes read
+    ///
+    /// Same as [`chain_reader`](Self::chain_reader), but takes `&mut self`.
+    ///
+    /// ## Panic
+    /// Panics if total length of the leaf overflows `usize`
+    #[cfg(feature = "std")]
+    pub fn update_from_reader(&mut self, mut reader: impl std::io::Read) -> std::io::Result<u64> {
+        let mut chunk = [0u8; 8192];
+        let mut total = 0u64;
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => return Ok(total),
+                Ok(n) => {
+                    let bytes = chunk.get(..n).ok_or_else(|| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            "reader returned more bytes than requested",
+                        )
+                    })?;
+                    self.update(bytes);
+                    total = total.saturating_add(n as u64);
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
     /// Finalizes the encoding, puts the necessary metadata to the buffer
     ///
     /// It's an alias to dropping the encoder
@@ -427,16 +1775,12 @@ impl<'b, B: Buffer> EncodeLeaf<'b, B> {
 
 impl<'b, B: Buffer> Drop for EncodeLeaf<'b, B> {
     fn drop(&mut self) {
-        encode_len(self.buffer, self.len);
-
-        if let Some(tag) = self.tag {
-            self.buffer.write(tag);
-            encode_len(self.buffer, tag.len());
-
-            self.buffer.write(&[LEAF_CTX]);
-        } else {
-            self.buffer.write(&[LEAF]);
-        }
+        self.buffer.on_event(Event::LeafEnd {
+            len: self.len,
+            tag: self.tag,
+        });
+        self.format
+            .write_leaf_metadata(self.buffer, self.len, self.tag);
     }
 }
 
@@ -445,15 +1789,23 @@ pub struct EncodeList<'b, B: Buffer> {
     buffer: &'b mut B,
     len: usize,
     tag: Option<&'b [u8]>,
+    format: Format,
 }
 
 impl<'b, B: Buffer> EncodeList<'b, B> {
     /// Constructs an encoder
     pub fn new(buffer: &'b mut B) -> Self {
+        Self::new_with_format(buffer, Format::V1)
+    }
+
+    /// Constructs an encoder that uses specified encoding format
+    pub fn new_with_format(buffer: &'b mut B, format: Format) -> Self {
+        buffer.on_event(Event::ListStart);
         Self {
             buffer,
             len: 0,
             tag: None,
+            format,
         }
     }
 
@@ -479,25 +1831,94 @@ impl<'b, B: Buffer> EncodeList<'b, B> {
     /// ## Panic
     /// Panics if list length overflows `usize`
     #[allow(clippy::expect_used)]
-    pub fn add_item(&mut self) -> EncodeValue<B> {
+    pub fn add_item(&mut self) -> EncodeValue<'_, B> {
         self.len = self.len.checked_add(1).expect("list len overflows usize");
-        EncodeValue::new(self.buffer)
+        EncodeValue::new_with_format(self.buffer, self.format)
     }
 
     /// Adds a leaf (bytestring) to the list
     ///
     /// Alias to `.add_item().encode_leaf()`
-    pub fn add_leaf(&mut self) -> EncodeLeaf<B> {
+    pub fn add_leaf(&mut self) -> EncodeLeaf<'_, B> {
         self.add_item().encode_leaf()
     }
 
     /// Adds a sublist to the list
     ///
     /// Alias to `.add_item().encode_list()`
-    pub fn add_list(&mut self) -> EncodeList<B> {
+    pub fn add_list(&mut self) -> EncodeList<'_, B> {
         self.add_item().encode_list()
     }
 
+    /// Adds every item of `iter` to the list
+    ///
+    /// Alias to calling `.add_item()` for every item
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// struct Team {
+    ///     members: Vec<String>,
+    /// }
+    ///
+    /// impl udigest::Digestable for Team {
+    ///     fn unambiguously_encode<B: udigest::Buffer>(
+    ///         &self,
+    ///         encoder: udigest::encoding::EncodeValue<B>,
+    ///     ) {
+    ///         let mut list = encoder.encode_list();
+    ///         list.extend(&self.members);
+    ///     }
+    /// }
+    /// # assert_eq!(
+    /// #     udigest::encode_to_vec(&Team { members: vec!["Alice".into(), "Bob".into()] }),
+    /// #     udigest::encode_to_vec(&["Alice", "Bob"]),
+    /// # );
+    /// # }
+    /// ```
+    pub fn extend(&mut self, iter: impl IntoIterator<Item = impl crate::Digestable>) {
+        for item in iter {
+            item.unambiguously_encode(self.add_item())
+        }
+    }
+
+    /// Encodes every item of `iter` in parallel and adds them to the list in original order
+    ///
+    /// Items are encoded into [`Detached`] values using `rayon`, then spliced into the list,
+    /// so the encoding is exactly the same as of [`extend`](Self::extend).
+    ///
+    /// ```rust
+    /// let records: Vec<(u64, String)> = (0..1000).map(|i| (i, format!("record {i}"))).collect();
+    ///
+    /// let mut bytes = vec![];
+    /// let mut list = udigest::encoding::EncodeList::new(&mut bytes);
+    /// list.extend_par(&records);
+    /// list.finish();
+    ///
+    /// assert_eq!(bytes, udigest::encode_to_vec(&records));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn extend_par(
+        &mut self,
+        iter: impl rayon::iter::IntoParallelIterator<Item = impl crate::Digestable>,
+    ) {
+        use rayon::iter::ParallelIterator;
+
+        let items: alloc::vec::Vec<Detached> = iter
+            .into_par_iter()
+            .map(|item| Detached::new(&item))
+            .collect();
+        self.extend(&items)
+    }
+
+    /// Adds every item of `iter` to the list as a leaf (bytestring)
+    ///
+    /// Alias to calling `.add_item().encode_leaf_value(item)` for every item
+    pub fn extend_leaves(&mut self, iter: impl IntoIterator<Item = impl AsRef<[u8]>>) {
+        for item in iter {
+            self.add_item().encode_leaf_value(item)
+        }
+    }
+
     /// Finalizes the encoding, puts the necessary metadata to the buffer
     ///
     /// It's an alias to dropping the encoder
@@ -506,15 +1927,217 @@ impl<'b, B: Buffer> EncodeList<'b, B> {
 
 impl<'b, B: Buffer> Drop for EncodeList<'b, B> {
     fn drop(&mut self) {
-        encode_len(self.buffer, self.len);
+        self.buffer.on_event(Event::ListEnd {
+            len: self.len,
+            tag: self.tag,
+        });
+        self.format
+            .write_list_metadata(self.buffer, self.len, self.tag);
+    }
+}
 
-        if let Some(tag) = self.tag {
-            self.buffer.write(tag);
-            encode_len(self.buffer, tag.len());
+/// Value encoded separately from its parent, which can be spliced into the parent later
+///
+/// Encoders borrow the buffer of the parent, so children of a value have to be encoded
+/// in order, one after another. `Detached` owns its buffer: children can be encoded out
+/// of order, or in parallel, and then put into the parent list or struct. Splicing
+/// produces exactly the same encoding (and reports the same [events](Event) to the buffer)
+/// as if the value was encoded directly, in whichever [`Format`] the parent uses.
+///
+/// ```rust
+/// use udigest::encoding::{Detached, EncodeValue};
+///
+/// struct Batch {
+///     records: Vec<(u64, String)>,
+/// }
+///
+/// impl udigest::Digestable for Batch {
+///     fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: EncodeValue<B>) {
+///         // Records are encoded in parallel
+///         let records = std::thread::scope(|scope| {
+///             let handles = self
+///                 .records
+///                 .iter()
+///                 .map(|record| scope.spawn(|| Detached::new(record)))
+///                 .collect::<Vec<_>>();
+///             handles
+///                 .into_iter()
+///                 .map(|handle| handle.join().unwrap())
+///                 .collect::<Vec<_>>()
+///         });
+///
+///         let mut list = encoder.encode_list();
+///         list.extend(&records);
+///     }
+/// }
+///
+/// let records: Vec<_> = (0..4).map(|i| (i, format!("record {i}"))).collect();
+/// assert_eq!(
+///     udigest::encode_to_vec(&Batch { records: records.clone() }),
+///     udigest::encode_to_vec(&records),
+/// );
+/// ```
+///
+/// Only bytes of leaves and the structure of the value are stored, so it takes roughly as
+/// much memory as the encoding of the value.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct Detached {
+    buffer: DetachedBuffer,
+}
 
-            self.buffer.write(&[LIST_CTX]);
-        } else {
-            self.buffer.write(&[LIST])
+#[cfg(feature = "alloc")]
+impl Detached {
+    /// Encodes a value
+    pub fn new(value: &(impl crate::Digestable + ?Sized)) -> Self {
+        Self::encode_with(|encoder| value.unambiguously_encode(encoder))
+    }
+
+    /// Encodes a value using provided encoder
+    ///
+    /// Useful for encoding values that don't implement [`Digestable`](crate::Digestable),
+    /// like a part of a structure.
+    ///
+    /// ```rust
+    /// use udigest::encoding::Detached;
+    ///
+    /// let fields = Detached::encode_with(|encoder| {
+    ///     let mut s = encoder.encode_struct();
+    ///     s.add_leaf_field("name", "Alice");
+    ///     s.add_leaf_field("age", [24]);
+    /// });
+    /// # let _ = fields;
+    /// ```
+    pub fn encode_with(encode: impl FnOnce(EncodeValue<'_, DetachedBuffer>)) -> Self {
+        let mut buffer = DetachedBuffer::new();
+        encode(EncodeValue::new(&mut buffer));
+        Self { buffer }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl crate::Digestable for Detached {
+    fn unambiguously_encode<B: Buffer>(&self, mut encoder: EncodeValue<B>) {
+        let format = encoder.format;
+        let Some(buffer) = encoder.buffer.take() else {
+            return;
+        };
+        let bytes = &self.buffer.bytes;
+        let mut offset = 0;
+        for (at, event) in &self.buffer.events {
+            buffer.write(&bytes[offset..*at]);
+            offset = *at;
+            let event = event.as_event();
+            buffer.on_event(event);
+            match event {
+                Event::LeafEnd { len, tag } => format.write_leaf_metadata(buffer, len, tag),
+                Event::ListEnd { len, tag } => format.write_list_metadata(buffer, len, tag),
+                Event::LeafStart | Event::ListStart | Event::Field { .. } => {}
+            }
+        }
+    }
+}
+
+/// Buffer of [`Detached`] value
+///
+/// Stores bytes of leaves and the events, but not the metadata, which is written again when
+/// the value is spliced into the parent. It can't be constructed outside of this crate, so it
+/// can only be written via [`Detached::encode_with`].
+///
+/// ```rust,compile_fail
+/// let buffer = udigest::encoding::DetachedBuffer::default();
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct DetachedBuffer {
+    /// Bytes of all leaves
+    bytes: alloc::vec::Vec<u8>,
+    /// Events and offsets in `bytes` at which they occurred
+    events: alloc::vec::Vec<(usize, OwnedEvent)>,
+    /// Whether bytes of a leaf are being written
+    in_leaf: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl DetachedBuffer {
+    fn new() -> Self {
+        Self {
+            bytes: alloc::vec::Vec::new(),
+            events: alloc::vec::Vec::new(),
+            in_leaf: false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Buffer for DetachedBuffer {
+    fn write(&mut self, bytes: &[u8]) {
+        // metadata is written when the value is spliced
+        if self.in_leaf {
+            self.bytes.extend_from_slice(bytes)
+        }
+    }
+
+    fn on_event(&mut self, event: Event<'_>) {
+        let event = match event {
+            Event::LeafStart => {
+                self.in_leaf = true;
+                OwnedEvent::LeafStart
+            }
+            Event::LeafEnd { len, tag } => {
+                self.in_leaf = false;
+                OwnedEvent::LeafEnd {
+                    len,
+                    tag: tag.map(<[u8]>::to_vec),
+                }
+            }
+            Event::ListStart => OwnedEvent::ListStart,
+            Event::ListEnd { len, tag } => OwnedEvent::ListEnd {
+                len,
+                tag: tag.map(<[u8]>::to_vec),
+            },
+            Event::Field { name } => OwnedEvent::Field {
+                name: name.to_vec(),
+            },
+        };
+        self.events.push((self.bytes.len(), event))
+    }
+}
+
+/// [`Event`] that owns its data
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+enum OwnedEvent {
+    LeafStart,
+    LeafEnd {
+        len: usize,
+        tag: Option<alloc::vec::Vec<u8>>,
+    },
+    ListStart,
+    ListEnd {
+        len: usize,
+        tag: Option<alloc::vec::Vec<u8>>,
+    },
+    Field {
+        name: alloc::vec::Vec<u8>,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl OwnedEvent {
+    fn as_event(&self) -> Event<'_> {
+        match self {
+            OwnedEvent::LeafStart => Event::LeafStart,
+            OwnedEvent::LeafEnd { len, tag } => Event::LeafEnd {
+                len: *len,
+                tag: tag.as_deref(),
+            },
+            OwnedEvent::ListStart => Event::ListStart,
+            OwnedEvent::ListEnd { len, tag } => Event::ListEnd {
+                len: *len,
+                tag: tag.as_deref(),
+            },
+            OwnedEvent::Field { name } => Event::Field { name },
         }
     }
 }
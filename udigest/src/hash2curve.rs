@@ -0,0 +1,101 @@
+//! # Hashing structured data to field elements and curve points
+//!
+//! This module implements hash-to-field and hash-to-curve ([RFC 9380]) for curves that
+//! implement [`elliptic_curve`] traits (e.g. `k256`, `p256`). Unambiguous encoding of the value is
+//! used as the message, so structured data can be deterministically mapped to a scalar or a
+//! curve point.
+//!
+//! Message expansion is defined by `X`, which is either [`ExpandMsgXmd`] (for fixed-output hash
+//! functions like sha2-256) or [`ExpandMsgXof`] (for extendable-output functions like shake-256).
+//! `dst` is a domain separation tag, see [RFC 9380 section 3.1] for guidance on choosing it.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")] {
+//! use elliptic_curve::hash2curve::ExpandMsgXmd;
+//!
+//! #[derive(udigest::Digestable)]
+//! struct Message<'a> {
+//!     session_id: u64,
+//!     text: &'a str,
+//! }
+//! let message = Message {
+//!     session_id: 1,
+//!     text: "Hello, world!",
+//! };
+//!
+//! let point = udigest::hash2curve::hash_to_curve::<k256::Secp256k1, ExpandMsgXmd<sha2::Sha256>>(
+//!     &message,
+//!     b"udigest-example-V01-CS01-with-secp256k1_XMD:SHA-256_SSWU_RO_",
+//! )?;
+//! let scalar = udigest::hash2curve::hash_to_scalar::<k256::Secp256k1, ExpandMsgXmd<sha2::Sha256>>(
+//!     &message,
+//!     b"udigest-example-V01-CS01-with-secp256k1_XMD:SHA-256_SSWU_RO_",
+//! )?;
+//! # }
+//! # Ok::<_, elliptic_curve::Error>(())
+//! ```
+//!
+//! Message expansion needs the whole message at once, so the encoding is buffered in memory.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+//! [RFC 9380 section 3.1]: https://www.rfc-editor.org/rfc/rfc9380.html#section-3.1
+//! [`ExpandMsgXmd`]: elliptic_curve::hash2curve::ExpandMsgXmd
+//! [`ExpandMsgXof`]: elliptic_curve::hash2curve::ExpandMsgXof
+
+use ::elliptic_curve::{
+    group::cofactor::CofactorGroup,
+    hash2curve::{ExpandMsg, FromOkm, GroupDigest},
+    ProjectivePoint, Result,
+};
+
+use crate::Digestable;
+
+/// Hashes the `value` to field elements, filling the `out`
+pub fn hash_to_field<X, T>(value: &impl Digestable, dst: &[u8], out: &mut [T]) -> Result<()>
+where
+    X: for<'a> ExpandMsg<'a>,
+    T: FromOkm + Default,
+{
+    let msg = crate::encode_to_vec(value);
+    ::elliptic_curve::hash2curve::hash_to_field::<X, T>(&[&msg], &[dst], out)
+}
+
+/// Hashes the `value` to a scalar
+pub fn hash_to_scalar<C, X>(value: &impl Digestable, dst: &[u8]) -> Result<C::Scalar>
+where
+    C: GroupDigest,
+    C::Scalar: FromOkm,
+    ProjectivePoint<C>: CofactorGroup,
+    X: for<'a> ExpandMsg<'a>,
+{
+    let msg = crate::encode_to_vec(value);
+    C::hash_to_scalar::<X>(&[&msg], &[dst])
+}
+
+/// Hashes the `value` to a curve point
+///
+/// Implements `hash_to_curve` routine: the output distribution is statistically close to
+/// uniform.
+pub fn hash_to_curve<C, X>(value: &impl Digestable, dst: &[u8]) -> Result<ProjectivePoint<C>>
+where
+    C: GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    X: for<'a> ExpandMsg<'a>,
+{
+    let msg = crate::encode_to_vec(value);
+    C::hash_from_bytes::<X>(&[&msg], &[dst])
+}
+
+/// Encodes the `value` to a curve point
+///
+/// Implements `encode_to_curve` routine: it's faster than [`hash_to_curve`], but the output
+/// distribution is not uniform.
+pub fn encode_to_curve<C, X>(value: &impl Digestable, dst: &[u8]) -> Result<ProjectivePoint<C>>
+where
+    C: GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    X: for<'a> ExpandMsg<'a>,
+{
+    let msg = crate::encode_to_vec(value);
+    C::encode_from_bytes::<X>(&[&msg], &[dst])
+}
@@ -0,0 +1,158 @@
+use crate::{encoding, Digestable};
+
+/// Incrementally digests a list of structured data
+///
+/// Values are pushed one by one, so they don't need to be collected before hashing. Digest is
+/// the same as of encoding a list of pushed values tagged with `tag`. [`Hasher::default()`]
+/// uses the same tag as [`hash_iter`](crate::hash_iter), so it outputs the same digest for the
+/// same values.
+///
+/// ```rust
+/// let mut hasher = udigest::Hasher::<sha2::Sha256>::default();
+/// for chunk in ["Alice", "Bob", "Carol"] {
+///     hasher.push(&chunk);
+/// }
+/// let hash = hasher.finalize();
+///
+/// assert_eq!(hash, udigest::hash_iter::<sha2::Sha256>(["Alice", "Bob", "Carol"]));
+/// ```
+pub struct Hasher<'t, D: digest::Digest> {
+    hash: encoding::BufferDigest<D>,
+    len: usize,
+    tag: &'t [u8],
+}
+
+impl<'t, D: digest::Digest> Hasher<'t, D> {
+    /// Constructs a hasher of a list with domain separation `tag`
+    pub fn new(tag: &'t [u8]) -> Self {
+        Self {
+            hash: encoding::BufferDigest(D::new()),
+            len: 0,
+            tag,
+        }
+    }
+
+    /// Appends a value to the list
+    ///
+    /// ## Panic
+    /// Panics if list length overflows `usize`
+    #[allow(clippy::expect_used)]
+    pub fn push(&mut self, value: &impl Digestable) {
+        self.len = self.len.checked_add(1).expect("list len overflows usize");
+        value.unambiguously_encode(encoding::EncodeValue::new(&mut self.hash));
+    }
+
+    /// Returns amount of values pushed so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether no values were pushed
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Finalizes the list and returns the digest
+    pub fn finalize(self) -> digest::Output<D> {
+        let mut hash = self.hash;
+        // Same metadata as written by `EncodeList` when it's finished
+        encoding::encode_len(&mut hash, self.len);
+        encoding::Buffer::write(&mut hash, self.tag);
+        encoding::encode_len(&mut hash, self.tag.len());
+        encoding::Buffer::write(&mut hash, &[encoding::LIST_CTX]);
+        hash.0.finalize()
+    }
+}
+
+/// Hasher compatible with [`hash_iter`](crate::hash_iter)
+impl<D: digest::Digest> Default for Hasher<'static, D> {
+    fn default() -> Self {
+        Self::new(b"udigest.list")
+    }
+}
+
+/// Digests several values of different types as a list, with method chaining
+///
+/// Same as [`Hasher`], but takes and returns `self`, which is convenient for digesting
+/// transcripts that consist of values of different types without defining a struct for them.
+/// Digest is the same as of encoding a list of chained values tagged with `tag`. By default,
+/// the tag is the same as used by [`hash_iter`](crate::hash_iter).
+///
+/// ```rust
+/// let hash = udigest::Digester::<sha2::Sha256>::new()
+///     .tag(b"udigest.example.transcript")
+///     .chain(&"Alice")
+///     .chain(&24_u32)
+///     .chain(&[1_u8, 2, 3])
+///     .finalize();
+///
+/// let mut hasher = udigest::Hasher::<sha2::Sha256>::new(b"udigest.example.transcript");
+/// hasher.push(&"Alice");
+/// hasher.push(&24_u32);
+/// hasher.push(&[1_u8, 2, 3]);
+/// assert_eq!(hash, hasher.finalize());
+/// ```
+pub struct Digester<'t, D: digest::Digest> {
+    hasher: Hasher<'t, D>,
+}
+
+impl<D: digest::Digest> Digester<'static, D> {
+    /// Constructs a digester with default tag
+    pub fn new() -> Self {
+        Self {
+            hasher: Hasher::default(),
+        }
+    }
+}
+
+impl<'t, D: digest::Digest> Digester<'t, D> {
+    /// Sets domain separation tag of the list
+    ///
+    /// The tag is written at the end of the encoding, so it can be set at any point before
+    /// the digest is finalized.
+    pub fn tag<'a>(self, tag: &'a [u8]) -> Digester<'a, D> {
+        Digester {
+            hasher: Hasher {
+                hash: self.hasher.hash,
+                len: self.hasher.len,
+                tag,
+            },
+        }
+    }
+
+    /// Appends a value to the list
+    ///
+    /// ## Panic
+    /// Panics if list length overflows `usize`
+    pub fn chain(mut self, value: &impl Digestable) -> Self {
+        self.hasher.push(value);
+        self
+    }
+
+    /// Returns amount of values chained so far
+    pub fn len(&self) -> usize {
+        self.hasher.len()
+    }
+
+    /// Checks whether no values were chained
+    pub fn is_empty(&self) -> bool {
+        self.hasher.is_empty()
+    }
+
+    /// Finalizes the list and returns the digest
+    pub fn finalize(self) -> digest::Output<D> {
+        self.hasher.finalize()
+    }
+}
+
+impl<D: digest::Digest> Default for Digester<'static, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'t, D: digest::Digest> From<Hasher<'t, D>> for Digester<'t, D> {
+    fn from(hasher: Hasher<'t, D>) -> Self {
+        Self { hasher }
+    }
+}
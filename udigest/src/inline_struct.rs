@@ -74,7 +74,7 @@ impl<'t, F: FieldsList> InlineStruct<'t, F> {
     /// Sets domain-separation tag
     ///
     /// Normally, you don't need to use it directly. Use [`inline_struct!`] macro instead.
-    pub fn set_tag<T: ?Sized + AsRef<[u8]>>(self, tag: &T) -> InlineStruct<F> {
+    pub fn set_tag<T: ?Sized + AsRef<[u8]>>(self, tag: &T) -> InlineStruct<'_, F> {
         InlineStruct {
             fields_list: self.fields_list,
             tag: Some(tag.as_ref()),
@@ -235,12 +235,11 @@ impl<'a, V: crate::Digestable, T: FieldsList> FieldsList for Cons<'a, V, T> {
         // to reverse order of fields
         self.tail.encode(encoder);
 
-        let value_encoder = encoder.add_field(self.field_name);
-        self.field_value.unambiguously_encode(value_encoder);
+        encoder.add_field_with(self.field_name, |field| field.encode(&self.field_value));
     }
 }
 
-fn cons<V, T>(field_name: &str, field_value: V, tail: T) -> Cons<V, T>
+fn cons<V, T>(field_name: &str, field_value: V, tail: T) -> Cons<'_, V, T>
 where
     V: crate::Digestable,
     T: FieldsList,
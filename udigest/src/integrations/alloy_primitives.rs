@@ -0,0 +1,48 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as unsigned primitive integers: big-endian bytes without leading zeroes
+impl<const BITS: usize, const LIMBS: usize> Digestable for ::alloy_primitives::Uint<BITS, LIMBS> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        // limbs are stored in little-endian order
+        let mut limbs = self.as_limbs().iter().rev().skip_while(|limb| **limb == 0);
+
+        let mut leaf = encoder.encode_leaf();
+        if let Some(most_significant) = limbs.next() {
+            let bytes = most_significant.to_be_bytes();
+            let leading_zeroes = bytes.iter().take_while(|b| **b == 0).count();
+            leaf.update(&bytes[leading_zeroes..]);
+        }
+        for limb in limbs {
+            leaf.update(&limb.to_be_bytes());
+        }
+        leaf.finish()
+    }
+}
+
+/// Encoded as a bytestring
+impl<const N: usize> Digestable for ::alloy_primitives::FixedBytes<N> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.as_slice())
+    }
+}
+
+/// Encoded as a bytestring of 20 bytes
+impl Digestable for ::alloy_primitives::Address {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.as_slice())
+    }
+}
+
+/// Encoded as a bytestring of 256 bytes
+impl Digestable for ::alloy_primitives::Bloom {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.as_slice())
+    }
+}
+
+/// Encoded as a bytestring
+impl Digestable for ::alloy_primitives::Bytes {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.as_ref())
+    }
+}
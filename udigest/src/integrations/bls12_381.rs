@@ -0,0 +1,72 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring of 48 bytes containing compressed point
+impl Digestable for ::bls12_381::G1Affine {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_compressed())
+    }
+}
+
+/// Encoded as a bytestring of 96 bytes containing compressed point
+impl Digestable for ::bls12_381::G2Affine {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_compressed())
+    }
+}
+
+/// Encoded as a bytestring of 32 bytes containing canonical little-endian representation
+/// of the scalar
+impl Digestable for ::bls12_381::Scalar {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_bytes())
+    }
+}
+
+/// Encoded as a bytestring of 576 bytes containing 12 coefficients of the $\mathbb{F}_{p^{12}}$
+/// element, each coefficient is 48 bytes in canonical big-endian form
+///
+/// `Gt` doesn't expose a compressed encoding nor its coefficients, so they are extracted from
+/// its `Debug` output which prints each coefficient in canonical form. No allocation is made.
+impl Digestable for ::bls12_381::Gt {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        /// Extracts `0x`-prefixed hex numbers from the formatted output and writes them
+        /// into the leaf
+        struct Writer<'a, 'b, B: Buffer> {
+            leaf: &'a mut encoding::EncodeLeaf<'b, B>,
+            prev_char: char,
+            in_hex: bool,
+            high_nibble: Option<u8>,
+        }
+        impl<B: Buffer> core::fmt::Write for Writer<'_, '_, B> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                for c in s.chars() {
+                    if self.in_hex {
+                        if let Some(nibble) = c.to_digit(16) {
+                            let nibble = nibble as u8;
+                            match self.high_nibble.take() {
+                                Some(high) => self.leaf.update(&[high << 4 | nibble]),
+                                None => self.high_nibble = Some(nibble),
+                            }
+                        } else {
+                            self.in_hex = false;
+                        }
+                    } else if self.prev_char == '0' && c == 'x' {
+                        self.in_hex = true;
+                    }
+                    self.prev_char = c;
+                }
+                Ok(())
+            }
+        }
+
+        let mut leaf = encoder.encode_leaf();
+        let mut writer = Writer {
+            leaf: &mut leaf,
+            prev_char: '\0',
+            in_hex: false,
+            high_nibble: None,
+        };
+        // writer never fails
+        let _ = core::fmt::write(&mut writer, format_args!("{self:?}"));
+    }
+}
@@ -0,0 +1,127 @@
+use ::bson::{Bson, Document};
+
+use super::encode_variant;
+use crate::{encoding, Buffer, Bytes, Digestable};
+
+/// Encoded as a bytestring of 12 bytes
+impl Digestable for ::bson::oid::ObjectId {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.bytes())
+    }
+}
+
+/// Encoded as a list of `(key, value)` pairs sorted by key, i.e. exactly as
+/// `BTreeMap<String, Bson>`
+///
+/// Order of the fields in the document doesn't affect the encoding.
+impl Digestable for Document {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut fields = self.iter().collect::<alloc::vec::Vec<_>>();
+        // documents with the same set of fields must have the same encoding
+        fields.sort_unstable_by_key(|(key, _)| *key);
+        crate::unambiguously_encode_iter(encoder, fields)
+    }
+}
+
+/// Encoded as a number of milliseconds since Unix epoch
+impl Digestable for ::bson::DateTime {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.timestamp_millis().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a struct `{ time, increment }`
+impl Digestable for ::bson::Timestamp {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("time", |field| field.encode(&self.time));
+        encoder.add_field_with("increment", |field| field.encode(&self.increment));
+    }
+}
+
+/// Encoded as a struct `{ subtype, bytes }`, where `subtype` is an integer and `bytes`
+/// is a bytestring
+impl Digestable for ::bson::Binary {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("subtype", |field| field.encode(&u8::from(self.subtype)));
+        encoder.add_field_with("bytes", |field| field.encode(&Bytes(&self.bytes)));
+    }
+}
+
+/// Encoded as a struct `{ pattern, options }`
+impl Digestable for ::bson::Regex {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("pattern", |field| field.encode(&self.pattern));
+        encoder.add_field_with("options", |field| field.encode(&self.options));
+    }
+}
+
+/// Encoded as a struct `{ code, scope }`
+impl Digestable for ::bson::JavaScriptCodeWithScope {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("code", |field| field.encode(&self.code));
+        encoder.add_field_with("scope", |field| field.encode(&self.scope));
+    }
+}
+
+/// Encoded as a bytestring of 16 bytes
+impl Digestable for ::bson::Decimal128 {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.bytes())
+    }
+}
+
+/// Encoded as an enum with the same variants as `Bson` has
+///
+/// Each variant (except for unit variants `Null`, `Undefined`, `MaxKey`, `MinKey`) has a single
+/// field `0` that holds the value. `Double` is encoded as `f64` (see
+/// [`encoding` module](crate::encoding#floats)), documents are encoded with keys sorted.
+/// Deprecated `DbPointer` is encoded as a bytestring of its BSON serialization as the type
+/// doesn't expose its content.
+impl Digestable for Bson {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        match self {
+            Bson::Double(x) => encode_variant(encoder, "Double", x),
+            Bson::String(x) => encode_variant(encoder, "String", x),
+            Bson::Array(x) => encode_variant(encoder, "Array", x),
+            Bson::Document(x) => encode_variant(encoder, "Document", x),
+            Bson::Boolean(x) => encode_variant(encoder, "Boolean", x),
+            Bson::Null => {
+                encoder.with_variant("Null");
+            }
+            Bson::RegularExpression(x) => encode_variant(encoder, "RegularExpression", x),
+            Bson::JavaScriptCode(x) => encode_variant(encoder, "JavaScriptCode", x),
+            Bson::JavaScriptCodeWithScope(x) => {
+                encode_variant(encoder, "JavaScriptCodeWithScope", x)
+            }
+            Bson::Int32(x) => encode_variant(encoder, "Int32", x),
+            Bson::Int64(x) => encode_variant(encoder, "Int64", x),
+            Bson::Timestamp(x) => encode_variant(encoder, "Timestamp", x),
+            Bson::Binary(x) => encode_variant(encoder, "Binary", x),
+            Bson::ObjectId(x) => encode_variant(encoder, "ObjectId", x),
+            Bson::DateTime(x) => encode_variant(encoder, "DateTime", x),
+            Bson::Symbol(x) => encode_variant(encoder, "Symbol", x),
+            Bson::Decimal128(x) => encode_variant(encoder, "Decimal128", x),
+            Bson::Undefined => {
+                encoder.with_variant("Undefined");
+            }
+            Bson::MaxKey => {
+                encoder.with_variant("MaxKey");
+            }
+            Bson::MinKey => {
+                encoder.with_variant("MinKey");
+            }
+            Bson::DbPointer(x) => {
+                let mut bytes = alloc::vec::Vec::new();
+                let document = ::bson::doc! { "": x.clone() };
+                // writing to `Vec` never fails
+                let _ = document.to_writer(&mut bytes);
+                encode_variant(encoder, "DbPointer", &Bytes(bytes))
+            }
+        }
+    }
+}
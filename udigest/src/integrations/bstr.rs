@@ -0,0 +1,19 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring, i.e. exactly as [`Bytes`](crate::Bytes)
+///
+/// Note that the bytestring is not required to be valid UTF-8, it's digested as is.
+impl Digestable for ::bstr::BStr {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self)
+    }
+}
+
+/// Encoded as a bytestring, i.e. exactly as [`Bytes`](crate::Bytes)
+///
+/// Note that the bytestring is not required to be valid UTF-8, it's digested as is.
+impl Digestable for ::bstr::BString {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self)
+    }
+}
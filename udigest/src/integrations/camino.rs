@@ -0,0 +1,21 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as `str`
+///
+/// Path is digested as is, without any normalization. For instance, paths `a/b` and `a//b`,
+/// or `a/b` and `a\b` on Windows have different encodings.
+impl Digestable for ::camino::Utf8Path {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_str().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded exactly as `str`
+///
+/// Path is digested as is, without any normalization. For instance, paths `a/b` and `a//b`,
+/// or `a/b` and `a\b` on Windows have different encodings.
+impl Digestable for ::camino::Utf8PathBuf {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_str().unambiguously_encode(encoder)
+    }
+}
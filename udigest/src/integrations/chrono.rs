@@ -0,0 +1,104 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encodes a timestamp as a struct `{ secs, nanos }`, or `{ secs, nanos, offset }` if
+/// offset is provided
+fn encode_timestamp<B: Buffer>(
+    secs: i64,
+    nanos: u32,
+    offset: Option<i32>,
+    encoder: encoding::EncodeValue<B>,
+) {
+    let mut encoder = encoder.encode_struct();
+    encoder.add_field_with("secs", |field| field.encode(&secs));
+    encoder.add_field_with("nanos", |field| field.encode(&nanos));
+    if let Some(offset) = offset {
+        encoder.add_field_with("offset", |field| field.encode(&offset));
+    }
+}
+
+/// Encoded as a struct `{ secs, nanos, offset }` where `secs` is a number of non-leap seconds
+/// since Unix epoch, `nanos` is a number of nanoseconds since the last second boundary
+/// (can exceed `1_000_000_000` to represent a leap second), and `offset` is `0`
+///
+/// Encoding is the same as encoding of `DateTime<FixedOffset>` with zero offset.
+impl Digestable for ::chrono::DateTime<::chrono::Utc> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encode_timestamp(
+            self.timestamp(),
+            self.timestamp_subsec_nanos(),
+            Some(0),
+            encoder,
+        )
+    }
+}
+
+/// Encoded as a struct `{ secs, nanos, offset }` where `secs` is a number of non-leap seconds
+/// since Unix epoch, `nanos` is a number of nanoseconds since the last second boundary
+/// (can exceed `1_000_000_000` to represent a leap second), and `offset` is a number of
+/// seconds east of UTC
+///
+/// Note that, unlike `PartialEq` implementation, the same moment of time in different
+/// offsets has different encoding.
+impl Digestable for ::chrono::DateTime<::chrono::FixedOffset> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encode_timestamp(
+            self.timestamp(),
+            self.timestamp_subsec_nanos(),
+            Some(self.offset().local_minus_utc()),
+            encoder,
+        )
+    }
+}
+
+/// Encoded as a struct `{ secs, nanos }` where `secs` is a number of non-leap seconds since
+/// Unix epoch as if the date and time were in UTC, and `nanos` is a number of nanoseconds
+/// since the last second boundary (can exceed `1_000_000_000` to represent a leap second)
+impl Digestable for ::chrono::NaiveDateTime {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let datetime = self.and_utc();
+        encode_timestamp(
+            datetime.timestamp(),
+            datetime.timestamp_subsec_nanos(),
+            None,
+            encoder,
+        )
+    }
+}
+
+/// Encoded as a struct `{ year, month, day }`, where `month` and `day` start from 1
+impl Digestable for ::chrono::NaiveDate {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        use ::chrono::Datelike;
+
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("year", |field| field.encode(&self.year()));
+        encoder.add_field_with("month", |field| field.encode(&self.month()));
+        encoder.add_field_with("day", |field| field.encode(&self.day()));
+    }
+}
+
+/// Encoded as a struct `{ secs, nanos }` where `secs` is a number of seconds since midnight,
+/// and `nanos` is a number of nanoseconds since the last second boundary (can exceed
+/// `1_000_000_000` to represent a leap second)
+impl Digestable for ::chrono::NaiveTime {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        use ::chrono::Timelike;
+
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("secs", |field| {
+            field.encode(&self.num_seconds_from_midnight())
+        });
+        encoder.add_field_with("nanos", |field| field.encode(&self.nanosecond()));
+    }
+}
+
+/// Encoded as a struct `{ secs, nanos }` where `secs` is a whole number of seconds, and `nanos`
+/// is a number of nanoseconds in the fractional part. For negative durations, both `secs` and
+/// `nanos` are non-positive.
+impl Digestable for ::chrono::TimeDelta {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("secs", |field| field.encode(&self.num_seconds()));
+        encoder.add_field_with("nanos", |field| field.encode(&self.subsec_nanos()));
+    }
+}
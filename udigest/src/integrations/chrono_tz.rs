@@ -0,0 +1,27 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as `str` holding canonical IANA name of the time zone, e.g.
+/// `"Europe/London"`
+impl Digestable for ::chrono_tz::Tz {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.name().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a struct `{ secs, nanos, tz }` where `secs` is a number of non-leap seconds
+/// since Unix epoch, `nanos` is a number of nanoseconds since the last second boundary
+/// (can exceed `1_000_000_000` to represent a leap second), and `tz` is encoded as
+/// [`Tz`](::chrono_tz::Tz)
+///
+/// Note that, unlike `PartialEq` implementation, the same moment of time in different
+/// time zones has different encoding.
+impl Digestable for ::chrono::DateTime<::chrono_tz::Tz> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("secs", |field| field.encode(&self.timestamp()));
+        encoder.add_field_with("nanos", |field| {
+            field.encode(&self.timestamp_subsec_nanos())
+        });
+        encoder.add_field_with("tz", |field| field.encode(&self.timezone()));
+    }
+}
@@ -0,0 +1,65 @@
+use ::ciborium::value::{Integer, Value};
+
+use super::encode_variant;
+use crate::{encoding, Buffer, Bytes, Digestable};
+
+/// Encoded exactly as `i128` holding the same number
+///
+/// CBOR unsigned and negative integers are, therefore, encoded in the same way as Rust
+/// integers of the same value.
+impl Digestable for Integer {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        i128::from(*self).unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as an enum with the same variants as `Value` has
+///
+/// Each variant (except for unit variant `Null`) has a single field `0` that holds the value.
+/// `Tag` variant has two fields: `tag` and `value`. `Float` is encoded as `f64` (see
+/// [`encoding` module](crate::encoding#floats)).
+///
+/// `Map` is encoded as a list of `(key, value)` pairs sorted by unambiguous encoding of the
+/// key, so order of the entries doesn't affect the encoding. Duplicated entries are not
+/// deduplicated.
+impl Digestable for Value {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        match self {
+            Value::Integer(x) => encode_variant(encoder, "Integer", x),
+            Value::Bytes(x) => encode_variant(encoder, "Bytes", &Bytes(x)),
+            Value::Float(x) => encode_variant(encoder, "Float", x),
+            Value::Text(x) => encode_variant(encoder, "Text", x),
+            Value::Bool(x) => encode_variant(encoder, "Bool", x),
+            Value::Null => {
+                encoder.with_variant("Null");
+            }
+            Value::Tag(tag, value) => {
+                let mut encoder = encoder.with_variant("Tag");
+                encoder.add_field_with("tag", |field| field.encode(&tag));
+                encoder.add_field_with("value", |field| field.encode(&value));
+            }
+            Value::Array(x) => encode_variant(encoder, "Array", x),
+            Value::Map(x) => {
+                let mut entries = x
+                    .iter()
+                    .map(|(key, value)| (crate::encode_to_vec(key), (key, value)))
+                    .collect::<alloc::vec::Vec<_>>();
+                // maps with the same set of entries must have the same encoding
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                let entries = entries.iter().map(|(_, entry)| entry);
+
+                let mut encoder = encoder.with_variant("Map");
+                encoder.add_iter_field("0", entries);
+            }
+            // `Value` is non-exhaustive, any variant added in the future is encoded as
+            // a bytestring of its CBOR serialization
+            x => {
+                let mut bytes = alloc::vec::Vec::new();
+                // writing to `Vec` never fails
+                let _ = ::ciborium::into_writer(x, &mut bytes);
+                encode_variant(encoder, "Unknown", &Bytes(bytes))
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring containing the CID in its binary form
+///
+/// CIDv0 is encoded as its multihash, CIDv1 is encoded as varint-encoded version and codec
+/// followed by the multihash.
+impl<const S: usize> Digestable for ::cid::CidGeneric<S> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_bytes())
+    }
+}
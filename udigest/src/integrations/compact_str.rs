@@ -0,0 +1,8 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as `str`
+impl Digestable for ::compact_str::CompactString {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_str().unambiguously_encode(encoder)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{as_::As, encoding, Buffer, DigestAs, Digestable};
+
+/// Digests `DashSet` by transforming it into `BTreeSet`
+///
+/// All the shards are locked before any entry is read and stay locked until the set is
+/// digested, so the digest matches content of the set at a single point in time, even if
+/// it's concurrently modified. Digesting a set while holding a mutable reference into it
+/// from the same thread will deadlock.
+impl<T, U, S> DigestAs<::dashmap::DashSet<T, S>> for std::collections::BTreeSet<U>
+where
+    U: DigestAs<T>,
+    T: Ord + core::hash::Hash,
+    S: core::hash::BuildHasher + Clone,
+{
+    fn digest_as<B: Buffer>(value: &::dashmap::DashSet<T, S>, encoder: encoding::EncodeValue<B>) {
+        // readers are not blocked by waiting writers, so entries can be read while all
+        // the shards are locked
+        let _locked = value
+            .shards()
+            .iter()
+            .map(|shard| shard.read())
+            .collect::<std::vec::Vec<_>>();
+        let snapshot = value.iter().collect::<std::vec::Vec<_>>();
+        let ordered_set = snapshot
+            .iter()
+            .map(|entry| As::<&T, &U>::new(entry.key()))
+            .collect::<std::collections::BTreeSet<_>>();
+
+        // ordered set has deterministic order, so we can reproducibly hash it
+        ordered_set.unambiguously_encode(encoder)
+    }
+}
+
+/// Digests `DashMap` by transforming it into `BTreeMap`
+///
+/// All the shards are locked before any entry is read and stay locked until the map is
+/// digested, so the digest matches content of the map at a single point in time, even if
+/// it's concurrently modified. Digesting a map while holding a mutable reference into it
+/// from the same thread will deadlock.
+impl<K, KAs, V, VAs, S> DigestAs<::dashmap::DashMap<K, V, S>>
+    for std::collections::BTreeMap<KAs, VAs>
+where
+    KAs: DigestAs<K>,
+    VAs: DigestAs<V>,
+    K: Ord + core::hash::Hash,
+    S: core::hash::BuildHasher + Clone,
+{
+    fn digest_as<B: Buffer>(
+        value: &::dashmap::DashMap<K, V, S>,
+        encoder: encoding::EncodeValue<B>,
+    ) {
+        // readers are not blocked by waiting writers, so entries can be read while all
+        // the shards are locked
+        let _locked = value
+            .shards()
+            .iter()
+            .map(|shard| shard.read())
+            .collect::<std::vec::Vec<_>>();
+        let snapshot = value.iter().collect::<std::vec::Vec<_>>();
+        let ordered_map = snapshot
+            .iter()
+            .map(|entry| {
+                (
+                    As::<&K, &KAs>::new(entry.key()),
+                    As::<&V, &VAs>::new(entry.value()),
+                )
+            })
+            .collect::<std::collections::BTreeMap<_, _>>();
+
+        // ordered map has deterministic order, so we can reproducibly hash it
+        ordered_map.unambiguously_encode(encoder)
+    }
+}
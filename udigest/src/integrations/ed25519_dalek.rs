@@ -0,0 +1,15 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring of 32 bytes containing compressed Edwards point
+impl Digestable for ::ed25519_dalek::VerifyingKey {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.as_bytes())
+    }
+}
+
+/// Encoded as a bytestring of 64 bytes: `R` followed by `s`
+impl Digestable for ::ed25519_dalek::Signature {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_bytes())
+    }
+}
@@ -0,0 +1,32 @@
+use ::either::Either;
+
+use crate::{as_::As, encoding, Buffer, DigestAs, Digestable};
+
+/// Encoded as an enum with `Left` and `Right` variants, similarly to `Result`
+impl<L: Digestable, R: Digestable> Digestable for Either<L, R> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        match self {
+            Either::Left(value) => {
+                let mut encoder = encoder.encode_enum().with_variant("Left");
+                encoder.add_field_with("0", |field| field.encode(value));
+            }
+            Either::Right(value) => {
+                let mut encoder = encoder.encode_enum().with_variant("Right");
+                encoder.add_field_with("0", |field| field.encode(value));
+            }
+        }
+    }
+}
+
+impl<L, LAs, R, RAs> DigestAs<Either<L, R>> for Either<LAs, RAs>
+where
+    LAs: DigestAs<L>,
+    RAs: DigestAs<R>,
+{
+    fn digest_as<B: Buffer>(value: &Either<L, R>, encoder: encoding::EncodeValue<B>) {
+        value
+            .as_ref()
+            .map_either(As::<&L, &LAs>::new, As::<&R, &RAs>::new)
+            .unambiguously_encode(encoder)
+    }
+}
@@ -0,0 +1,26 @@
+use ::elliptic_curve::{
+    sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    AffinePoint, CurveArithmetic, FieldBytesSize, NonZeroScalar, PublicKey,
+};
+
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring containing SEC1 compressed encoding of the point
+impl<C> Digestable for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_encoded_point(true).as_bytes())
+    }
+}
+
+/// Encoded as a bytestring containing canonical (big-endian for most curves) encoding
+/// of the scalar
+impl<C: CurveArithmetic> Digestable for NonZeroScalar<C> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(::elliptic_curve::FieldBytes::<C>::from(self))
+    }
+}
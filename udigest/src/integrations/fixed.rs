@@ -0,0 +1,35 @@
+use ::fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+
+use crate::{encoding, Buffer, Digestable};
+
+macro_rules! digestable_fixed {
+    ($($fixed:ident<$frac:ident>),* $(,)?) => {$(
+        /// Encoded as a struct `{ bits, frac_bits }`, where `bits` is the underlying integer
+        /// and `frac_bits` is the number of fractional bits
+        ///
+        /// The value is `bits / 2^frac_bits`. Integers are encoded regardless of their width,
+        /// so signed (or unsigned) fixed-point numbers with the same bits and the same number
+        /// of fractional bits have the same encoding, e.g. `I16F16` and `I48F16` holding
+        /// the same value.
+        impl<Frac: $frac> Digestable for ::fixed::$fixed<Frac> {
+            fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+                let mut encoder = encoder.encode_struct();
+                encoder.add_field_with("bits", |field| field.encode(&self.to_bits()));
+                encoder.add_field_with("frac_bits", |field| field.encode(&Self::FRAC_NBITS));
+            }
+        }
+    )*};
+}
+
+digestable_fixed!(
+    FixedI8<LeEqU8>,
+    FixedI16<LeEqU16>,
+    FixedI32<LeEqU32>,
+    FixedI64<LeEqU64>,
+    FixedI128<LeEqU128>,
+    FixedU8<LeEqU8>,
+    FixedU16<LeEqU16>,
+    FixedU32<LeEqU32>,
+    FixedU64<LeEqU64>,
+    FixedU128<LeEqU128>,
+);
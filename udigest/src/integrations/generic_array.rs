@@ -0,0 +1,23 @@
+use ::generic_array::{ArrayLength, GenericArray};
+
+use crate::{as_::As, encoding, Buffer, DigestAs, Digestable};
+
+/// Encoded exactly as `[T; N]`, i.e. as a list of elements
+///
+/// Use [`Bytes`](crate::Bytes) to digest `GenericArray<u8, N>` (e.g. output of a hash function)
+/// as a bytestring.
+impl<T: Digestable, N: ArrayLength<T>> Digestable for GenericArray<T, N> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_slice().unambiguously_encode(encoder)
+    }
+}
+
+impl<T, U, N> DigestAs<GenericArray<T, N>> for GenericArray<U, N>
+where
+    U: DigestAs<T>,
+    N: ArrayLength<T> + ArrayLength<U>,
+{
+    fn digest_as<B: Buffer>(value: &GenericArray<T, N>, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, value.iter().map(As::<&T, &U>::new))
+    }
+}
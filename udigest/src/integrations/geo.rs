@@ -0,0 +1,117 @@
+use ::geo_types::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+use super::encode_variant;
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a struct `{ x, y }`
+impl<T: CoordNum + Digestable> Digestable for Coord<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("x", |field| field.encode(&self.x));
+        encoder.add_field_with("y", |field| field.encode(&self.y));
+    }
+}
+
+/// Encoded exactly as its [`Coord`]
+impl<T: CoordNum + Digestable> Digestable for Point<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.0.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a struct `{ start, end }`
+impl<T: CoordNum + Digestable> Digestable for Line<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("start", |field| field.encode(&self.start));
+        encoder.add_field_with("end", |field| field.encode(&self.end));
+    }
+}
+
+/// Encoded as a struct `{ min, max }`
+impl<T: CoordNum + Digestable> Digestable for Rect<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("min", |field| field.encode(&self.min()));
+        encoder.add_field_with("max", |field| field.encode(&self.max()));
+    }
+}
+
+/// Encoded as a list of coordinates, in the same order as they appear in the line string
+impl<T: CoordNum + Digestable> Digestable for LineString<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.0.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a list of three vertices `[v1, v2, v3]`
+impl<T: CoordNum + Digestable> Digestable for Triangle<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.to_array().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a struct `{ exterior, interiors }`, where `exterior` is a line string and
+/// `interiors` is a list of line strings
+///
+/// Rings are not normalized: the order of points and interior rings is preserved as is.
+impl<T: CoordNum + Digestable> Digestable for Polygon<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("exterior", |field| field.encode(&self.exterior()));
+        encoder.add_field_with("interiors", |field| field.encode(&self.interiors()));
+    }
+}
+
+/// Encoded as a list of points
+impl<T: CoordNum + Digestable> Digestable for MultiPoint<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.0.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a list of line strings
+impl<T: CoordNum + Digestable> Digestable for MultiLineString<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.0.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a list of polygons
+impl<T: CoordNum + Digestable> Digestable for MultiPolygon<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.0.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a list of geometries
+impl<T: CoordNum + Digestable> Digestable for GeometryCollection<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.0.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as an enum with variant name matching the name of the geometry type (e.g. `Point`
+/// or `Polygon`), and the geometry itself stored in field `0`
+impl<T: CoordNum + Digestable> Digestable for Geometry<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        match self {
+            Geometry::Point(value) => encode_variant(encoder, "Point", value),
+            Geometry::Line(value) => encode_variant(encoder, "Line", value),
+            Geometry::LineString(value) => encode_variant(encoder, "LineString", value),
+            Geometry::Polygon(value) => encode_variant(encoder, "Polygon", value),
+            Geometry::MultiPoint(value) => encode_variant(encoder, "MultiPoint", value),
+            Geometry::MultiLineString(value) => encode_variant(encoder, "MultiLineString", value),
+            Geometry::MultiPolygon(value) => encode_variant(encoder, "MultiPolygon", value),
+            Geometry::GeometryCollection(value) => {
+                encode_variant(encoder, "GeometryCollection", value)
+            }
+            Geometry::Rect(value) => encode_variant(encoder, "Rect", value),
+            Geometry::Triangle(value) => encode_variant(encoder, "Triangle", value),
+        }
+    }
+}
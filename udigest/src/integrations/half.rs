@@ -0,0 +1,19 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as `f64` holding the same value (conversion to `f64` is lossless)
+///
+/// See [`encoding` module](crate::encoding#floats) for details on float encoding.
+impl Digestable for ::half::f16 {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::encode_float(self.to_f64(), encoder)
+    }
+}
+
+/// Encoded exactly as `f64` holding the same value (conversion to `f64` is lossless)
+///
+/// See [`encoding` module](crate::encoding#floats) for details on float encoding.
+impl Digestable for ::half::bf16 {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::encode_float(self.to_f64(), encoder)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{as_::As, encoding, Buffer, DigestAs, Digestable};
+
+/// Digests `hashbrown::HashSet` by transforming it into `BTreeSet`
+impl<T, U, S> DigestAs<::hashbrown::HashSet<T, S>> for alloc::collections::BTreeSet<U>
+where
+    U: DigestAs<T>,
+    T: core::cmp::Ord,
+{
+    fn digest_as<B: Buffer>(value: &::hashbrown::HashSet<T, S>, encoder: encoding::EncodeValue<B>) {
+        let ordered_set = value
+            .iter()
+            .map(As::<&T, &U>::new)
+            .collect::<alloc::collections::BTreeSet<_>>();
+
+        // ordered set has deterministic order, so we can reproducibly hash it
+        ordered_set.unambiguously_encode(encoder)
+    }
+}
+
+/// Digests `hashbrown::HashMap` by transforming it into `BTreeMap`
+impl<K, KAs, V, VAs, S> DigestAs<::hashbrown::HashMap<K, V, S>>
+    for alloc::collections::BTreeMap<KAs, VAs>
+where
+    KAs: DigestAs<K>,
+    VAs: DigestAs<V>,
+    K: core::cmp::Ord,
+{
+    fn digest_as<B: Buffer>(
+        value: &::hashbrown::HashMap<K, V, S>,
+        encoder: encoding::EncodeValue<B>,
+    ) {
+        let ordered_map = value
+            .iter()
+            .map(|(key, value)| (As::<&K, &KAs>::new(key), As::<&V, &VAs>::new(value)))
+            .collect::<alloc::collections::BTreeMap<_, _>>();
+
+        // ordered map has deterministic order, so we can reproducibly hash it
+        ordered_map.unambiguously_encode(encoder)
+    }
+}
@@ -0,0 +1,65 @@
+use ::hashlink::{LinkedHashMap, LinkedHashSet, LruCache};
+
+use crate::{as_::As, encoding, Buffer, DigestAs, Digestable};
+
+/// Encoded as a list of `(key, value)` pairs in insertion order, i.e. exactly as `Vec<(K, V)>`
+///
+/// Use `BTreeMap` rule to digest the map with keys sorted when insertion order doesn't matter.
+impl<K: Digestable, V: Digestable, S> Digestable for LinkedHashMap<K, V, S> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Encoded as a list of elements in insertion order, i.e. exactly as `Vec<T>`
+///
+/// Use `BTreeSet` rule to digest the set with elements sorted when insertion order doesn't
+/// matter.
+impl<T: Digestable, S> Digestable for LinkedHashSet<T, S> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Encoded as a list of `(key, value)` pairs from least to most recently used, i.e. exactly
+/// as [`LinkedHashMap`] holding the same entries in the same order
+impl<K: Digestable, V: Digestable, S> Digestable for LruCache<K, V, S> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Digests `LinkedHashSet` by transforming it into `BTreeSet`
+impl<T, U, S> DigestAs<LinkedHashSet<T, S>> for alloc::collections::BTreeSet<U>
+where
+    U: DigestAs<T>,
+    T: core::cmp::Ord,
+{
+    fn digest_as<B: Buffer>(value: &LinkedHashSet<T, S>, encoder: encoding::EncodeValue<B>) {
+        let ordered_set = value
+            .iter()
+            .map(As::<&T, &U>::new)
+            .collect::<alloc::collections::BTreeSet<_>>();
+
+        // ordered set doesn't depend on insertion order
+        ordered_set.unambiguously_encode(encoder)
+    }
+}
+
+/// Digests `LinkedHashMap` by transforming it into `BTreeMap`
+impl<K, KAs, V, VAs, S> DigestAs<LinkedHashMap<K, V, S>> for alloc::collections::BTreeMap<KAs, VAs>
+where
+    KAs: DigestAs<K>,
+    VAs: DigestAs<V>,
+    K: core::cmp::Ord,
+{
+    fn digest_as<B: Buffer>(value: &LinkedHashMap<K, V, S>, encoder: encoding::EncodeValue<B>) {
+        let ordered_map = value
+            .iter()
+            .map(|(key, value)| (As::<&K, &KAs>::new(key), As::<&V, &VAs>::new(value)))
+            .collect::<alloc::collections::BTreeMap<_, _>>();
+
+        // ordered map doesn't depend on insertion order
+        ordered_map.unambiguously_encode(encoder)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{encoding, Buffer, Bytes, Digestable};
+
+/// Encoded exactly as `str` holding the URI as it's displayed
+impl Digestable for ::http::Uri {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        use alloc::string::ToString;
+        self.to_string().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded exactly as `str`, e.g. `"GET"`
+impl Digestable for ::http::Method {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_str().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded exactly as `u16`
+impl Digestable for ::http::StatusCode {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_u16().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded exactly as `str` holding the name in lowercase
+impl Digestable for ::http::HeaderName {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_str().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a bytestring
+///
+/// Header values are not guaranteed to be valid UTF-8, so they're encoded as bytes
+/// rather than strings.
+impl Digestable for ::http::HeaderValue {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        Bytes(self.as_bytes()).unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded exactly as `BTreeMap<HeaderName, Vec<HeaderValue>>`
+///
+/// Headers are sorted by name, so order in which different headers were inserted doesn't affect
+/// the encoding. Values of the same header keep their order as it's semantically meaningful.
+impl<T: Digestable> Digestable for ::http::HeaderMap<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut names = self.keys().collect::<alloc::vec::Vec<_>>();
+        // maps with the same set of headers must have the same encoding
+        names.sort_unstable_by_key(|name| name.as_str());
+
+        let mut list = encoder.encode_list();
+        for name in names {
+            let mut entry = list.add_item().encode_list();
+            name.unambiguously_encode(entry.add_item());
+            crate::unambiguously_encode_iter(entry.add_item(), self.get_all(name));
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use ::hybrid_array::{Array, ArraySize};
+
+use crate::{as_::As, encoding, Buffer, DigestAs, Digestable};
+
+/// Encoded exactly as `[T; N]`, i.e. as a list of elements
+///
+/// Use [`Bytes`](crate::Bytes) to digest `Array<u8, U>` (e.g. output of a hash function)
+/// as a bytestring.
+impl<T: Digestable, U: ArraySize> Digestable for Array<T, U> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_slice().unambiguously_encode(encoder)
+    }
+}
+
+impl<T, TAs, U> DigestAs<Array<T, U>> for Array<TAs, U>
+where
+    TAs: DigestAs<T>,
+    U: ArraySize,
+{
+    fn digest_as<B: Buffer>(value: &Array<T, U>, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, value.iter().map(As::<&T, &TAs>::new))
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as `Vec<A>`
+impl<A: Digestable + Clone> Digestable for ::im::Vector<A> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self)
+    }
+}
+
+/// Encoded exactly as `BTreeMap<K, V>`
+impl<K: Digestable + Ord + Clone, V: Digestable + Clone> Digestable for ::im::OrdMap<K, V> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self)
+    }
+}
+
+/// Encoded exactly as `BTreeSet<A>`
+impl<A: Digestable + Ord + Clone> Digestable for ::im::OrdSet<A> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{encoding, Buffer, Bytes, Digestable};
+
+/// Encoded as a struct `{ addr, prefix_len }`, where `addr` is a bytestring of 4 octets
+impl Digestable for ::ipnet::Ipv4Net {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("addr", |field| field.encode(&Bytes(self.addr().octets())));
+        encoder.add_field_with("prefix_len", |field| field.encode(&self.prefix_len()));
+    }
+}
+
+/// Encoded as a struct `{ addr, prefix_len }`, where `addr` is a bytestring of 16 octets
+impl Digestable for ::ipnet::Ipv6Net {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("addr", |field| field.encode(&Bytes(self.addr().octets())));
+        encoder.add_field_with("prefix_len", |field| field.encode(&self.prefix_len()));
+    }
+}
+
+/// Encoded as an enum with `V4` and `V6` variants
+impl Digestable for ::ipnet::IpNet {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        match self {
+            ::ipnet::IpNet::V4(net) => {
+                let mut encoder = encoder.encode_enum().with_variant("V4");
+                encoder.add_field_with("0", |field| field.encode(&net));
+            }
+            ::ipnet::IpNet::V6(net) => {
+                let mut encoder = encoder.encode_enum().with_variant("V6");
+                encoder.add_field_with("0", |field| field.encode(&net));
+            }
+        }
+    }
+}
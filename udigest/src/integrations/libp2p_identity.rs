@@ -0,0 +1,9 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring containing the peer ID in its binary form, i.e. as multihash
+/// of the public key
+impl Digestable for ::libp2p_identity::PeerId {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_bytes())
+    }
+}
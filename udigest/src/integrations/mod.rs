@@ -0,0 +1,130 @@
+//! Implementations of [`Digestable`](crate::Digestable) trait for types from third-party crates
+//!
+//! Each integration is enabled by the corresponding feature.
+
+#[cfg(feature = "alloy-primitives")]
+mod alloy_primitives;
+#[cfg(feature = "bls12_381")]
+mod bls12_381;
+#[cfg(feature = "bson")]
+mod bson;
+#[cfg(feature = "bstr")]
+mod bstr;
+#[cfg(feature = "camino")]
+mod camino;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "chrono-tz")]
+mod chrono_tz;
+#[cfg(feature = "ciborium")]
+mod ciborium;
+#[cfg(feature = "cid")]
+mod cid;
+#[cfg(feature = "compact_str")]
+mod compact_str;
+#[cfg(feature = "dashmap")]
+mod dashmap;
+#[cfg(feature = "ed25519-dalek")]
+mod ed25519_dalek;
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "elliptic-curve")]
+mod elliptic_curve;
+#[cfg(feature = "fixed")]
+mod fixed;
+#[cfg(feature = "generic-array")]
+mod generic_array;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+#[cfg(feature = "hashlink")]
+mod hashlink;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "hybrid-array")]
+mod hybrid_array;
+#[cfg(feature = "im")]
+mod im;
+#[cfg(feature = "ipnet")]
+mod ipnet;
+#[cfg(feature = "libp2p-identity")]
+mod libp2p_identity;
+#[cfg(feature = "multiaddr")]
+mod multiaddr;
+#[cfg(feature = "multihash")]
+mod multihash;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "nonempty")]
+mod nonempty;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "num-complex")]
+mod num_complex;
+#[cfg(feature = "num-rational")]
+mod num_rational;
+#[cfg(feature = "prost-types")]
+mod prost_types;
+#[cfg(feature = "roaring")]
+mod roaring;
+#[cfg(feature = "rpds")]
+mod rpds;
+#[cfg(feature = "semver")]
+mod semver;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "serde_yaml")]
+mod serde_yaml;
+#[cfg(feature = "slab")]
+mod slab;
+#[cfg(feature = "slotmap")]
+mod slotmap;
+#[cfg(feature = "smol_str")]
+mod smol_str;
+#[cfg(feature = "ssh-key")]
+mod ssh_key;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "ulid")]
+mod ulid;
+#[cfg(feature = "uom")]
+mod uom;
+#[cfg(feature = "x509-cert")]
+mod x509_cert;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+/// Encodes enum variant `variant` holding a single field `0` with the `value`
+#[cfg(any(
+    feature = "bson",
+    feature = "ciborium",
+    feature = "geo",
+    feature = "prost-types",
+    feature = "serde_json",
+    feature = "serde_yaml",
+    feature = "toml",
+))]
+fn encode_variant<B: crate::Buffer>(
+    encoder: crate::encoding::EncodeEnum<B>,
+    variant: &str,
+    value: &impl crate::Digestable,
+) {
+    let mut encoder = encoder.with_variant(variant);
+    encoder.add_field_with("0", |field| field.encode(value));
+}
+
+/// Converts float into integer if it has no fractional part and fits into `i128`
+#[cfg(any(feature = "serde_json", feature = "serde_yaml"))]
+fn float_to_integer(x: f64) -> Option<i128> {
+    // 2^127 is exactly representable as `f64`
+    const BOUND: f64 = 170141183460469231731687303715884105728.0;
+    if !(-BOUND..BOUND).contains(&x) {
+        return None;
+    }
+    // `as` truncates the fractional part, the round trip is lossless only for integers
+    let integer = x as i128;
+    (integer as f64 == x).then_some(integer)
+}
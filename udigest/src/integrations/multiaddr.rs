@@ -0,0 +1,9 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring containing the multiaddr in its binary form: a sequence of
+/// varint-encoded protocol codes, each followed by the protocol-specific address
+impl Digestable for ::multiaddr::Multiaddr {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self)
+    }
+}
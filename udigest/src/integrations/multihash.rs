@@ -0,0 +1,9 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring containing the multihash in its binary form: varint-encoded
+/// hash function code, varint-encoded digest size, and the digest
+impl<const S: usize> Digestable for ::multihash::Multihash<S> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_bytes())
+    }
+}
@@ -0,0 +1,30 @@
+use ::nalgebra::{Dim, Matrix, RawStorage};
+
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a struct `{ rows, cols, elements }`, where `rows` and `cols` are dimensions
+/// of the matrix, and `elements` is a list of matrix elements in row-major order
+///
+/// Encoding doesn't depend on whether dimensions are known at compile time, so `SMatrix`,
+/// `DMatrix`, and matrix views holding the same elements have the same encoding. Vectors
+/// are encoded as matrices with a single column. Floats are encoded as described in
+/// [`encoding` module](crate::encoding#floats) (requires `float` feature).
+impl<T, R, C, S> Digestable for Matrix<T, R, C, S>
+where
+    T: Digestable,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let (rows, cols) = self.shape();
+
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("rows", |field| field.encode(&rows));
+        encoder.add_field_with("cols", |field| field.encode(&cols));
+        encoder.add_iter_field(
+            "elements",
+            (0..rows).flat_map(|i| (0..cols).map(move |j| &self[(i, j)])),
+        );
+    }
+}
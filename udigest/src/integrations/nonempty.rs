@@ -0,0 +1,8 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a list of elements, i.e. exactly as `Vec<T>` with the same elements
+impl<T: Digestable> Digestable for ::nonempty::NonEmpty<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
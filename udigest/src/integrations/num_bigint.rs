@@ -0,0 +1,17 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as unsigned primitive integers: big-endian bytes without leading zeroes
+impl Digestable for ::num_bigint::BigUint {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::encode_unsigned_integer(&self.to_bytes_be(), encoder)
+    }
+}
+
+/// Encoded exactly as signed primitive integers: sign byte followed by big-endian bytes
+/// of absolute value without leading zeroes (zero is encoded as empty bytestring)
+impl Digestable for ::num_bigint::BigInt {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let (sign, abs_be_bytes) = self.to_bytes_be();
+        crate::encode_signed_integer(sign == ::num_bigint::Sign::Plus, &abs_be_bytes, encoder)
+    }
+}
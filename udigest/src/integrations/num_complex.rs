@@ -0,0 +1,8 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a list `[re, im]`, i.e. exactly as a tuple `(re, im)`
+impl<T: Digestable> Digestable for ::num_complex::Complex<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        (&self.re, &self.im).unambiguously_encode(encoder)
+    }
+}
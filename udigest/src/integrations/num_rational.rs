@@ -0,0 +1,28 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a struct `{ numer, denom }`
+///
+/// Ratio is normalized before encoding: numerator and denominator are coprime, and denominator
+/// is positive. It means that equal ratios have the same encoding even if some of them were
+/// constructed via [`Ratio::new_raw`](::num_rational::Ratio::new_raw).
+///
+/// Ratio with zero denominator (which can only be constructed via `new_raw`) can't be normalized,
+/// so its numerator and denominator are encoded as they are.
+impl<T> Digestable for ::num_rational::Ratio<T>
+where
+    T: Digestable + Clone + ::num_integer::Integer,
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        // `reduced()` panics on zero denominator
+        let reduced;
+        let ratio = if self.denom().is_zero() {
+            self
+        } else {
+            reduced = self.reduced();
+            &reduced
+        };
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("numer", |field| field.encode(ratio.numer()));
+        encoder.add_field_with("denom", |field| field.encode(ratio.denom()));
+    }
+}
@@ -0,0 +1,88 @@
+use ::prost_types::{value::Kind, Any, Duration, ListValue, Struct, Timestamp, Value};
+
+use super::encode_variant;
+use crate::{encoding, Buffer, Bytes, Digestable};
+
+/// Encoded as a struct `{ seconds, nanos }`
+///
+/// Timestamp is normalized before encoding, so the same point in time always has the same
+/// encoding.
+impl Digestable for Timestamp {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut normalized = *self;
+        normalized.normalize();
+
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("seconds", |field| field.encode(&normalized.seconds));
+        encoder.add_field_with("nanos", |field| field.encode(&normalized.nanos));
+    }
+}
+
+/// Encoded as a struct `{ seconds, nanos }`
+///
+/// Duration is normalized before encoding, so the same time span always has the same
+/// encoding.
+impl Digestable for Duration {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut normalized = *self;
+        normalized.normalize();
+
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("seconds", |field| field.encode(&normalized.seconds));
+        encoder.add_field_with("nanos", |field| field.encode(&normalized.nanos));
+    }
+}
+
+/// Encoded as a struct `{ type_url, value }`, where `value` is a bytestring
+impl Digestable for Any {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("type_url", |field| field.encode(&self.type_url));
+        encoder.add_field_with("value", |field| field.encode(&Bytes(&self.value)));
+    }
+}
+
+/// Encoded exactly as `BTreeMap<String, Value>`, i.e. fields are sorted by name
+impl Digestable for Struct {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.fields.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded exactly as `Vec<Value>`
+impl Digestable for ListValue {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.values.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded exactly as `Option<Kind>`, where `Kind` is encoded as an enum with the same
+/// variants as [`Kind`] has
+///
+/// Each variant has a single field `0` that holds the value. `NumberValue` is encoded as `f64`
+/// (see [`encoding` module](crate::encoding#floats)), `NullValue` holds `i32` representation
+/// of the enum.
+impl Digestable for Value {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.kind
+            .as_ref()
+            .map(KindRef)
+            .unambiguously_encode(encoder)
+    }
+}
+
+struct KindRef<'a>(&'a Kind);
+
+impl Digestable for KindRef<'_> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        match self.0 {
+            Kind::NullValue(x) => encode_variant(encoder, "NullValue", x),
+            Kind::NumberValue(x) => encode_variant(encoder, "NumberValue", x),
+            Kind::StringValue(x) => encode_variant(encoder, "StringValue", x),
+            Kind::BoolValue(x) => encode_variant(encoder, "BoolValue", x),
+            Kind::StructValue(x) => encode_variant(encoder, "StructValue", x),
+            Kind::ListValue(x) => encode_variant(encoder, "ListValue", x),
+        }
+    }
+}
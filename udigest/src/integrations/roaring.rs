@@ -0,0 +1,21 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as `BTreeSet<u32>`: list of the integers in the bitmap in ascending order
+///
+/// Internal container layout doesn't affect the encoding, so bitmaps containing the same
+/// integers always have the same digest.
+impl Digestable for ::roaring::RoaringBitmap {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Encoded exactly as `BTreeSet<u64>`: list of the integers in the treemap in ascending order
+///
+/// Internal container layout doesn't affect the encoding, so treemaps containing the same
+/// integers always have the same digest.
+impl Digestable for ::roaring::RoaringTreemap {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
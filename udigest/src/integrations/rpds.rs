@@ -0,0 +1,50 @@
+use ::archery::SharedPointerKind;
+
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded exactly as `Vec<T>`, elements are listed starting from the first one
+impl<T: Digestable, P: SharedPointerKind> Digestable for ::rpds::List<T, P> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Encoded exactly as `Vec<T>`
+impl<T: Digestable, P: SharedPointerKind> Digestable for ::rpds::Vector<T, P> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Encoded exactly as `Vec<T>`, elements are listed starting from the top of the stack
+impl<T: Digestable, P: SharedPointerKind> Digestable for ::rpds::Stack<T, P> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Encoded exactly as `Vec<T>`, elements are listed starting from the front of the queue
+impl<T: Digestable, P: SharedPointerKind> Digestable for ::rpds::Queue<T, P> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Encoded exactly as `BTreeMap<K, V>`
+impl<K, V, P> Digestable for ::rpds::RedBlackTreeMap<K, V, P>
+where
+    K: Digestable + Ord,
+    V: Digestable,
+    P: SharedPointerKind,
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
+
+/// Encoded exactly as `BTreeSet<T>`
+impl<T: Digestable + Ord, P: SharedPointerKind> Digestable for ::rpds::RedBlackTreeSet<T, P> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
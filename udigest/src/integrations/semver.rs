@@ -0,0 +1,64 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a struct `{ major, minor, patch, pre, build }`, where `pre` and `build` are
+/// strings (empty if absent)
+impl Digestable for ::semver::Version {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("major", |field| field.encode(&self.major));
+        encoder.add_field_with("minor", |field| field.encode(&self.minor));
+        encoder.add_field_with("patch", |field| field.encode(&self.patch));
+        encoder.add_field_with("pre", |field| field.encode(&self.pre.as_str()));
+        encoder.add_field_with("build", |field| field.encode(&self.build.as_str()));
+    }
+}
+
+/// Encoded as a list of comparators
+///
+/// Each comparator is encoded as a struct `{ op, major, minor, patch, pre }`, where `op` is
+/// an enum with a unit variant named after [`Op`](::semver::Op) variant (e.g. `Caret`),
+/// `minor` and `patch` are options, and `pre` is a string (empty if absent)
+impl Digestable for ::semver::VersionReq {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.comparators.iter().map(Comparator))
+    }
+}
+
+struct Comparator<'a>(&'a ::semver::Comparator);
+
+impl Digestable for Comparator<'_> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("op", |field| field.encode(&Operator(self.0.op)));
+        encoder.add_field_with("major", |field| field.encode(&self.0.major));
+        encoder.add_field_with("minor", |field| field.encode(&self.0.minor));
+        encoder.add_field_with("patch", |field| field.encode(&self.0.patch));
+        encoder.add_field_with("pre", |field| field.encode(&self.0.pre.as_str()));
+    }
+}
+
+struct Operator(::semver::Op);
+
+impl Digestable for Operator {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        use ::semver::Op;
+        let op = self.0;
+        let variant = match op {
+            Op::Exact => "Exact",
+            Op::Greater => "Greater",
+            Op::GreaterEq => "GreaterEq",
+            Op::Less => "Less",
+            Op::LessEq => "LessEq",
+            Op::Tilde => "Tilde",
+            Op::Caret => "Caret",
+            Op::Wildcard => "Wildcard",
+            // `Op` is non-exhaustive. Operators that might be added in the future are
+            // encoded using their debug representation, which is the variant name.
+            _ => {
+                encoder.encode_enum().with_variant(alloc::format!("{op:?}"));
+                return;
+            }
+        };
+        encoder.encode_enum().with_variant(variant);
+    }
+}
@@ -0,0 +1,80 @@
+use ::serde_json::{Map, Number, Value};
+
+use super::{encode_variant, float_to_integer};
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as an enum with variants `Integer`, `Float`, and `Decimal`, each holding a single
+/// field `0`
+///
+/// Numbers are canonicalized, so the same value always has the same encoding regardless of
+/// how it was written in JSON:
+///
+/// * Integers are encoded as `Integer` variant holding `i128`, i.e. `5` parsed as `u64` and
+///   `5` parsed as `i64` have the same encoding
+/// * Floats that have no fractional part and fit into `i128` are encoded as integers, i.e. `1.0`,
+///   `1e0`, and `1` have the same encoding
+/// * Any other number is converted to `f64` and encoded as `Float` variant (see
+///   [`encoding` module](crate::encoding#floats))
+///
+/// When `arbitrary_precision` feature of `serde_json` is enabled, numbers are still converted
+/// to `f64` as described above, so numbers that only differ beyond `f64` precision have the same
+/// encoding. Numbers that don't fit into `f64` (like `1e400`) are encoded as `Decimal` variant
+/// holding the number as it was written in JSON, so they are not canonicalized.
+impl Digestable for Number {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        if let Some(x) = self.as_i64() {
+            encode_variant(encoder, "Integer", &i128::from(x))
+        } else if let Some(x) = self.as_u64() {
+            encode_variant(encoder, "Integer", &i128::from(x))
+        } else if let Some(x) = self.as_f64() {
+            if let Some(x) = float_to_integer(x) {
+                encode_variant(encoder, "Integer", &x)
+            } else {
+                encode_variant(encoder, "Float", &x)
+            }
+        } else {
+            // number is always convertible to `f64`, unless `arbitrary_precision`
+            // feature is enabled and the number is out of range
+            encode_variant(
+                encoder,
+                "Decimal",
+                &alloc::string::ToString::to_string(self),
+            )
+        }
+    }
+}
+
+/// Encoded as a list of `(key, value)` pairs sorted by key, i.e. exactly as
+/// `BTreeMap<String, Value>`
+///
+/// Order of the fields in the object doesn't affect the encoding.
+impl Digestable for Map<alloc::string::String, Value> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut fields = self.iter().collect::<alloc::vec::Vec<_>>();
+        // objects with the same set of fields must have the same encoding
+        fields.sort_unstable_by_key(|(key, _)| *key);
+        crate::unambiguously_encode_iter(encoder, fields)
+    }
+}
+
+/// Encoded as an enum with the same variants as `Value` has
+///
+/// Each variant (except for unit variant `Null`) has a single field `0` that holds the value.
+/// Numbers are canonicalized as described in [`Number`] encoding, objects are encoded with
+/// keys sorted.
+impl Digestable for Value {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        match self {
+            Value::Null => {
+                encoder.with_variant("Null");
+            }
+            Value::Bool(x) => encode_variant(encoder, "Bool", x),
+            Value::Number(x) => encode_variant(encoder, "Number", x),
+            Value::String(x) => encode_variant(encoder, "String", x),
+            Value::Array(x) => encode_variant(encoder, "Array", x),
+            Value::Object(x) => encode_variant(encoder, "Object", x),
+        }
+    }
+}
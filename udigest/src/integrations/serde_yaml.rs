@@ -0,0 +1,90 @@
+use ::serde_yaml::value::{Tag, TaggedValue};
+use ::serde_yaml::{Mapping, Number, Value};
+
+use super::{encode_variant, float_to_integer};
+use crate::{as_, encoding, Buffer, DigestAs, Digestable};
+
+/// Encoded as an enum with two variants: `Integer` and `Float`, each holding a single field `0`
+///
+/// Numbers are canonicalized, so the same value always has the same encoding regardless of
+/// how it was written in YAML:
+///
+/// * Integers are encoded as `Integer` variant holding `i128`, i.e. `5` parsed as `u64` and
+///   `5` parsed as `i64` have the same encoding
+/// * Floats that have no fractional part and fit into `i128` are encoded as integers, i.e. `1.0`,
+///   `1e0`, and `1` have the same encoding
+/// * Any other number is encoded as `Float` variant (see
+///   [`encoding` module](crate::encoding#floats)), e.g. `.nan` and `.inf`
+impl Digestable for Number {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        if let Some(x) = self.as_i64() {
+            encode_variant(encoder, "Integer", &i128::from(x))
+        } else if let Some(x) = self.as_u64() {
+            encode_variant(encoder, "Integer", &i128::from(x))
+        } else {
+            // number is always convertible to `f64`
+            let x = self.as_f64().unwrap_or(f64::NAN);
+            if let Some(x) = float_to_integer(x) {
+                encode_variant(encoder, "Integer", &x)
+            } else {
+                encode_variant(encoder, "Float", &x)
+            }
+        }
+    }
+}
+
+/// Encoded as a list of `(key, value)` pairs sorted by unambiguous encoding of the key
+///
+/// Order of the entries in the mapping doesn't affect the encoding.
+impl Digestable for Mapping {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut entries = self
+            .iter()
+            .map(|(key, value)| (crate::encode_to_vec(key), (key, value)))
+            .collect::<alloc::vec::Vec<_>>();
+        // mappings with the same set of entries must have the same encoding
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        crate::unambiguously_encode_iter(encoder, entries.iter().map(|(_, entry)| entry))
+    }
+}
+
+/// Encoded as a string, e.g. `!Thing`
+///
+/// Tags are canonicalized, i.e. `Thing` and `!Thing` have the same encoding.
+impl Digestable for Tag {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        as_::Display::digest_as(self, encoder)
+    }
+}
+
+/// Encoded as a struct `{ tag, value }`
+impl Digestable for TaggedValue {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("tag", |field| field.encode(&self.tag));
+        encoder.add_field_with("value", |field| field.encode(&self.value));
+    }
+}
+
+/// Encoded as an enum with the same variants as `Value` has
+///
+/// Each variant (except for unit variant `Null`) has a single field `0` that holds the value.
+/// Numbers are canonicalized as described in [`Number`] encoding, mappings are encoded with
+/// keys sorted.
+impl Digestable for Value {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        match self {
+            Value::Null => {
+                encoder.with_variant("Null");
+            }
+            Value::Bool(x) => encode_variant(encoder, "Bool", x),
+            Value::Number(x) => encode_variant(encoder, "Number", x),
+            Value::String(x) => encode_variant(encoder, "String", x),
+            Value::Sequence(x) => encode_variant(encoder, "Sequence", x),
+            Value::Mapping(x) => encode_variant(encoder, "Mapping", x),
+            Value::Tagged(x) => encode_variant(encoder, "Tagged", x),
+        }
+    }
+}
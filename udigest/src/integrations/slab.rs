@@ -0,0 +1,8 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a list of `(key, value)` pairs in key order, i.e. exactly as `BTreeMap<usize, T>`
+impl<T: Digestable> Digestable for ::slab::Slab<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        crate::unambiguously_encode_iter(encoder, self.iter())
+    }
+}
@@ -0,0 +1,49 @@
+use ::slotmap::{DenseSlotMap, Key, KeyData, SecondaryMap, SlotMap};
+
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as `u64` returned by [`KeyData::as_ffi`] which contains both index and version
+/// of the key
+impl Digestable for KeyData {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.as_ffi().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a list of `(key, value)` pairs sorted by key, i.e. exactly as
+/// `BTreeMap<u64, V>` where each key is converted via [`KeyData::as_ffi`]
+///
+/// Key includes its version, so slot maps holding the same values at the same indices may
+/// have different encodings if they had different history of insertions and removals.
+impl<K: Key, V: Digestable> Digestable for SlotMap<K, V> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encode_entries(encoder, self.iter())
+    }
+}
+
+/// Encoded exactly as [`SlotMap`] holding the same entries
+///
+/// Entries are sorted by key, so their order in the dense storage doesn't affect the encoding.
+impl<K: Key, V: Digestable> Digestable for DenseSlotMap<K, V> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encode_entries(encoder, self.iter())
+    }
+}
+
+/// Encoded exactly as [`SlotMap`] holding the same entries
+impl<K: Key, V: Digestable> Digestable for SecondaryMap<K, V> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encode_entries(encoder, self.iter())
+    }
+}
+
+fn encode_entries<'a, B: Buffer, K: Key, V: Digestable + 'a>(
+    encoder: encoding::EncodeValue<B>,
+    entries: impl Iterator<Item = (K, &'a V)>,
+) {
+    let mut entries = entries
+        .map(|(key, value)| (key.data().as_ffi(), value))
+        .collect::<alloc::vec::Vec<_>>();
+    entries.sort_unstable_by_key(|(key, _)| *key);
+    crate::unambiguously_encode_iter(encoder, entries)
+}
@@ -0,0 +1,42 @@
+use ::ssh_encoding::Encode;
+
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring containing the key in SSH wire format (the same bytes
+/// that appear base64-encoded in the OpenSSH public key format)
+///
+/// The comment is not part of the key and is not digested. No allocation is made.
+impl Digestable for ::ssh_key::PublicKey {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.key_data().unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a bytestring containing the key data in SSH wire format
+///
+/// No allocation is made.
+impl Digestable for ::ssh_key::public::KeyData {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        struct Writer<'a, 'b, B: Buffer>(&'a mut encoding::EncodeLeaf<'b, B>);
+        impl<B: Buffer> ::ssh_encoding::Writer for Writer<'_, '_, B> {
+            fn write(&mut self, bytes: &[u8]) -> ::ssh_encoding::Result<()> {
+                self.0.update(bytes);
+                Ok(())
+            }
+        }
+
+        let mut leaf = encoder.encode_leaf();
+        // writer never fails
+        let _ = self.encode(&mut Writer(&mut leaf));
+    }
+}
+
+/// Encoded as a struct `{ algorithm, hash }`, where `algorithm` is a name of the hash
+/// function (e.g. `sha256`), and `hash` is a bytestring
+impl Digestable for ::ssh_key::Fingerprint {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_leaf_field("algorithm", self.algorithm().as_str());
+        encoder.add_leaf_field("hash", self.as_bytes());
+    }
+}
@@ -0,0 +1,88 @@
+use ::toml::value::{Date, Datetime, Offset, Time};
+use ::toml::{Table, Value};
+
+use super::encode_variant;
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a struct `{ year, month, day }`
+impl Digestable for Date {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("year", |field| field.encode(&self.year));
+        encoder.add_field_with("month", |field| field.encode(&self.month));
+        encoder.add_field_with("day", |field| field.encode(&self.day));
+    }
+}
+
+/// Encoded as a struct `{ hour, minute, second, nanosecond }`
+///
+/// Precision used to write fractional seconds doesn't affect the encoding, i.e. `07:32:00.5`
+/// and `07:32:00.500` have the same encoding.
+impl Digestable for Time {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("hour", |field| field.encode(&self.hour));
+        encoder.add_field_with("minute", |field| field.encode(&self.minute));
+        encoder.add_field_with("second", |field| field.encode(&self.second));
+        encoder.add_field_with("nanosecond", |field| field.encode(&self.nanosecond));
+    }
+}
+
+/// Encoded exactly as `i16` holding the offset from UTC in minutes
+///
+/// `Z` is encoded as zero offset, i.e. the same as `+00:00`.
+impl Digestable for Offset {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let minutes = match self {
+            Offset::Z => 0,
+            Offset::Custom { minutes } => *minutes,
+        };
+        minutes.unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded as a struct `{ date, time, offset }`, where each field is an `Option`
+///
+/// The encoding only depends on the value, not on how it was written in TOML: separator
+/// between date and time, precision of fractional seconds, and `Z` vs `+00:00` offset
+/// don't affect the encoding.
+impl Digestable for Datetime {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("date", |field| field.encode(&self.date));
+        encoder.add_field_with("time", |field| field.encode(&self.time));
+        encoder.add_field_with("offset", |field| field.encode(&self.offset));
+    }
+}
+
+/// Encoded as a list of `(key, value)` pairs sorted by key, i.e. exactly as
+/// `BTreeMap<String, Value>`
+///
+/// Order of the keys in the table doesn't affect the encoding.
+impl Digestable for Table {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut fields = self.iter().collect::<alloc::vec::Vec<_>>();
+        // tables with the same set of keys must have the same encoding
+        fields.sort_unstable_by_key(|(key, _)| *key);
+        crate::unambiguously_encode_iter(encoder, fields)
+    }
+}
+
+/// Encoded as an enum with the same variants as `Value` has
+///
+/// Each variant has a single field `0` that holds the value. `Float` is encoded as `f64`
+/// (see [`encoding` module](crate::encoding#floats)), tables are encoded with keys sorted.
+impl Digestable for Value {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let encoder = encoder.encode_enum();
+        match self {
+            Value::String(x) => encode_variant(encoder, "String", x),
+            Value::Integer(x) => encode_variant(encoder, "Integer", x),
+            Value::Float(x) => encode_variant(encoder, "Float", x),
+            Value::Boolean(x) => encode_variant(encoder, "Boolean", x),
+            Value::Datetime(x) => encode_variant(encoder, "Datetime", x),
+            Value::Array(x) => encode_variant(encoder, "Array", x),
+            Value::Table(x) => encode_variant(encoder, "Table", x),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a bytestring of 16 bytes (big-endian)
+///
+/// Use [`as_::Display`](crate::as_::Display) rule to digest ULID in its string form.
+impl Digestable for ::ulid::Ulid {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf_value(self.to_bytes())
+    }
+}
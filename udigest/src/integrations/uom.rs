@@ -0,0 +1,40 @@
+use ::uom::si::{Dimension, Quantity, Units};
+use ::uom::typenum::Integer;
+
+use crate::{encoding, Buffer, Digestable};
+
+/// Encoded as a struct `{ value, dimension }`, where `value` is the value of the quantity in
+/// the base units, and `dimension` is a struct of exponents (`i64`) of the seven base
+/// quantities: `{ length, mass, time, electric_current, thermodynamic_temperature,
+/// amount_of_substance, luminous_intensity }`
+///
+/// Quantities of different dimensions never have the same encoding, e.g. `3.0 m` and `3.0 s`
+/// are encoded differently. The same quantity has the same encoding regardless of the unit it
+/// was constructed from, e.g. `1 km` and `1000 m`. Kind of the quantity is not encoded, so
+/// quantities of the same dimension but different kinds (e.g. angle and ratio) holding the
+/// same value have the same encoding.
+///
+/// Floats are encoded as described in [`encoding` module](crate::encoding#floats) (requires
+/// `float` feature).
+impl<D, U, V> Digestable for Quantity<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: ::uom::num_traits::Num + ::uom::Conversion<V> + Digestable,
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let mut encoder = encoder.encode_struct();
+        encoder.add_field_with("value", |field| field.encode(&self.value));
+
+        let mut dimension = encoder.add_struct_field("dimension");
+        dimension.add_field_with("length", |field| field.encode(&D::L::to_i64()));
+        dimension.add_field_with("mass", |field| field.encode(&D::M::to_i64()));
+        dimension.add_field_with("time", |field| field.encode(&D::T::to_i64()));
+        dimension.add_field_with("electric_current", |field| field.encode(&D::I::to_i64()));
+        dimension.add_field_with("thermodynamic_temperature", |field| {
+            field.encode(&D::Th::to_i64())
+        });
+        dimension.add_field_with("amount_of_substance", |field| field.encode(&D::N::to_i64()));
+        dimension.add_field_with("luminous_intensity", |field| field.encode(&D::J::to_i64()));
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{as_, encoding, Buffer, DigestAs, Digestable};
+
+/// Encoded as a bytestring containing the DER-encoded certificate
+impl<P: ::x509_cert::certificate::Profile> Digestable
+    for ::x509_cert::certificate::CertificateInner<P>
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        as_::Der::digest_as(self, encoder)
+    }
+}
+
+/// Encoded as a bytestring containing the DER-encoded TBS certificate
+impl<P: ::x509_cert::certificate::Profile> Digestable
+    for ::x509_cert::certificate::TbsCertificateInner<P>
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        as_::Der::digest_as(self, encoder)
+    }
+}
+
+/// Encoded as a bytestring containing the DER-encoded public key info
+impl<Params, Key> Digestable for ::x509_cert::spki::SubjectPublicKeyInfo<Params, Key>
+where
+    Self: ::der::Encode,
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        as_::Der::digest_as(self, encoder)
+    }
+}
+
+/// Encoded as a bytestring containing the DER-encoded name
+impl Digestable for ::x509_cert::name::RdnSequence {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        as_::Der::digest_as(self, encoder)
+    }
+}
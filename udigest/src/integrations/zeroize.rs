@@ -0,0 +1,8 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Digests the inner value
+impl<T: Digestable + ::zeroize::Zeroize> Digestable for ::zeroize::Zeroizing<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        (**self).unambiguously_encode(encoder)
+    }
+}
@@ -13,12 +13,20 @@
 //!   `i8`, `i16`, `i32`, `i64`, `i128`,
 //!   `u8`, `u16`, `u32`, `u64`, `u128`,
 //!   `char`, `isize`, `usize`
-//! * Containers: `Box`, `Arc`, `Rc`, `Cow`, `Option`, `Result`
+//! * Floats: `f32`, `f64` (requires `float` feature)
+//! * Atomics: `AtomicBool`, `AtomicU8`, ..., `AtomicUsize`, `AtomicI8`, ..., `AtomicIsize` \
+//!   Value is loaded with `SeqCst` ordering and encoded exactly as the non-atomic value
+//! * References and pointers: `&T`, `&mut T`, `Pin<P>`
+//! * Containers: `Box`, `Arc`, `Rc`, `Cow`, `Option`, `Result`, `ControlFlow`, `Cell`,
+//!   `RefCell`, `Mutex`, `RwLock`
 //! * Collections: arrays, slices, `Vec`, `LinkedList`, `VecDeque`, `BTreeSet`, `BTreeMap`
+//! * Tuples (up to 32 elements), `()`, `PhantomData`, `PhantomPinned`, `Infallible`
 //!
 //! The trait is intentionally not implemented for certain types:
 //!
 //! * `HashMap`, `HashSet` as they can not be traversed in deterministic order
+//! * `BinaryHeap` as its iteration order is unspecified. It can be digested as a sorted
+//!   list using `as = Vec<_>` attribute
 //!
 //! The `Digestable` trait can be implemented for the struct using [a macro](derive@Digestable):
 //! ```rust
@@ -42,12 +50,193 @@
 //!
 //! ## Features
 //! * `digest` enables support of hash functions that implement [`digest`] traits \
-//!    If feature is not enabled, the crate is still usable via [`Digestable`] trait that
-//!    generically implements unambiguous encoding
+//!   If feature is not enabled, the crate is still usable via [`Digestable`] trait that
+//!   generically implements unambiguous encoding
+//! * `mac` enables [`hash_mac`] function that authenticates structured data using message
+//!   authentication codes (like HMAC) that implement [`digest::Mac`] trait. Enables `digest` feature.
+//! * `hkdf` enables [`derive_key`] and [`derive_key_with_info`] functions that derive keys
+//!   from structured data using [HKDF](hkdf). Enables `digest` feature.
+//! * `blake3` enables [`hash_blake3`], [`hash_blake3_keyed`], and [`derive_key_blake3`] functions
+//!   that digest structured data using [BLAKE3](blake3) in its regular, keyed, and key derivation
+//!   modes, and implements [`Buffer`] for [`blake3::Hasher`]
+//! * `rayon` enables [`hash_iter_par`] function that digests large collections in parallel
+//!   using [`rayon`], and [`EncodeList::extend_par`](encoding::EncodeList::extend_par) method
+//!   that encodes list items in parallel. Enables `digest` and `std` features.
 //! * `inline-struct` is required to use [`inline_struct!`] macro
 //! * `std` implements `Digestable` trait for types in standard library
-//! * `alloc` implements `Digestable` trait for type in `alloc` crate
+//! * `alloc` implements `Digestable` trait for type in `alloc` crate, and enables
+//!   [`encode_to_vec`] function, [`decoding` module](decoding) that parses encoded bytes
+//!   back into a tree of values, [`prefixed` module](prefixed) with length-prefixed encoding
+//!   that can be parsed incrementally, [`cbor` module](cbor) that exports the tree to CBOR,
+//!   and [`testing::vectors`] module that generates test vectors
 //! * `derive` enables `Digestable` proc macro
+//! * `float` implements `Digestable` trait for `f32` and `f64` \
+//!   Floats are canonicalized before encoding: all NaNs are encoded as the same NaN,
+//!   and `-0.0` is encoded as `+0.0`. See [`encoding` module](encoding#floats) for details.
+//! * `chrono` implements `Digestable` trait for [`chrono`](::chrono) date and time types:
+//!   `DateTime<Utc>`, `DateTime<FixedOffset>`, `NaiveDateTime`, `NaiveDate`, `NaiveTime`,
+//!   and `TimeDelta` \
+//!   Timestamps are encoded as a number of seconds since Unix epoch, nanoseconds, and an offset.
+//!   See [`Digestable`] implementations docs for details.
+//! * `num-bigint` implements `Digestable` trait for [`BigUint`](num_bigint::BigUint) and
+//!   [`BigInt`](num_bigint::BigInt) \
+//!   Big integers are encoded exactly as primitive integers, i.e. a big integer has the same
+//!   encoding as a primitive integer of the same value
+//! * `num-rational` implements `Digestable` trait for [`Ratio<T>`](num_rational::Ratio) \
+//!   Ratio is normalized before encoding, so equal ratios always have the same encoding
+//! * `num-complex` implements `Digestable` trait for [`Complex<T>`](num_complex::Complex) \
+//!   Complex number is encoded as a tuple `(re, im)`
+//! * `either` implements `Digestable` trait for [`Either<L, R>`](either::Either) \
+//!   It's encoded as an enum with `Left` and `Right` variants, similarly to `Result`
+//! * `semver` implements `Digestable` trait for [`Version`](semver::Version) and
+//!   [`VersionReq`](semver::VersionReq) \
+//!   Version is encoded as a struct `{ major, minor, patch, pre, build }`
+//! * `ipnet` implements `Digestable` trait for [`IpNet`](ipnet::IpNet), [`Ipv4Net`](ipnet::Ipv4Net),
+//!   and [`Ipv6Net`](ipnet::Ipv6Net) \
+//!   Network is encoded as a struct `{ addr, prefix_len }`
+//! * `half` implements `Digestable` trait for [`f16`](half::f16) and [`bf16`](half::bf16) \
+//!   Half-precision floats are encoded exactly as `f64` holding the same value. Enables `float`
+//!   feature.
+//! * `alloy-primitives` implements `Digestable` trait for [`Uint`](alloy_primitives::Uint)
+//!   (e.g. `U256`), [`FixedBytes`](alloy_primitives::FixedBytes) (e.g. `B256`),
+//!   [`Address`](alloy_primitives::Address), [`Bloom`](alloy_primitives::Bloom), and
+//!   [`Bytes`](alloy_primitives::Bytes) \
+//!   Unsigned integers are encoded exactly as primitive integers, other types are encoded as
+//!   bytestrings
+//! * `generic-array` implements `Digestable` trait for [`GenericArray<T, N>`](generic_array::GenericArray) \
+//!   It's encoded exactly as `[T; N]`. Use [`Bytes`] to digest `GenericArray<u8, N>` as a bytestring.
+//! * `hybrid-array` implements `Digestable` trait for [`Array<T, U>`](hybrid_array::Array) \
+//!   It's encoded exactly as `[T; N]`. Use [`Bytes`] to digest `Array<u8, U>` as a bytestring.
+//! * `zeroize` implements `Digestable` trait for [`Zeroizing<T>`](zeroize::Zeroizing) \
+//!   It's encoded exactly as `T`. Also enables [`hash_secret`] function,
+//!   [`encoding::ZeroizingVec`] buffer, and implements [`Zeroize`](zeroize::Zeroize) for
+//!   buffers wrapping zeroizable types.
+//! * `ulid` implements `Digestable` trait for [`Ulid`](ulid::Ulid) \
+//!   It's encoded as a bytestring of 16 bytes. Use [`as_::Display`] rule to digest it in string form.
+//! * `bson` implements `Digestable` trait for [`Bson`](bson::Bson), [`Document`](bson::Document),
+//!   [`ObjectId`](bson::oid::ObjectId), and other BSON types \
+//!   Documents are digested with keys sorted, so order of the fields doesn't affect the hash.
+//!   Enables `std` and `float` features.
+//! * `smol_str` implements `Digestable` trait for [`SmolStr`](smol_str::SmolStr) \
+//!   It's encoded exactly as `str`
+//! * `compact_str` implements `Digestable` trait for [`CompactString`](compact_str::CompactString) \
+//!   It's encoded exactly as `str`
+//! * `bstr` implements `Digestable` trait for [`BStr`](bstr::BStr) and [`BString`](bstr::BString) \
+//!   They are encoded as bytestrings, even if they contain invalid UTF-8. Enables `alloc` feature.
+//! * `camino` implements `Digestable` trait for [`Utf8Path`](camino::Utf8Path) and
+//!   [`Utf8PathBuf`](camino::Utf8PathBuf) \
+//!   Paths are encoded exactly as `str`. Enables `std` feature.
+//! * `hashbrown` provides [`DigestAs`] rules for `hashbrown::HashMap` and `hashbrown::HashSet`, so they
+//!   can be digested as `BTreeMap` and `BTreeSet` via `as` attribute. Enables `alloc` feature.
+//! * `im` implements `Digestable` trait for [`Vector`](im::Vector), [`OrdMap`](im::OrdMap), and
+//!   [`OrdSet`](im::OrdSet) \
+//!   They are encoded exactly as `Vec`, `BTreeMap`, and `BTreeSet` respectively. Enables `std` feature.
+//! * `rpds` implements `Digestable` trait for [`List`](rpds::List), [`Vector`](rpds::Vector),
+//!   [`Stack`](rpds::Stack), [`Queue`](rpds::Queue), [`RedBlackTreeMap`](rpds::RedBlackTreeMap), and
+//!   [`RedBlackTreeSet`](rpds::RedBlackTreeSet) \
+//!   Sequences are encoded exactly as `Vec`, maps and sets are encoded as `BTreeMap` and `BTreeSet`.
+//!   Enables `alloc` feature.
+//! * `dashmap` provides [`DigestAs`] rules for `DashMap` and `DashSet`, so they can be digested as
+//!   `BTreeMap` and `BTreeSet` via `as` attribute. Enables `std` feature.
+//! * `roaring` implements `Digestable` trait for [`RoaringBitmap`](roaring::RoaringBitmap) and
+//!   [`RoaringTreemap`](roaring::RoaringTreemap) \
+//!   They are encoded exactly as `BTreeSet<u32>` and `BTreeSet<u64>` respectively, regardless of
+//!   the internal container layout. Enables `std` feature.
+//! * `serde_json` implements `Digestable` trait for [`Value`](serde_json::Value),
+//!   [`Map`](serde_json::Map), and [`Number`](serde_json::Number) \
+//!   Objects are digested with keys sorted, numbers are canonicalized so that the same value
+//!   always has the same hash regardless of its representation (except for numbers out of `f64`
+//!   range when `arbitrary_precision` feature of `serde_json` is enabled). Enables `alloc` and
+//!   `float` features.
+//! * `toml` implements `Digestable` trait for [`Value`](toml::Value), [`Table`](toml::Table),
+//!   and [`Datetime`](toml::value::Datetime) \
+//!   Tables are digested with keys sorted, datetimes are encoded by value, so their textual
+//!   representation doesn't affect the hash. Enables `std` and `float` features.
+//! * `ciborium` implements `Digestable` trait for CBOR [`Value`](ciborium::Value) \
+//!   Map entries are digested sorted by encoding of their keys, so order of the entries doesn't
+//!   affect the hash. Enables `std` and `float` features.
+//! * `http` implements `Digestable` trait for [`Uri`](http::Uri), [`Method`](http::Method),
+//!   [`StatusCode`](http::StatusCode), [`HeaderName`](http::HeaderName),
+//!   [`HeaderValue`](http::HeaderValue), and [`HeaderMap`](http::HeaderMap) \
+//!   Headers in `HeaderMap` are digested sorted by name. Enables `std` feature.
+//! * `prost-types` implements `Digestable` trait for [`Timestamp`](prost_types::Timestamp),
+//!   [`Duration`](prost_types::Duration), [`Any`](prost_types::Any),
+//!   [`Struct`](prost_types::Struct), [`ListValue`](prost_types::ListValue), and
+//!   [`Value`](prost_types::Value) \
+//!   Timestamps and durations are normalized, struct fields are digested sorted by name.
+//!   Enables `alloc` and `float` features.
+//! * `multihash` implements `Digestable` trait for [`Multihash`](multihash::Multihash) \
+//!   It's encoded as a bytestring containing its binary form. Enables `alloc` feature.
+//! * `cid` implements `Digestable` trait for [`Cid`](cid::Cid) \
+//!   It's encoded as a bytestring containing its binary form. Enables `multihash` feature.
+//! * `elliptic-curve` implements `Digestable` trait for [`PublicKey<C>`](elliptic_curve::PublicKey)
+//!   and [`NonZeroScalar<C>`](elliptic_curve::NonZeroScalar) of any curve (e.g. `k256`, `p256`) \
+//!   Points are encoded in SEC1 compressed form, scalars are encoded as their canonical bytes
+//!   representation. Curve points and scalars can be digested via [`as_::Sec1`] and
+//!   [`as_::FieldRepr`] rules.
+//! * `hash2curve` enables [`hash2curve` module](hash2curve) that hashes structured data to
+//!   field elements and curve points as defined in RFC 9380. Enables `elliptic-curve` and
+//!   `alloc` features.
+//! * `ed25519-dalek` implements `Digestable` trait for [`VerifyingKey`](ed25519_dalek::VerifyingKey)
+//!   and [`Signature`](ed25519_dalek::Signature) \
+//!   They are encoded as bytestrings of 32 and 64 bytes respectively.
+//! * `bls12_381` implements `Digestable` trait for [`G1Affine`](bls12_381::G1Affine),
+//!   [`G2Affine`](bls12_381::G2Affine), [`Scalar`](bls12_381::Scalar), and [`Gt`](bls12_381::Gt) \
+//!   Points are encoded in compressed form, scalars are encoded as their canonical bytes representation.
+//! * `chrono-tz` implements `Digestable` trait for [`Tz`](chrono_tz::Tz) and `DateTime<Tz>` \
+//!   Time zone is encoded as its IANA name. Enables `chrono` feature.
+//! * `nalgebra` implements `Digestable` trait for [`Matrix`](nalgebra::Matrix), which covers
+//!   statically- and dynamically-sized matrices, vectors, and views \
+//!   Matrix is encoded as its dimensions followed by elements in row-major order. Use `float`
+//!   feature to digest matrices of floats.
+//! * `geo` implements `Digestable` trait for geometries from `geo-types` crate, such as
+//!   [`Coord`](geo_types::Coord), [`Point`](geo_types::Point),
+//!   [`LineString`](geo_types::LineString), [`Polygon`](geo_types::Polygon), and
+//!   [`Geometry`](geo_types::Geometry) \
+//!   Geometries are not normalized, i.e. the order of points and rings is preserved.
+//! * `ssh-key` implements `Digestable` trait for [`PublicKey`](ssh_key::PublicKey),
+//!   [`KeyData`](ssh_key::public::KeyData), and [`Fingerprint`](ssh_key::Fingerprint) \
+//!   Public key is encoded in SSH wire format, key comment is not digested.
+//! * `multiaddr` implements `Digestable` trait for [`Multiaddr`](multiaddr::Multiaddr) \
+//!   Enables `libp2p-identity` feature.
+//! * `libp2p-identity` implements `Digestable` trait for [`PeerId`](libp2p_identity::PeerId)
+//! * `der` provides [`as_::Der`] rule that digests any DER-encodable value as its DER bytes
+//! * `x509-cert` implements `Digestable` trait for [`Certificate`](x509_cert::Certificate),
+//!   [`TbsCertificate`](x509_cert::TbsCertificate),
+//!   [`SubjectPublicKeyInfo`](x509_cert::spki::SubjectPublicKeyInfo), and
+//!   [`RdnSequence`](x509_cert::name::RdnSequence) \
+//!   Values are encoded as their DER bytes. Enables `der` feature.
+//! * `serde_yaml` implements `Digestable` trait for [`Value`](serde_yaml::Value),
+//!   [`Mapping`](serde_yaml::Mapping), and [`Number`](serde_yaml::Number) \
+//!   Mappings are digested with keys sorted, numbers and tags are canonicalized. Enables `std`
+//!   and `float` features.
+//! * `bitflags` provides [`as_::Bits`] rule that digests flags generated by `bitflags!` macro
+//!   via their `bits()` value
+//! * `fixed` implements `Digestable` trait for fixed-point numbers [`FixedI32`](fixed::FixedI32),
+//!   [`FixedU32`](fixed::FixedU32), and other `FixedI*`/`FixedU*` types \
+//!   Number is encoded as its underlying bits and the number of fractional bits.
+//! * `nonempty` implements `Digestable` trait for [`NonEmpty<T>`](nonempty::NonEmpty) \
+//!   It's encoded exactly as `Vec<T>` with the same elements. Enables `alloc` feature.
+//! * `slotmap` implements `Digestable` trait for [`SlotMap`](slotmap::SlotMap),
+//!   [`DenseSlotMap`](slotmap::DenseSlotMap), [`SecondaryMap`](slotmap::SecondaryMap), and
+//!   [`KeyData`](slotmap::KeyData) \
+//!   Maps are encoded as `BTreeMap<u64, V>` with keys converted via `KeyData::as_ffi`. Enables
+//!   `alloc` feature.
+//! * `slab` implements `Digestable` trait for [`Slab<T>`](slab::Slab) \
+//!   It's encoded exactly as `BTreeMap<usize, T>`. Enables `alloc` feature.
+//! * `hashlink` implements `Digestable` trait for [`LinkedHashMap`](hashlink::LinkedHashMap),
+//!   [`LinkedHashSet`](hashlink::LinkedHashSet), and [`LruCache`](hashlink::LruCache) \
+//!   They are encoded in insertion order, i.e. as `Vec<(K, V)>` and `Vec<T>`. When order doesn't
+//!   matter, maps and sets can be digested as `BTreeMap` and `BTreeSet` via `as` attribute.
+//!   Enables `alloc` feature.
+//! * `uom` implements `Digestable` trait for SI quantities [`Quantity`](uom::si::Quantity) \
+//!   Quantity is encoded as its value in base units along with exponents of its dimension,
+//!   so quantities of different dimensions never collide. Use `float` feature to digest
+//!   quantities stored as floats.
+//! * `bytemuck` provides [`as_::PodBytes`] and [`as_::PodSlice`] rules that digest plain-old-data
+//!   values and slices of them via their raw bytes
+//! * `zerocopy` provides [`as_::ZerocopyBytes`] and [`as_::ZerocopySlice`] rules that digest values
+//!   and slices of them via their raw bytes
 //!
 //! ## Join us in Discord!
 //! Feel free to reach out to us [in Discord](https://discordapp.com/channels/905194001349627914/1285268686147424388)!
@@ -68,7 +257,8 @@ pub use encoding::Buffer;
 /// Derives a [`Digestable`] trait
 ///
 /// Works with any struct and enum. Requires each field to be [`Digestable`] or, alternatively,
-/// it can be specified how to digest a field via attributes.
+/// it can be specified how to digest a field via attributes. Unions are supported as well, but
+/// only if it's specified how to digest them via `with` or `as_bytes` container attribute.
 ///
 /// ### Example
 /// ```rust
@@ -107,6 +297,8 @@ pub use encoding::Buffer;
 ///   `person_a` and `person_b` have exactly the same hash as they have the same bytes
 ///   representation. If you need to distinguish them, you can specify a domain-separation
 ///   tag using `#[udigest(tag = "...")]` attribute.
+/// * Several attributes can be specified either separately or within a single
+///   `#[udigest(...)]` separated by commas, e.g. `#[udigest(tag = "...", bound = "")]`
 ///
 /// ### Container attributes
 /// * `#[udigest(tag = "...")]` \
@@ -136,6 +328,183 @@ pub use encoding::Buffer;
 ///       job_title: String,
 ///   }
 ///   ```
+/// * `#[udigest(with = ...)]` \
+///   Overrides the encoding of the whole container. Accepts the same function as
+///   [field-level `with` attribute](#field-attributes), which receives a reference to the
+///   container. Cannot be used together with `tag` attribute. It's the way to digest
+///   a union:
+///   ```rust
+///   #[derive(udigest::Digestable)]
+///   #[udigest(with = encode_value)]
+///   union Value {
+///       int: u32,
+///       bytes: [u8; 4],
+///   }
+///   fn encode_value<B: udigest::Buffer>(
+///       value: &Value,
+///       encoder: udigest::encoding::EncodeValue<B>,
+///   ) {
+///       // Any bit pattern is a valid `[u8; 4]`
+///       encoder.encode_leaf_value(unsafe { value.bytes })
+///   }
+///   ```
+/// * `#[udigest(as_bytes)]`, `#[udigest(as_bytes = ...)]` \
+///   Tells that the whole container should be digested as a bytestring, similarly to
+///   [field-level `as_bytes` attribute](#field-attributes). Without a function specified, the
+///   container must implement `AsRef<[u8]>`. Can be combined with `tag` attribute.
+///   ```rust
+///   #[derive(udigest::Digestable)]
+///   #[udigest(as_bytes = Pod::to_bytes)]
+///   union Pod {
+///       int: u32,
+///       float: f32,
+///   }
+///   impl Pod {
+///       fn to_bytes(&self) -> [u8; 4] {
+///           // Any bit pattern is a valid `u32`
+///           unsafe { self.int }.to_be_bytes()
+///       }
+///   }
+///   ```
+/// * `#[udigest(from = ...)]` \
+///   Tells to digest the container by converting it into another type `U` which implements
+///   [`Digestable`]. The container must implement [`Clone`], and `U` must implement `From<Self>`.
+///   Cannot be used together with `tag` attribute.
+///   ```rust
+///   #[derive(Clone, udigest::Digestable)]
+///   #[udigest(from = u64)]
+///   struct Seconds(u64);
+///   impl From<Seconds> for u64 {
+///       fn from(s: Seconds) -> u64 {
+///           s.0
+///       }
+///   }
+///   ```
+/// * `#[udigest(untagged)]` \
+///   Can only be used with enums. Tells to encode the enum without the variant name:
+///   newtype variant (a tuple variant with a single field) is encoded exactly as its inner
+///   value, any other variant is encoded as a structure with the same fields would be encoded.
+///   Cannot be used together with `tag` attribute.
+///
+///   Note that different variants of untagged enum may have the same encoding.
+///   ```rust
+///   #[derive(udigest::Digestable)]
+///   struct Person {
+///       name: String,
+///   }
+///
+///   #[derive(udigest::Digestable)]
+///   #[udigest(untagged)]
+///   enum Stored {
+///       Inline(Person),
+///       Shared(std::sync::Arc<Person>),
+///   }
+///
+///   let alice = Person { name: "Alice".into() };
+///   let alice_hash = udigest::hash::<sha2::Sha256>(&alice);
+///   assert_eq!(
+///       alice_hash,
+///       udigest::hash::<sha2::Sha256>(&Stored::Inline(alice)),
+///   );
+///   ```
+/// * `#[udigest(rename_fields = ["...", ...])]` \
+///   Can only be used with tuple structs. Specifies names of the fields that are used in the
+///   encoding instead of their positions `"0"`, `"1"`, etc. Exactly one name per each field must be
+///   provided. It makes it possible to turn a tuple struct into a struct with named fields without
+///   changing the hash.
+///   ```rust
+///   #[derive(udigest::Digestable)]
+///   #[udigest(rename_fields = ["x", "y"])]
+///   struct Point(i64, i64);
+///
+///   #[derive(udigest::Digestable)]
+///   struct NamedPoint {
+///       x: i64,
+///       y: i64,
+///   }
+///
+///   assert_eq!(
+///       udigest::hash::<sha2::Sha256>(&Point(3, 4)),
+///       udigest::hash::<sha2::Sha256>(&NamedPoint { x: 3, y: 4 }),
+///   );
+///   ```
+/// * `#[udigest(compat = "0.2")]` \
+///   Guarantees that the encoding is exactly the same as the one produced by `udigest` v0.2 derive
+///   macro. Attributes which encoding v0.2 could not produce (`untagged`, `rename_fields`,
+///   container-level `with`, `as_bytes` and `from`, field-level `as_bytes_iter` and `from`)
+///   cannot be used in the compatibility mode. Attributes on enum variants are rejected as well:
+///   v0.2 derive silently ignored them, so they would have no effect.
+///   ```rust
+///   #[derive(udigest::Digestable)]
+///   #[udigest(compat = "0.2")]
+///   enum Shape {
+///       Circle { radius: u8 },
+///       Square { #[udigest(rename = "side")] width: u8 },
+///   }
+///   ```
+///   ```compile_fail
+///   #[derive(udigest::Digestable)]
+///   #[udigest(compat = "0.2")]
+///   enum Shape {
+///       #[udigest(with = encode_circle)]
+///       Circle { radius: u8 },
+///   }
+///   # fn encode_circle<B: udigest::Buffer>(_: &u8, _: udigest::encoding::EncodeStruct<B>) {}
+///   ```
+/// * `#[udigest(test_vector(value = "...", expected = "..."))]` \
+///   Generates a `#[test]` which asserts that sha2-256 hash of the value matches the expected one.
+///   `value` is a string containing an expression that evaluates to `Self`, `expected` is the
+///   expected hash encoded in hex. It catches accidental changes of the encoding, like renaming
+///   a field. The attribute can be specified several times. Generic types are not supported. \
+///   Requires `digest` feature to be enabled. The generated test refers to `::sha2::Sha256`, so
+///   the crate using the attribute must have `sha2` in its `[dev-dependencies]`. Alternatively,
+///   any other fixed-output hash function can be specified via `hash = path::To::Hash`
+///   (e.g. `hash = sha3::Sha3_256`), then the crate needs to depend only on the crate providing it.
+///   Length of the `expected` hash must match the output size of the hash function
+///   (`D::OutputSize`), e.g. 128 hex digits for `hash = sha2::Sha512`. \
+///   `sha256 = "..."` is a deprecated alias to `expected = "..."`.
+///   ```rust
+///   #[derive(udigest::Digestable)]
+///   #[udigest(test_vector(
+///       value = "Person::alice()",
+///       expected = "49c43095eaffb3e3232dd23940686d3c6fb80e5ff82b5a09d336ad32369ca9df",
+///   ))]
+///   struct Person {
+///       name: &'static str,
+///       age: u16,
+///       job_title: &'static str,
+///   }
+///   impl Person {
+///       fn alice() -> Self {
+///           Self { name: "Alice", age: 24, job_title: "cryptographer" }
+///       }
+///   }
+///   ```
+///
+/// ### Variant attributes
+/// * `#[udigest(with = ...)]` \
+///   Overrides the encoding of the variant fields. The variant name is still encoded as usual,
+///   then the specified function is called with references to all the variant fields (in the
+///   order they are defined) followed by an encoder which can be used to encode the fields.
+///   Other variants are not affected. Field-level attributes cannot be used in such variant.
+///   ```rust
+///   use udigest::Digestable;
+///
+///   #[derive(udigest::Digestable)]
+///   enum Message {
+///       Ping { seq: u32 },
+///       #[udigest(with = encode_legacy)]
+///       Legacy(u32, String),
+///   }
+///   fn encode_legacy<B: udigest::Buffer>(
+///       seq: &u32,
+///       text: &String,
+///       mut encoder: udigest::encoding::EncodeStruct<B>,
+///   ) {
+///       seq.unambiguously_encode(encoder.add_field("seq"));
+///       encoder.add_leaf_field("payload", text);
+///   }
+///   ```
 ///
 /// ### Field attributes
 /// * `#[udigest(as_bytes)]` \
@@ -163,6 +532,20 @@ pub use encoding::Buffer;
 ///       data: Data
 ///   }
 ///   ```
+/// * `#[udigest(as_bytes_iter = ...)]` \
+///   Tells that the field should be converted to a bytestring that consists of several
+///   chunks. Uses specified function that accepts a reference of the field value, and returns
+///   `impl IntoIterator<Item = impl AsRef<[u8]>>`. All the chunks are concatenated into a
+///   single leaf, so the encoding is the same as if the chunks were joined into one bytestring
+///   and digested with `#[udigest(as_bytes)]`, but without allocating a contiguous buffer.
+///   ```rust
+///   #[derive(udigest::Digestable)]
+///   struct Response {
+///       status: u16,
+///       #[udigest(as_bytes_iter = <[_]>::iter)]
+///       body: Vec<Vec<u8>>,
+///   }
+///   ```
 /// * `#[udigest(with = ...)]` \
 ///   Can be used to override the field encoding. Accepts as input a function with a signature:
 ///   ```rust,no_run
@@ -232,6 +615,18 @@ pub use encoding::Buffer;
 ///   );
 ///   ```
 ///
+/// * `#[udigest(from = ...)]` \
+///   Tells to digest the field by converting it into another type `U` which implements
+///   [`Digestable`]. The field is cloned, and then converted via `U::from`, so the field
+///   type must implement [`Clone`], and `U` must implement `From<FieldType>`. It's a simpler
+///   alternative to `#[udigest(as = ...)]` when the conversion already exists.
+///   ```rust
+///   #[derive(udigest::Digestable)]
+///   struct Config {
+///       #[udigest(from = u32)]
+///       port: std::num::NonZeroU32,
+///   }
+///   ```
 /// * `#[udigest(rename = "...")]` \
 ///   Specifies another name to use for the field. As field name gets mixed into the hash,
 ///   changing the field name will change the hash. Sometimes, it may be required to change
@@ -249,13 +644,120 @@ pub use encoding::Buffer;
 #[cfg(feature = "derive")]
 pub use udigest_derive::Digestable;
 
+#[cfg(feature = "alloc")]
+pub mod cbor;
+#[cfg(feature = "alloc")]
+pub mod decoding;
 pub mod encoding;
+#[cfg(feature = "hash2curve")]
+pub mod hash2curve;
 #[cfg(feature = "inline-struct")]
 pub mod inline_struct;
+#[cfg(feature = "alloc")]
+pub mod prefixed;
+#[cfg(feature = "alloc")]
+pub mod testing;
 
 pub mod as_;
 pub use as_::DigestAs;
 
+mod integrations;
+
+mod compare;
+pub use compare::{cmp, eq, ByEncoding};
+#[cfg(feature = "digest")]
+mod hasher;
+#[cfg(feature = "digest")]
+pub use hasher::{Digester, Hasher};
+#[cfg(feature = "digest")]
+mod memoized;
+#[cfg(feature = "digest")]
+pub use memoized::Memoized;
+mod std_hash;
+pub use std_hash::StdHashAdapter;
+
+/// Encodes a structured `value` into bytes without hashing
+///
+/// Returns the same bytes that would be fed to the hash function by [`hash`]. Useful for
+/// testing and debugging, or for hashing with a function that doesn't implement [`digest`]
+/// traits.
+///
+/// ```rust
+/// let bytes = udigest::encode_to_vec(&"abc");
+/// assert_eq!(bytes, b"abc\x00\x00\x00\x03\x05\x03");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec(value: &impl Digestable) -> alloc::vec::Vec<u8> {
+    let mut buffer = alloc::vec::Vec::new();
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut buffer));
+    buffer
+}
+
+/// Returns length of the `value` encoding in bytes
+///
+/// Encoding is not stored anywhere, so it can be used to pre-allocate a buffer or to check
+/// the size limits before digesting the value.
+///
+/// ```rust
+/// let value = ("Alice", 24_u32);
+/// let len = udigest::encoded_len(&value);
+/// # #[cfg(feature = "alloc")]
+/// assert_eq!(len, udigest::encode_to_vec(&value).len());
+/// ```
+pub fn encoded_len(value: &impl Digestable) -> usize {
+    let mut counter = encoding::LengthCounter::default();
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut counter));
+    counter.0
+}
+
+/// Encodes a structured `value` into a fallible buffer
+///
+/// Once the buffer fails to append bytes, the rest of the encoding is discarded, and the
+/// error is returned. See [`encoding::SliceBuffer`] for an example.
+pub fn try_encode<B: encoding::TryBuffer>(
+    value: &impl Digestable,
+    buffer: &mut B,
+) -> Result<(), B::Error> {
+    let mut buffer = encoding::BufferFallible::new(buffer);
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut buffer));
+    buffer.finish()
+}
+
+/// Encodes a structured `value` into the beginning of `out` slice
+///
+/// Returns length of the encoding. The value is encoded twice: the first pass computes the
+/// length of the encoding (see [`encoded_len`]), and the second pass writes it. If the encoding
+/// doesn't fit, an error is returned and `out` is left untouched. Doesn't require any
+/// allocations.
+///
+/// ```rust
+/// let mut out = [0u8; 16];
+/// let len = udigest::encode_into(&"abc", &mut out)?;
+/// assert_eq!(&out[..len], b"abc\x00\x00\x00\x03\x05\x03");
+///
+/// let err = udigest::encode_into(&"a long string", &mut out).unwrap_err();
+/// assert_eq!(err.required(), 19);
+/// assert_eq!(err.available(), 16);
+/// # Ok::<_, udigest::encoding::BufferTooSmall>(())
+/// ```
+pub fn encode_into(
+    value: &impl Digestable,
+    out: &mut [u8],
+) -> Result<usize, encoding::BufferTooSmall> {
+    let required = encoded_len(value);
+    let err = encoding::BufferTooSmall {
+        required,
+        available: out.len(),
+    };
+    if required > out.len() {
+        return Err(err);
+    }
+    let mut buffer = encoding::SliceBuffer::new(out);
+    // can only fail if `Digestable` impl produces different encodings on different calls
+    try_encode(value, &mut buffer).map_err(|_| err)?;
+    Ok(buffer.len())
+}
+
 /// Digests a structured `value` using fixed-output hash function (like sha2-256)
 #[cfg(feature = "digest")]
 pub fn hash<D: digest::Digest>(value: &impl Digestable) -> digest::Output<D> {
@@ -264,6 +766,113 @@ pub fn hash<D: digest::Digest>(value: &impl Digestable) -> digest::Output<D> {
     hash.0.finalize()
 }
 
+/// Feeds encoding of a structured `value` into an existing hasher
+///
+/// Unlike [`hash`], it doesn't construct a new hasher, so the caller can feed a protocol
+/// prefix into the hasher beforehand, or reuse the same hasher for many values (e.g. via
+/// [`finalize_reset`](digest::Digest::finalize_reset)). Works with any hasher that
+/// implements [`digest::Update`], including all [`digest::Digest`] hash functions.
+///
+/// ```rust
+/// use sha2::Digest;
+///
+/// let mut hasher = sha2::Sha256::new();
+/// let hashes = [("Alice", 24_u32), ("Bob", 25)].map(|value| {
+///     udigest::hash_into(&mut hasher, &value);
+///     hasher.finalize_reset()
+/// });
+/// assert_eq!(hashes[0], udigest::hash::<sha2::Sha256>(&("Alice", 24_u32)));
+/// assert_eq!(hashes[1], udigest::hash::<sha2::Sha256>(&("Bob", 25_u32)));
+/// ```
+#[cfg(feature = "digest")]
+pub fn hash_into<H: digest::Update + ?Sized>(hasher: &mut H, value: &impl Digestable) {
+    struct UpdateRef<'h, H: ?Sized>(&'h mut H);
+    impl<H: digest::Update + ?Sized> Buffer for UpdateRef<'_, H> {
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.update(bytes)
+        }
+    }
+
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut UpdateRef(hasher)))
+}
+
+/// Digests a structured `value` using fixed-output hash function (like sha2-256) and
+/// compact encoding format
+///
+/// Lengths take much less space in compact format, which speeds up hashing deeply nested
+/// structures. Digest is different from [`hash`] of the same value. See
+/// [`encoding` module](encoding#compact-format-v2) for details.
+///
+/// ```rust
+/// let value = ("Alice", 24_u32);
+/// let hash = udigest::hash_v2::<sha2::Sha256>(&value);
+/// assert_ne!(hash, udigest::hash::<sha2::Sha256>(&value));
+/// ```
+#[cfg(feature = "digest")]
+pub fn hash_v2<D: digest::Digest>(value: &impl Digestable) -> digest::Output<D> {
+    let mut hash = encoding::BufferDigest(D::new());
+    value.unambiguously_encode(encoding::EncodeValue::new_with_format(
+        &mut hash,
+        encoding::Format::V2,
+    ));
+    hash.0.finalize()
+}
+
+/// Digests a structured `value` with domain separation `tag` using fixed-output hash
+/// function (like sha2-256)
+///
+/// Useful when the same type is digested in different contexts: the tag is specified at
+/// call site, so the type doesn't need to carry `#[udigest(tag = "...")]` attribute.
+/// Value is encoded as a single-item list tagged with `tag`, so the digest is different
+/// from [`hash`] of the same value, and different for different tags.
+///
+/// ```rust
+/// let value = ("Alice", 24_u32);
+/// let commitment = udigest::hash_tagged::<sha2::Sha256>(b"example.commitment", &value);
+/// let challenge = udigest::hash_tagged::<sha2::Sha256>(b"example.challenge", &value);
+/// assert_ne!(commitment, challenge);
+/// ```
+#[cfg(feature = "digest")]
+pub fn hash_tagged<D: digest::Digest>(tag: &[u8], value: &impl Digestable) -> digest::Output<D> {
+    let mut hash = encoding::BufferDigest(D::new());
+    let mut encoder = encoding::EncodeList::new(&mut hash).with_tag(tag);
+    value.unambiguously_encode(encoder.add_item());
+    encoder.finish();
+    hash.0.finalize()
+}
+
+/// Digests a secret `value` (like a key share) using fixed-output hash function
+///
+/// Produces the same digest as [`hash`]. Unlike [`hash`], it guarantees that no copies of the
+/// secret are left in memory once the function returns:
+/// * Encoding of the value is streamed straight into the hash function, udigest never copies
+///   it into intermediate buffers
+/// * Hash function is required to implement [`ZeroizeOnDrop`](zeroize::ZeroizeOnDrop), so its
+///   internal state, which contains (parts of) the encoding, is wiped once it's finalized
+///
+/// Note that hash functions based on `digest` v0.10 (like `sha2::Sha256`) don't implement
+/// `ZeroizeOnDrop`, use [`hash`] with them if wiping the hash state is not required. It's also
+/// up to the `Digestable` implementation of the value to not copy the secret into non-zeroized
+/// allocations. If the encoding needs to be stored, use [`encoding::ZeroizingVec`].
+///
+/// ```rust
+/// fn commit_to_key_share<D>(key_share: &[u8; 32]) -> digest::Output<D>
+/// where
+///     D: digest::Digest + zeroize::ZeroizeOnDrop,
+/// {
+///     udigest::hash_secret::<D>(&udigest::Bytes(key_share))
+/// }
+/// ```
+#[cfg(all(feature = "digest", feature = "zeroize"))]
+pub fn hash_secret<D>(value: &impl Digestable) -> digest::Output<D>
+where
+    D: digest::Digest + zeroize::ZeroizeOnDrop,
+{
+    let mut hash = encoding::BufferDigest(D::new());
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut hash));
+    hash.0.finalize()
+}
+
 /// Digests a list of structured data using fixed-output hash function (like sha2-256)
 #[cfg(feature = "digest")]
 pub fn hash_iter<D: digest::Digest>(
@@ -279,6 +888,105 @@ pub fn hash_iter<D: digest::Digest>(
     hash.0.finalize()
 }
 
+/// Digests key-value pairs as a map using fixed-output hash function (like sha2-256)
+///
+/// Each pair is encoded as a two-element list, pairs are encoded in the order they are
+/// given. If pairs are given in ascending order of keys, digest is the same as of `BTreeMap`
+/// with the same pairs, so the map doesn't need to be built just to be digested. Use
+/// [`hash_map_iter_sorted`] if pairs are given in arbitrary order.
+///
+/// ```rust
+/// let prices = [("apple", 3_u32), ("banana", 1), ("cherry", 7)];
+/// let hash = udigest::hash_map_iter::<sha2::Sha256, _, _>(prices);
+///
+/// let map = std::collections::BTreeMap::from(prices);
+/// assert_eq!(hash, udigest::hash::<sha2::Sha256>(&map));
+/// ```
+#[cfg(feature = "digest")]
+pub fn hash_map_iter<D: digest::Digest, K: Digestable, V: Digestable>(
+    iter: impl IntoIterator<Item = (K, V)>,
+) -> digest::Output<D> {
+    let mut hash = encoding::BufferDigest(D::new());
+    unambiguously_encode_iter(encoding::EncodeValue::new(&mut hash), iter);
+    hash.0.finalize()
+}
+
+/// Digests key-value pairs as a map in canonical order using fixed-output hash function
+/// (like sha2-256)
+///
+/// Pairs are sorted by [encoding](cmp) of their keys, then digested as [`hash_map_iter`]
+/// does, so the digest doesn't depend on the order in which pairs are given, e.g. it's
+/// the same for `HashMap`s with the same content. Keys are expected to be unique: pairs
+/// with identical key encodings are kept in the order they are given.
+///
+/// Note that order of encodings may differ from [`Ord`] of the keys (e.g. for integers),
+/// so the digest generally differs from the digest of `BTreeMap`.
+///
+/// ```rust
+/// let a = std::collections::HashMap::from([("apple", 3_u32), ("banana", 1), ("cherry", 7)]);
+/// let b = std::collections::HashMap::from([("cherry", 7_u32), ("apple", 3), ("banana", 1)]);
+/// assert_eq!(
+///     udigest::hash_map_iter_sorted::<sha2::Sha256, _, _>(&a),
+///     udigest::hash_map_iter_sorted::<sha2::Sha256, _, _>(&b),
+/// );
+/// ```
+#[cfg(all(feature = "digest", feature = "alloc"))]
+pub fn hash_map_iter_sorted<D: digest::Digest, K: Digestable, V: Digestable>(
+    iter: impl IntoIterator<Item = (K, V)>,
+) -> digest::Output<D> {
+    let mut pairs = iter
+        .into_iter()
+        .map(|(key, value)| (encode_to_vec(&key), (key, value)))
+        .collect::<alloc::vec::Vec<_>>();
+    pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+    hash_map_iter::<D, K, V>(pairs.into_iter().map(|(_, pair)| pair))
+}
+
+/// Digests a list of structured data in parallel using fixed-output hash function (like sha2-256)
+///
+/// Each item is digested separately in parallel, then digests of all items are combined
+/// in their original order. Output is the same as of digesting a list of items digests
+/// tagged with `udigest.par_list`, so it can be reproduced without `rayon`:
+///
+/// ```rust
+/// use udigest::encoding::{BufferDigest, EncodeList};
+/// use sha2::{Digest, Sha256};
+///
+/// let records: Vec<(u64, String)> = (0..1000).map(|i| (i, format!("record {i}"))).collect();
+/// let hash = udigest::hash_iter_par::<Sha256>(&records);
+///
+/// // Same as:
+/// let mut expected = BufferDigest(Sha256::new());
+/// let mut list = EncodeList::new(&mut expected).with_tag(b"udigest.par_list");
+/// for record in &records {
+///     list.add_leaf().chain(udigest::hash::<Sha256>(record));
+/// }
+/// list.finish();
+/// assert_eq!(hash, expected.0.finalize());
+/// ```
+///
+/// Note that output is different from [`hash_iter`]. To encode a list in parallel with the same
+/// output as sequential encoding, use [`EncodeList::extend_par`](encoding::EncodeList::extend_par).
+#[cfg(feature = "rayon")]
+pub fn hash_iter_par<D: digest::Digest>(
+    iter: impl rayon::iter::IntoParallelIterator<Item = impl Digestable>,
+) -> digest::Output<D> {
+    use rayon::iter::ParallelIterator;
+
+    let digests: alloc::vec::Vec<digest::Output<D>> = iter
+        .into_par_iter()
+        .map(|value| hash::<D>(&value))
+        .collect();
+
+    let mut hash = encoding::BufferDigest(D::new());
+    let mut encoder = encoding::EncodeList::new(&mut hash).with_tag(b"udigest.par_list");
+    for digest in &digests {
+        encoder.add_leaf().chain(digest);
+    }
+    encoder.finish();
+    hash.0.finalize()
+}
+
 /// Digests a structured `value` using extendable-output hash function (like shake-256)
 #[cfg(feature = "digest")]
 pub fn hash_xof<D>(value: &impl Digestable) -> D::Reader
@@ -290,6 +998,29 @@ where
     hash.0.finalize_xof()
 }
 
+/// Digests a structured `value` using extendable-output hash function (like shake-256) and
+/// returns first `N` bytes of the output
+///
+/// Same as reading `N` bytes from the reader returned by [`hash_xof`].
+///
+/// ```rust
+/// let value = ("Alice", 24_u32);
+/// let hash: [u8; 32] = udigest::hash_xof_n::<sha3::Shake256, 32>(&value);
+///
+/// let mut expected = [0u8; 32];
+/// digest::XofReader::read(&mut udigest::hash_xof::<sha3::Shake256>(&value), &mut expected);
+/// assert_eq!(hash, expected);
+/// ```
+#[cfg(feature = "digest")]
+pub fn hash_xof_n<D, const N: usize>(value: &impl Digestable) -> [u8; N]
+where
+    D: Default + digest::Update + digest::ExtendableOutput,
+{
+    let mut out = [0u8; N];
+    digest::XofReader::read(&mut hash_xof::<D>(value), &mut out);
+    out
+}
+
 /// Digests a list of structured data using extendable-output hash function (like shake-256)
 #[cfg(feature = "digest")]
 pub fn hash_xof_iter<D>(iter: impl IntoIterator<Item = impl Digestable>) -> D::Reader
@@ -340,6 +1071,153 @@ where
         .map_err(|_| digest::InvalidOutputSize)
 }
 
+/// Computes a message authentication code (like HMAC-SHA256) of a structured `value`
+///
+/// Returns an error if `key` has invalid length for the MAC algorithm. Returned [`CtOutput`](digest::CtOutput)
+/// is compared in constant time, use it to verify the MAC.
+///
+/// ```rust
+/// type HmacSha256 = hmac::Hmac<sha2::Sha256>;
+///
+/// let key = b"secret key";
+/// let message = ("transfer", 100_u64, "Alice", "Bob");
+/// let tag = udigest::hash_mac::<HmacSha256>(key, &message)?;
+///
+/// let received_tag = tag.clone().into_bytes();
+/// assert!(udigest::hash_mac::<HmacSha256>(key, &message)? == received_tag.into());
+/// # Ok::<_, digest::InvalidLength>(())
+/// ```
+#[cfg(feature = "mac")]
+pub fn hash_mac<M>(
+    key: &[u8],
+    value: &impl Digestable,
+) -> Result<digest::CtOutput<M>, digest::InvalidLength>
+where
+    M: digest::Mac + digest::KeyInit,
+{
+    let mut mac = encoding::BufferMac(<M as digest::Mac>::new_from_slice(key)?);
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut mac));
+    Ok(mac.0.finalize())
+}
+
+/// Derives a key from structured input keying material `ikm` using HKDF
+///
+/// `H` is a hash function HMAC is instantiated with (like sha2-256). `ikm` is
+/// unambiguously encoded and streamed into HKDF-Extract with optional `salt`, then
+/// HKDF-Expand fills `out` with the key derived for the `info` context.
+///
+/// Returns an error if `out` is too long (more than `255 * H::output_size()` bytes).
+///
+/// HKDF is instantiated with [`SimpleHmac`](hkdf::hmac::SimpleHmac), so any hash function
+/// that implements [`digest::Digest`] can be used. Output is the same as of [`hkdf::Hkdf`].
+///
+/// ```rust
+/// let shared_secret = [42u8; 32];
+/// let handshake = ("udigest.example.handshake", shared_secret, 1_u32);
+///
+/// let mut key = [0u8; 32];
+/// udigest::derive_key::<sha2::Sha256>(None, &handshake, b"encryption key", &mut key)?;
+/// # Ok::<_, hkdf::InvalidLength>(())
+/// ```
+#[cfg(feature = "hkdf")]
+pub fn derive_key<H>(
+    salt: Option<&[u8]>,
+    ikm: &impl Digestable,
+    info: &[u8],
+    out: &mut [u8],
+) -> Result<(), hkdf::InvalidLength>
+where
+    H: digest::Digest + digest::core_api::BlockSizeUser + Clone,
+{
+    struct Extract<H: digest::Digest + digest::core_api::BlockSizeUser + Clone>(
+        hkdf::HkdfExtract<H, hkdf::hmac::SimpleHmac<H>>,
+    );
+    impl<H: digest::Digest + digest::core_api::BlockSizeUser + Clone> encoding::Buffer for Extract<H> {
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.input_ikm(bytes)
+        }
+    }
+
+    let mut extract = Extract::<H>(hkdf::HkdfExtract::new(salt));
+    ikm.unambiguously_encode(encoding::EncodeValue::new(&mut extract));
+    let (_prk, hkdf) = extract.0.finalize();
+    hkdf.expand(info, out)
+}
+
+/// Derives a key for structured context `info` using HKDF
+///
+/// Same as [`derive_key`], but input keying material `ikm` is raw bytes (like a shared secret),
+/// and context `info` is structured data, like a session descriptor. Unlike `ikm`, `info` is
+/// processed several times by HKDF-Expand, so its encoding is buffered in memory.
+///
+/// ```rust
+/// let shared_secret = [42u8; 32];
+/// let session = ("udigest.example.session", 1_u32, ["Alice", "Bob"]);
+///
+/// let mut key = [0u8; 32];
+/// udigest::derive_key_with_info::<sha2::Sha256>(None, &shared_secret, &session, &mut key)?;
+/// # Ok::<_, hkdf::InvalidLength>(())
+/// ```
+#[cfg(all(feature = "hkdf", feature = "alloc"))]
+pub fn derive_key_with_info<H>(
+    salt: Option<&[u8]>,
+    ikm: &[u8],
+    info: &impl Digestable,
+    out: &mut [u8],
+) -> Result<(), hkdf::InvalidLength>
+where
+    H: digest::Digest + digest::core_api::BlockSizeUser + Clone,
+{
+    let info = encode_to_vec(info);
+    hkdf::SimpleHkdf::<H>::new(salt, ikm).expand(&info, out)
+}
+
+/// Digests a structured `value` using BLAKE3
+///
+/// ```rust
+/// let hash = udigest::hash_blake3(&("Alice", 24_u32));
+/// ```
+#[cfg(feature = "blake3")]
+pub fn hash_blake3(value: &impl Digestable) -> blake3::Hash {
+    let mut hasher = blake3::Hasher::new();
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut hasher));
+    hasher.finalize()
+}
+
+/// Digests a structured `value` using BLAKE3 in keyed mode
+///
+/// Keyed mode can be used as a MAC. Returned [`blake3::Hash`] is compared in constant time.
+///
+/// ```rust
+/// let key = [42u8; 32];
+/// let tag = udigest::hash_blake3_keyed(&key, &("Alice", 24_u32));
+/// ```
+#[cfg(feature = "blake3")]
+pub fn hash_blake3_keyed(key: &[u8; 32], value: &impl Digestable) -> blake3::Hash {
+    let mut hasher = blake3::Hasher::new_keyed(key);
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut hasher));
+    hasher.finalize()
+}
+
+/// Derives a key from structured key material `value` using BLAKE3 in key derivation mode
+///
+/// `context` should be hardcoded, globally unique, and application-specific, see
+/// [`blake3::derive_key`] for details.
+///
+/// ```rust
+/// let shared_secret = [42u8; 32];
+/// let key = udigest::derive_key_blake3(
+///     "udigest example 2024-01-01 session key",
+///     &(shared_secret, 1_u32),
+/// );
+/// ```
+#[cfg(feature = "blake3")]
+pub fn derive_key_blake3(context: &str, value: &impl Digestable) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new_derive_key(context);
+    value.unambiguously_encode(encoding::EncodeValue::new(&mut hasher));
+    hasher.finalize().into()
+}
+
 /// A value that can be unambiguously digested
 pub trait Digestable {
     /// Unambiguously encodes the value
@@ -352,6 +1230,22 @@ impl<T: Digestable + ?Sized> Digestable for &T {
     }
 }
 
+impl<T: Digestable + ?Sized> Digestable for &mut T {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        (**self).unambiguously_encode(encoder)
+    }
+}
+
+impl<P> Digestable for core::pin::Pin<P>
+where
+    P: core::ops::Deref,
+    P::Target: Digestable,
+{
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        (**self).unambiguously_encode(encoder)
+    }
+}
+
 /// Wrapper for a bytestring
 ///
 /// Wraps any bytestring that `impl AsRef<[u8]>` and provides [`Digestable`] trait implementation
@@ -417,6 +1311,41 @@ fn encode_unsigned_integer<B: Buffer>(be_bytes: &[u8], encoder: encoding::Encode
 digestable_signed_integers!(i8, i16, i32, i64, i128, isize);
 digestable_unsigned_integers!(u8, u16, u32, u64, u128, usize);
 
+/// Canonical NaN which all NaNs are mapped to before encoding
+#[cfg(feature = "float")]
+const CANONICAL_NAN_BITS: u64 = 0x7ff8_0000_0000_0000;
+
+/// Encodes a float
+///
+/// All NaNs are encoded as the same canonical NaN, `-0.0` is encoded as `+0.0`
+#[cfg(feature = "float")]
+fn encode_float<B: Buffer>(value: f64, encoder: encoding::EncodeValue<B>) {
+    let bits = if value.is_nan() {
+        CANONICAL_NAN_BITS
+    } else if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    };
+    encoder.encode_leaf_value(bits.to_be_bytes())
+}
+
+#[cfg(feature = "float")]
+impl Digestable for f64 {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encode_float(*self, encoder)
+    }
+}
+
+#[cfg(feature = "float")]
+impl Digestable for f32 {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        // conversion to `f64` is lossless, so `f32` and `f64` holding the same value
+        // have the same encoding
+        encode_float(f64::from(*self), encoder)
+    }
+}
+
 impl Digestable for bool {
     fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
         u8::from(*self).unambiguously_encode(encoder)
@@ -432,6 +1361,28 @@ impl Digestable for char {
     }
 }
 
+// Implements `Digestable` for atomics. Value is loaded with `SeqCst` ordering, and
+// encoded in the same way as its non-atomic counterpart.
+macro_rules! digestable_atomics {
+    ($($size:literal => $($atomic:ident),+;)*) => {$($(
+        #[cfg(target_has_atomic = $size)]
+        impl Digestable for core::sync::atomic::$atomic {
+            fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+                self.load(core::sync::atomic::Ordering::SeqCst)
+                    .unambiguously_encode(encoder)
+            }
+        }
+    )+)*};
+}
+
+digestable_atomics!(
+    "8" => AtomicBool, AtomicU8, AtomicI8;
+    "16" => AtomicU16, AtomicI16;
+    "32" => AtomicU32, AtomicI32;
+    "64" => AtomicU64, AtomicI64;
+    "ptr" => AtomicUsize, AtomicIsize;
+);
+
 // Implements `Digestable` for the types that can be converted to bytes
 macro_rules! digestable_as_bytes {
     ($($type:ty as $to_bytes:ident),*) => {$(
@@ -457,8 +1408,7 @@ impl<T: Digestable> Digestable for Option<T> {
         match self {
             Some(value) => {
                 let mut encoder = encoder.encode_enum().with_variant("Some");
-                let value_encoder = encoder.add_field("0");
-                value.unambiguously_encode(value_encoder);
+                encoder.add_field_with("0", |field| field.encode(value));
             }
             None => {
                 encoder.encode_enum().with_variant("None");
@@ -472,13 +1422,26 @@ impl<T: Digestable, E: Digestable> Digestable for Result<T, E> {
         match self {
             Ok(value) => {
                 let mut encoder = encoder.encode_enum().with_variant("Ok");
-                let value_encoder = encoder.add_field("0");
-                value.unambiguously_encode(value_encoder);
+                encoder.add_field_with("0", |field| field.encode(value));
             }
             Err(value) => {
                 let mut encoder = encoder.encode_enum().with_variant("Err");
-                let value_encoder = encoder.add_field("0");
-                value.unambiguously_encode(value_encoder);
+                encoder.add_field_with("0", |field| field.encode(value));
+            }
+        }
+    }
+}
+
+impl<B: Digestable, C: Digestable> Digestable for core::ops::ControlFlow<B, C> {
+    fn unambiguously_encode<BUF: Buffer>(&self, encoder: encoding::EncodeValue<BUF>) {
+        match self {
+            core::ops::ControlFlow::Continue(value) => {
+                let mut encoder = encoder.encode_enum().with_variant("Continue");
+                encoder.add_field_with("0", |field| field.encode(value));
+            }
+            core::ops::ControlFlow::Break(value) => {
+                let mut encoder = encoder.encode_enum().with_variant("Break");
+                encoder.add_field_with("0", |field| field.encode(value));
             }
         }
     }
@@ -500,7 +1463,14 @@ macro_rules! digestable_tuple {
     };
 }
 
-// We support tuples with up to 16 elements
+/// Unit is encoded as an empty tuple, i.e. an empty list
+impl Digestable for () {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_list();
+    }
+}
+
+// We support tuples with up to 32 elements
 digestable_tuple!(A);
 digestable_tuple!(A, B);
 digestable_tuple!(A, B, C);
@@ -517,6 +1487,34 @@ digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M);
 digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N);
 digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O);
 digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z);
+digestable_tuple!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, AA);
+digestable_tuple!(
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, AA, AB
+);
+digestable_tuple!(
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, AA, AB, AC
+);
+digestable_tuple!(
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, AA, AB, AC, AD
+);
+digestable_tuple!(
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, AA, AB, AC, AD,
+    AE
+);
+digestable_tuple!(
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, AA, AB, AC, AD,
+    AE, AF
+);
 
 fn unambiguously_encode_iter<B: Buffer, T: Digestable>(
     encoder: encoding::EncodeValue<B>,
@@ -601,9 +1599,72 @@ where
     }
 }
 
+impl<T: Digestable + Copy> Digestable for core::cell::Cell<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.get().unambiguously_encode(encoder)
+    }
+}
+
+/// Digests a value stored in `RefCell`
+///
+/// ## Panics
+/// Panics if the value is currently mutably borrowed (see [`RefCell::borrow`](core::cell::RefCell::borrow))
+impl<T: Digestable + ?Sized> Digestable for core::cell::RefCell<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        self.borrow().unambiguously_encode(encoder)
+    }
+}
+
+/// Locks the mutex and digests the value
+///
+/// If the mutex is poisoned, the value is digested anyway: poisoning does not prevent from
+/// reading the value. Blocks until the lock is acquired, i.e. it deadlocks (or panics)
+/// if the mutex is already locked by the current thread.
+#[cfg(feature = "std")]
+impl<T: Digestable + ?Sized> Digestable for std::sync::Mutex<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let guard = self
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        guard.unambiguously_encode(encoder)
+    }
+}
+
+/// Read-locks the lock and digests the value
+///
+/// If the lock is poisoned, the value is digested anyway: poisoning does not prevent from
+/// reading the value. Blocks until the lock is acquired, i.e. it deadlocks (or panics)
+/// if the lock is already write-locked by the current thread.
+#[cfg(feature = "std")]
+impl<T: Digestable + ?Sized> Digestable for std::sync::RwLock<T> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        let guard = self
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        guard.unambiguously_encode(encoder)
+    }
+}
+
 impl<T> Digestable for core::marker::PhantomData<T> {
     fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
         // Encode an empty list
         encoder.encode_list();
     }
 }
+
+impl Digestable for core::marker::PhantomPinned {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        // Encode an empty list
+        encoder.encode_list();
+    }
+}
+
+/// `Infallible` has no values, so it is never encoded. The impl makes it possible to digest
+/// types like `Result<T, Infallible>`.
+///
+/// Once the never type `!` is stabilized, it will get the same impl.
+impl Digestable for core::convert::Infallible {
+    fn unambiguously_encode<B: Buffer>(&self, _encoder: encoding::EncodeValue<B>) {
+        match *self {}
+    }
+}
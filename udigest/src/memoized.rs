@@ -0,0 +1,120 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Cell holding cached digest. It's thread-safe if `std` is available, so memoized values can
+/// be shared across threads.
+#[cfg(feature = "std")]
+type OnceCell<T> = std::sync::OnceLock<T>;
+#[cfg(not(feature = "std"))]
+type OnceCell<T> = core::cell::OnceCell<T>;
+
+/// Domain separation tag of the leaf containing memoized digest
+const TAG: &[u8] = b"udigest.memoized";
+
+/// Caches the digest of a value
+///
+/// Large values that are digested as a part of many messages are normally re-encoded every
+/// time. `Memoized<T, D>` digests the value using hash function `D` once, and then encodes
+/// as a leaf containing the cached digest, tagged with `udigest.memoized` domain separation tag.
+/// Note that it changes the encoding: `Memoized<T, D>` is never encoded the same way as `T`.
+///
+/// The digest is computed on the first encoding and invalidated only when the value is accessed
+/// via [`get_mut`](Self::get_mut). Note that if `T` has interior mutability (e.g. it contains
+/// a `RefCell` or a `Mutex`), modifying it via a shared reference doesn't invalidate the digest,
+/// and `Memoized<T, D>` keeps encoding the outdated one.
+///
+/// With `std` feature, `Memoized<T, D>` is [`Sync`] (given that `T` is), so a value can be
+/// shared across threads that digest messages in parallel. Without `std`, it's `!Sync`.
+///
+/// ```rust
+/// # #[cfg(all(feature = "derive", feature = "alloc"))] {
+/// use udigest::Memoized;
+///
+/// #[derive(udigest::Digestable)]
+/// struct Message<'a> {
+///     params: &'a Memoized<Vec<u64>, sha2::Sha256>,
+///     nonce: u64,
+/// }
+///
+/// let params = Memoized::new((0..10_000).collect::<Vec<u64>>());
+/// // `params` are encoded only once
+/// let hashes = (0..100)
+///     .map(|nonce| udigest::hash::<sha2::Sha256>(&Message { params: &params, nonce }))
+///     .collect::<Vec<_>>();
+/// # assert_ne!(hashes[0], hashes[1]);
+/// # }
+/// ```
+pub struct Memoized<T, D: digest::Digest> {
+    value: T,
+    digest: OnceCell<digest::Output<D>>,
+}
+
+impl<T: Digestable, D: digest::Digest> Memoized<T, D> {
+    /// Wraps the value
+    ///
+    /// Digest is not computed until the value is encoded
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            digest: OnceCell::new(),
+        }
+    }
+
+    /// Returns the digest of the value, computes it if it's not cached
+    ///
+    /// The digest is the same as returned by [`hash::<D>`](crate::hash)
+    pub fn digest(&self) -> &digest::Output<D> {
+        self.digest.get_or_init(|| crate::hash::<D>(&self.value))
+    }
+
+    /// Returns a reference to the value
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value, and invalidates the cached digest
+    pub fn get_mut(&mut self) -> &mut T {
+        self.digest = OnceCell::new();
+        &mut self.value
+    }
+
+    /// Returns the value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Digestable, D: digest::Digest> Digestable for Memoized<T, D> {
+    fn unambiguously_encode<B: Buffer>(&self, encoder: encoding::EncodeValue<B>) {
+        encoder.encode_leaf().with_tag(TAG).chain(self.digest());
+    }
+}
+
+impl<T: Clone, D: digest::Digest> Clone for Memoized<T, D> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            digest: self.digest.clone(),
+        }
+    }
+}
+
+impl<T: core::fmt::Debug, D: digest::Digest> core::fmt::Debug for Memoized<T, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Memoized")
+            .field("value", &self.value)
+            .field("is_cached", &self.digest.get().is_some())
+            .finish()
+    }
+}
+
+impl<T: Digestable + Default, D: digest::Digest> Default for Memoized<T, D> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Digestable, D: digest::Digest> From<T> for Memoized<T, D> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,299 @@
+//! # Length-prefixed encoding
+//!
+//! [Default encoding](crate::encoding#encoding-lists-into-bytes) is suffix-based: metadata is
+//! written after the value, so it can't be parsed until the whole input is received. This module
+//! provides an alternative encoding where metadata is written before the value, so the format
+//! permits parsing it incrementally, as the bytes arrive.
+//!
+//! Values are encoded with the same [encoders](crate::encoding) and [`Digestable`] trait, so any
+//! digestable value can be encoded in this mode. Lengths are only known once the value is encoded,
+//! so the value is encoded in default format into memory first, and then converted into
+//! length-prefixed form.
+//!
+//! Note that this module only defines the byte format. [`encode`] holds the whole encoding in
+//! memory, and [`decode`] needs the complete input. A streaming reader is not provided.
+//!
+//! ```text
+//! encoding ::= value END
+//!
+//! value    ::= leaf | leaf_ctx | list | list_ctx
+//!
+//! leaf     ::= LEAF len(bytestring) bytestring
+//! leaf_ctx ::= LEAF_CTX len(tag) tag len(bytestring) bytestring
+//!
+//! list     ::= LIST len([value]) [value]
+//! list_ctx ::= LIST_CTX len(ctx) ctx len([value]) [value]
+//!
+//! len(n) ::=
+//!   let len_n = n.len().to_be_bytes().strip();
+//!   (len_n.len() as u8) len_n
+//!
+//! LIST     ::= 33
+//! LIST_CTX ::= 34
+//! LEAF     ::= 35
+//! LEAF_CTX ::= 36
+//! END      ::= 37
+//! ```
+//!
+//! where `strip()` removes all leading zeroes. Encoding ends with `END` symbol which is never the
+//! last byte of [default](crate::encoding#encoding-lists-into-bytes) or
+//! [compact](crate::encoding#compact-format-v2) encoding, so the formats are domain-separated:
+//! length-prefixed encoding of a value never collides with default encoding of any value.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")] {
+//! #[derive(udigest::Digestable)]
+//! struct Person {
+//!     name: &'static str,
+//!     age: u32,
+//! }
+//! let alice = Person { name: "Alice", age: 24 };
+//!
+//! let bytes = udigest::prefixed::encode_to_vec(&alice);
+//! let decoded = udigest::prefixed::decode(&bytes)?;
+//! assert_eq!(decoded, udigest::decoding::decode(&udigest::encode_to_vec(&alice))?);
+//! # }
+//! # Ok::<_, udigest::decoding::DecodeError>(())
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::decoding::{DecodeError, Leaf, List, Value};
+use crate::{Buffer, Digestable};
+
+/// Control symbol
+///
+/// See [module level](self) docs
+pub const LIST: u8 = 33;
+/// Control symbol
+///
+/// See [module level](self) docs
+pub const LIST_CTX: u8 = 34;
+/// Control symbol
+///
+/// See [module level](self) docs
+pub const LEAF: u8 = 35;
+/// Control symbol
+///
+/// See [module level](self) docs
+pub const LEAF_CTX: u8 = 36;
+/// Control symbol
+///
+/// See [module level](self) docs
+pub const END: u8 = 37;
+
+/// Encodes a structured `value` in length-prefixed form and writes it to the `buffer`
+pub fn encode(value: &impl Digestable, buffer: &mut impl Buffer) {
+    let encoding = crate::encode_to_vec(value);
+    #[allow(clippy::expect_used)]
+    let value = crate::decoding::decode(&encoding).expect("encoders always produce valid encoding");
+
+    // Values are written in pre-order without recursion, so deeply nested values
+    // can't overflow the stack
+    let mut pending = Vec::new();
+    if let Some(items) = write_header(buffer, &value) {
+        pending.push(items.iter());
+    }
+    while let Some(items) = pending.last_mut() {
+        match items.next() {
+            Some(item) => {
+                if let Some(items) = write_header(buffer, item) {
+                    pending.push(items.iter());
+                }
+            }
+            None => {
+                pending.pop();
+            }
+        }
+    }
+    buffer.write(&[END]);
+}
+
+/// Encodes a structured `value` in length-prefixed form
+pub fn encode_to_vec(value: &impl Digestable) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    encode(value, &mut buffer);
+    buffer
+}
+
+/// Digests a structured `value` encoded in length-prefixed form using fixed-output hash
+/// function (like sha2-256)
+///
+/// Digest is different from [`hash`](crate::hash) of the same value.
+#[cfg(feature = "digest")]
+pub fn hash<D: digest::Digest>(value: &impl Digestable) -> digest::Output<D> {
+    let mut hash = crate::encoding::BufferDigest(D::new());
+    encode(value, &mut hash);
+    hash.0.finalize()
+}
+
+/// Writes metadata of the value. For leaves, also writes the bytestring. For lists, returns
+/// the items that need to be written next.
+fn write_header<'v, 'a>(buffer: &mut impl Buffer, value: &'v Value<'a>) -> Option<&'v [Value<'a>]> {
+    match value {
+        Value::Leaf(leaf) => {
+            if let Some(tag) = leaf.tag() {
+                buffer.write(&[LEAF_CTX]);
+                write_bytestring(buffer, tag);
+            } else {
+                buffer.write(&[LEAF]);
+            }
+            write_bytestring(buffer, leaf.bytes());
+            None
+        }
+        Value::List(list) => {
+            if let Some(tag) = list.tag() {
+                buffer.write(&[LIST_CTX]);
+                write_bytestring(buffer, tag);
+            } else {
+                buffer.write(&[LIST]);
+            }
+            write_len(buffer, list.items().len());
+            Some(list.items())
+        }
+    }
+}
+
+fn write_bytestring(buffer: &mut impl Buffer, bytes: &[u8]) {
+    write_len(buffer, bytes.len());
+    buffer.write(bytes);
+}
+
+fn write_len(buffer: &mut impl Buffer, len: usize) {
+    let len = len.to_be_bytes();
+    let leading_zeroes = len.iter().take_while(|b| **b == 0).count();
+    let len = &len[leading_zeroes..];
+
+    #[allow(clippy::expect_used)]
+    let len_of_len =
+        u8::try_from(len.len()).expect("it's impossible that usize is more than 256 bytes long");
+
+    buffer.write(&[len_of_len]);
+    buffer.write(len);
+}
+
+/// Decodes a single value encoded in length-prefixed form
+///
+/// Input is parsed from the beginning. The whole input must be available and must be consumed
+/// by the value, otherwise an error is returned.
+pub fn decode(bytes: &[u8]) -> Result<Value<'_>, DecodeError> {
+    // Lists are decoded without recursion, so deeply nested input can't overflow the stack
+    struct PendingList<'a> {
+        remaining: usize,
+        items: Vec<Value<'a>>,
+        tag: Option<&'a [u8]>,
+    }
+
+    let mut input = Input { bytes, offset: 0 };
+    let mut pending: Vec<PendingList> = Vec::new();
+
+    loop {
+        let offset = input.offset;
+        let mut value = match input.read_byte()? {
+            LEAF => Value::Leaf(Leaf::new(input.read_bytestring()?, None)),
+            LEAF_CTX => {
+                let tag = input.read_bytestring()?;
+                Value::Leaf(Leaf::new(input.read_bytestring()?, Some(tag)))
+            }
+            symbol @ (LIST | LIST_CTX) => {
+                let tag = if symbol == LIST_CTX {
+                    Some(input.read_bytestring()?)
+                } else {
+                    None
+                };
+                let len = input.read_len()?;
+                if len > 0 {
+                    pending.push(PendingList {
+                        remaining: len,
+                        // every item takes at least 2 bytes, so `len` can't be used to
+                        // preallocate memory as it's not validated yet
+                        items: Vec::new(),
+                        tag,
+                    });
+                    continue;
+                }
+                Value::List(List::new(Vec::new(), tag))
+            }
+            symbol => return Err(DecodeError::UnknownControlSymbol { offset, symbol }),
+        };
+
+        // Value is complete, we put it into the parent list. If it completes the parent
+        // list as well, we continue with the grandparent, and so on.
+        loop {
+            let Some(parent) = pending.last_mut() else {
+                let offset = input.offset;
+                match input.read_byte()? {
+                    END => {}
+                    symbol => return Err(DecodeError::UnknownControlSymbol { offset, symbol }),
+                }
+                if input.offset < input.bytes.len() {
+                    return Err(DecodeError::TrailingBytes {
+                        len: input.bytes.len() - input.offset,
+                    });
+                }
+                return Ok(value);
+            };
+            parent.items.push(value);
+            parent.remaining -= 1;
+            if parent.remaining > 0 {
+                break;
+            }
+
+            #[allow(clippy::expect_used)]
+            let list = pending.pop().expect("parent list is present");
+            value = Value::List(List::new(list.items, list.tag));
+        }
+    }
+}
+
+/// Input that is being consumed from the beginning
+struct Input<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Input<'a> {
+    /// Reads length followed by the bytestring of that length
+    fn read_bytestring(&mut self) -> Result<&'a [u8], DecodeError> {
+        let len = self.read_len()?;
+        self.read_bytes(len)
+    }
+
+    fn read_len(&mut self) -> Result<usize, DecodeError> {
+        let offset = self.offset;
+        let len_of_len = self.read_byte()?;
+        let len = self.read_bytes(len_of_len.into())?;
+        if len.first() == Some(&0) {
+            // leading zeroes must be stripped
+            return Err(DecodeError::NonCanonicalLength { offset });
+        }
+        if len.len() > core::mem::size_of::<usize>() {
+            return Err(DecodeError::LengthOverflow { offset });
+        }
+        Ok(len
+            .iter()
+            .fold(0usize, |acc, byte| acc << 8 | usize::from(*byte)))
+    }
+
+    fn read_byte(&mut self) -> Result<u8, DecodeError> {
+        let byte = *self
+            .bytes
+            .get(self.offset)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        let bytes = self
+            .bytes
+            .get(self.offset..end)
+            .ok_or(DecodeError::UnexpectedEnd)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{encoding, Buffer, Digestable};
+
+/// Size of chunks written to the hasher
+const CHUNK_SIZE: usize = 64;
+
+/// Feeds the encoding into [`core::hash::Hasher`]
+///
+/// Lets any [`Digestable`] value be hashed with non-cryptographic hashers used by `HashMap`,
+/// so values can be keyed by the same canonical identity that is hashed cryptographically
+/// by [`hash`](crate::hash). With `alloc` feature, `ByEncoding` wrapper implements
+/// [`Hash`](core::hash::Hash) this way.
+///
+/// Many hashers produce different results when the same bytes are written in different chunks.
+/// The adapter re-chunks the encoding, so the hasher receives the same sequence of writes
+/// for the same encoding, no matter how the encoding was produced. Remaining bytes are
+/// written to the hasher when the adapter is dropped.
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use std::hash::Hasher;
+///
+/// fn std_hash(value: &impl udigest::Digestable) -> u64 {
+///     let mut hasher = std::collections::hash_map::DefaultHasher::new();
+///     udigest::StdHashAdapter::new(&mut hasher).write_value(value);
+///     hasher.finish()
+/// }
+///
+/// // `Vec<u8>` and `[u8; N]` have the same encoding, thus the same hash
+/// assert_eq!(std_hash(&vec![1_u8, 2, 3]), std_hash(&[1_u8, 2, 3]));
+/// assert_ne!(std_hash(&vec![1_u8, 2, 3]), std_hash(&[1_u8, 2]));
+/// # }
+/// ```
+pub struct StdHashAdapter<'h, H: core::hash::Hasher + ?Sized> {
+    hasher: &'h mut H,
+    chunk: [u8; CHUNK_SIZE],
+    len: usize,
+}
+
+impl<'h, H: core::hash::Hasher + ?Sized> StdHashAdapter<'h, H> {
+    /// Wraps the hasher
+    pub fn new(hasher: &'h mut H) -> Self {
+        Self {
+            hasher,
+            chunk: [0; CHUNK_SIZE],
+            len: 0,
+        }
+    }
+
+    /// Writes encoding of the value to the hasher
+    pub fn write_value(&mut self, value: &impl Digestable) {
+        value.unambiguously_encode(encoding::EncodeValue::new(self))
+    }
+}
+
+impl<H: core::hash::Hasher + ?Sized> Buffer for StdHashAdapter<'_, H> {
+    fn write(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            let n = bytes.len().min(CHUNK_SIZE - self.len);
+            self.chunk[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+            self.len += n;
+            bytes = &bytes[n..];
+            if self.len == CHUNK_SIZE {
+                self.hasher.write(&self.chunk);
+                self.len = 0;
+            }
+        }
+    }
+}
+
+impl<H: core::hash::Hasher + ?Sized> Drop for StdHashAdapter<'_, H> {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            self.hasher.write(&self.chunk[..self.len]);
+        }
+    }
+}
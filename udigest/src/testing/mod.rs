@@ -0,0 +1,6 @@
+//! Utilities for testing implementations of the encoding
+//!
+//! * [`vectors`] generates test vectors that can be committed as golden files and used to check
+//!   that implementations in other languages produce the same encoding and digests
+
+pub mod vectors;
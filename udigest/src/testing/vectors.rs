@@ -0,0 +1,284 @@
+//! # Test vectors generation
+//!
+//! [`TestVector`] captures everything an implementation of the encoding in another language
+//! needs to be checked against: the bytes of the encoding, digests of the value computed with
+//! several hash functions, and the decoded tree of leaves and lists. Test vectors are exported
+//! as JSON, which can be committed as golden files.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "derive", feature = "digest"))] {
+//! #[derive(udigest::Digestable)]
+//! #[udigest(tag = "udigest.example.Person.v1")]
+//! struct Person {
+//!     name: &'static str,
+//!     age: u32,
+//! }
+//!
+//! let vectors = [
+//!     udigest::testing::vectors::TestVector::new("alice", &Person { name: "Alice", age: 24 })
+//!         .with_digest::<sha2::Sha256>("sha256")
+//!         .with_digest::<sha3::Sha3_256>("sha3-256"),
+//!     udigest::testing::vectors::TestVector::new("bob", &Person { name: "Bob", age: 25 })
+//!         .with_digest::<sha2::Sha256>("sha256")
+//!         .with_digest::<sha3::Sha3_256>("sha3-256"),
+//! ];
+//! let json = udigest::testing::vectors::to_json(&vectors);
+//! # let _ = json;
+//! // std::fs::write("tests/vectors/person.json", json)?;
+//! # }
+//! ```
+//!
+//! ## Format
+//!
+//! Each test vector is a JSON object:
+//!
+//! ```json
+//! {
+//!   "name": "alice",
+//!   "encoding": "...",
+//!   "digests": {
+//!     "sha256": "...",
+//!     "sha3-256": "..."
+//!   },
+//!   "tree": {"tag": "...", "list": [{"leaf": "..."}, ...]},
+//!   "inspect": "#\"udigest.example.Person.v1\" {name: \"Alice\", age: 0x...}"
+//! }
+//! ```
+//!
+//! * `encoding` is the [unambiguous encoding](crate::encoding) of the value in hex
+//! * `digests` maps the names of hash functions to the digests of the value in hex. Each
+//!   digest is computed as [`hash`](crate::hash) does, i.e. as the digest of `encoding`
+//! * `tree` is the [decoded](crate::decoding) encoding: leaves are objects with a `leaf` field
+//!   containing the bytestring in hex, lists are objects with a `list` field containing the array
+//!   of items. Domain separation tag, if any, is in the `tag` field encoded in hex.
+//! * `inspect` is the single-line human-readable rendering of the tree (see
+//!   [`decoding::inspect`](crate::decoding::inspect)). It's only given to make golden files easier
+//!   to review, and is not meant to be parsed
+//!
+//! [`to_json`] outputs a JSON array of test vectors.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::decoding::Value;
+use crate::Digestable;
+
+/// Test vector of a single value
+///
+/// See [module level](self) docs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestVector {
+    name: String,
+    encoding: Vec<u8>,
+    digests: Vec<(String, Vec<u8>)>,
+}
+
+impl TestVector {
+    /// Constructs a test vector of the `value`
+    ///
+    /// `name` identifies the test vector in the exported file. Test vector doesn't contain any
+    /// digests until they're added via [`with_digest`](Self::with_digest) or
+    /// [`with_digest_bytes`](Self::with_digest_bytes).
+    pub fn new(name: impl Into<String>, value: &impl Digestable) -> Self {
+        Self {
+            name: name.into(),
+            encoding: crate::encode_to_vec(value),
+            digests: Vec::new(),
+        }
+    }
+
+    /// Adds a digest of the value computed using hash function `D`
+    ///
+    /// `name` is the name of the hash function in the exported file, like `"sha256"`.
+    #[cfg(feature = "digest")]
+    pub fn with_digest<D: digest::Digest>(self, name: impl Into<String>) -> Self {
+        let digest = D::digest(&self.encoding);
+        self.with_digest_bytes(name, digest.to_vec())
+    }
+
+    /// Adds a digest of the value computed by the caller
+    ///
+    /// Useful for hash functions that aren't compatible with [`digest`] crate, or for
+    /// digests computed by [`hash_xof`](crate::hash_xof) and similar functions.
+    pub fn with_digest_bytes(mut self, name: impl Into<String>, digest: Vec<u8>) -> Self {
+        self.digests.push((name.into(), digest));
+        self
+    }
+
+    /// Returns the name of the test vector
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the unambiguous encoding of the value
+    pub fn encoding(&self) -> &[u8] {
+        &self.encoding
+    }
+
+    /// Returns the digests of the value, in the order they were added
+    pub fn digests(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.digests
+            .iter()
+            .map(|(name, digest)| (name.as_str(), digest.as_slice()))
+    }
+
+    /// Exports the test vector as a JSON object
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out, "");
+        out
+    }
+
+    fn write_json(&self, out: &mut String, indent: &str) {
+        out.push_str("{\n");
+        out.push_str(indent);
+        out.push_str("  \"name\": ");
+        write_string(out, &self.name);
+        out.push_str(",\n");
+
+        out.push_str(indent);
+        out.push_str("  \"encoding\": ");
+        write_hex(out, &self.encoding);
+        out.push_str(",\n");
+
+        out.push_str(indent);
+        out.push_str("  \"digests\": {");
+        for (i, (name, digest)) in self.digests.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push('\n');
+            out.push_str(indent);
+            out.push_str("    ");
+            write_string(out, name);
+            out.push_str(": ");
+            write_hex(out, digest);
+        }
+        if !self.digests.is_empty() {
+            out.push('\n');
+            out.push_str(indent);
+            out.push_str("  ");
+        }
+        out.push_str("},\n");
+
+        match crate::decoding::decode(&self.encoding) {
+            Ok(tree) => {
+                out.push_str(indent);
+                out.push_str("  \"tree\": ");
+                write_tree(out, &tree);
+                out.push_str(",\n");
+
+                out.push_str(indent);
+                out.push_str("  \"inspect\": ");
+                write_string(out, &alloc::format!("{tree}"));
+                out.push('\n');
+            }
+            // can only happen if `Digestable` impl produced a malformed encoding
+            Err(err) => {
+                out.push_str(indent);
+                out.push_str("  \"error\": ");
+                write_string(out, &alloc::format!("malformed encoding: {err}"));
+                out.push('\n');
+            }
+        }
+
+        out.push_str(indent);
+        out.push('}');
+    }
+}
+
+/// Exports a list of test vectors as a JSON array
+pub fn to_json(vectors: &[TestVector]) -> String {
+    let mut out = String::from("[");
+    for (i, vector) in vectors.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str("\n  ");
+        vector.write_json(&mut out, "  ");
+    }
+    if !vectors.is_empty() {
+        out.push('\n');
+    }
+    out.push_str("]\n");
+    out
+}
+
+fn write_tree(out: &mut String, value: &Value) {
+    // Values are written in pre-order without recursion, so deeply nested values
+    // can't overflow the stack
+    let mut pending = Vec::new();
+    if let Some(items) = write_tree_header(out, value) {
+        pending.push(items.iter().enumerate());
+    }
+    while let Some(items) = pending.last_mut() {
+        match items.next() {
+            Some((i, item)) => {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                if let Some(items) = write_tree_header(out, item) {
+                    pending.push(items.iter().enumerate());
+                }
+            }
+            None => {
+                out.push_str("]}");
+                pending.pop();
+            }
+        }
+    }
+}
+
+/// Writes the tag (if any) and opens the value. Leaves are written completely. For lists,
+/// returns the items that need to be written next, followed by `]}`.
+fn write_tree_header<'v, 'a>(out: &mut String, value: &'v Value<'a>) -> Option<&'v [Value<'a>]> {
+    out.push('{');
+    if let Some(tag) = value.tag() {
+        out.push_str("\"tag\": ");
+        write_hex(out, tag);
+        out.push_str(", ");
+    }
+    match value {
+        Value::Leaf(leaf) => {
+            out.push_str("\"leaf\": ");
+            write_hex(out, leaf.bytes());
+            out.push('}');
+            None
+        }
+        Value::List(list) => {
+            out.push_str("\"list\": [");
+            Some(list.items())
+        }
+    }
+}
+
+fn write_hex(out: &mut String, bytes: &[u8]) {
+    out.push('"');
+    for byte in bytes {
+        // writing to `String` never fails
+        let _ = write!(out, "{byte:02x}");
+    }
+    out.push('"');
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    let _ = write!(out, "\\u{unit:04x}");
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
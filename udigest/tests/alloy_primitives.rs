@@ -0,0 +1,55 @@
+use alloy_primitives::{Address, Bloom, B256, U256, U512};
+use udigest::Bytes;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn uint_is_encoded_as_primitive_integer() {
+    for x in [
+        0_u128,
+        1,
+        255,
+        256,
+        u64::MAX.into(),
+        u128::from(u64::MAX) + 1,
+        u128::MAX,
+    ] {
+        assert_eq!(
+            hex::encode(encode_to_vec(&U256::from(x))),
+            hex::encode(encode_to_vec(&x))
+        );
+        assert_eq!(
+            hex::encode(encode_to_vec(&U512::from(x))),
+            hex::encode(encode_to_vec(&x))
+        );
+    }
+
+    let max = U256::MAX;
+    assert_eq!(
+        hex::encode(encode_to_vec(&max)),
+        hex::encode(encode_to_vec(&Bytes(max.to_be_bytes::<32>())))
+    );
+}
+
+#[test]
+fn fixed_bytes() {
+    let hash = B256::repeat_byte(0xab);
+    assert_eq!(
+        hex::encode(encode_to_vec(&hash)),
+        hex::encode(encode_to_vec(&Bytes([0xab_u8; 32])))
+    );
+
+    let address = Address::repeat_byte(0x12);
+    assert_eq!(
+        hex::encode(encode_to_vec(&address)),
+        hex::encode(encode_to_vec(&Bytes([0x12_u8; 20])))
+    );
+
+    let bloom = Bloom::repeat_byte(0x01);
+    assert_eq!(
+        hex::encode(encode_to_vec(&bloom)),
+        hex::encode(encode_to_vec(&Bytes([0x01_u8; 256])))
+    );
+}
@@ -0,0 +1,40 @@
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy)]
+    pub struct Permissions: u16 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXECUTE = 0b100;
+    }
+}
+
+#[test]
+fn flags_are_digested_as_bits() {
+    #[derive(udigest::Digestable)]
+    struct File {
+        #[udigest(as = udigest::as_::Bits)]
+        permissions: Permissions,
+        #[udigest(as = Option<udigest::as_::Bits>)]
+        default_permissions: Option<Permissions>,
+    }
+
+    let file = File {
+        permissions: Permissions::READ | Permissions::WRITE,
+        default_permissions: Some(Permissions::READ),
+    };
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("permissions", |field| field.encode(&0b011_u16));
+    encoder.add_field_with("default_permissions", |field| {
+        field.encode(&Some(0b001_u16))
+    });
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&file)), hex::encode(buffer.0));
+}
@@ -0,0 +1,33 @@
+mod common;
+
+const VALUE: (&str, u32, [bool; 2]) = ("Alice", 24, [true, false]);
+
+#[test]
+fn hash() {
+    let encoding = common::encode_to_vec(&VALUE);
+    assert_eq!(udigest::hash_blake3(&VALUE), blake3::hash(&encoding));
+}
+
+#[test]
+fn hash_keyed() {
+    let key = [42u8; 32];
+    let encoding = common::encode_to_vec(&VALUE);
+    assert_eq!(
+        udigest::hash_blake3_keyed(&key, &VALUE),
+        blake3::keyed_hash(&key, &encoding)
+    );
+    assert_ne!(
+        udigest::hash_blake3_keyed(&[43u8; 32], &VALUE),
+        blake3::keyed_hash(&key, &encoding)
+    );
+}
+
+#[test]
+fn derive_key() {
+    const CONTEXT: &str = "udigest 2024-01-01 test context";
+    let encoding = common::encode_to_vec(&VALUE);
+    assert_eq!(
+        hex::encode(udigest::derive_key_blake3(CONTEXT, &VALUE)),
+        hex::encode(blake3::derive_key(CONTEXT, &encoding))
+    );
+}
@@ -0,0 +1,45 @@
+use bls12_381::{pairing, G1Affine, G2Affine, Gt, Scalar};
+use udigest::Bytes;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn group_elements_and_scalar() {
+    let g1 = G1Affine::generator();
+    let g2 = G2Affine::generator();
+    let scalar = Scalar::from(42);
+
+    assert_eq!(
+        hex::encode(encode_to_vec(&g1)),
+        hex::encode(encode_to_vec(&Bytes(g1.to_compressed())))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&g2)),
+        hex::encode(encode_to_vec(&Bytes(g2.to_compressed())))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&scalar)),
+        hex::encode(encode_to_vec(&Bytes(scalar.to_bytes())))
+    );
+}
+
+#[test]
+fn gt() {
+    let identity = Gt::identity();
+
+    // identity is `1`: all coefficients are zero except for the first one
+    let mut expected = [0_u8; 576];
+    expected[47] = 1;
+    assert_eq!(
+        hex::encode(encode_to_vec(&identity)),
+        hex::encode(encode_to_vec(&Bytes(expected)))
+    );
+
+    let a = pairing(&G1Affine::generator(), &G2Affine::generator());
+    let b = a + a;
+    assert_eq!(encode_to_vec(&a).len(), encode_to_vec(&identity).len());
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&b));
+    assert_eq!(encode_to_vec(&b), encode_to_vec(&a.double()));
+}
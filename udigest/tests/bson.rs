@@ -0,0 +1,53 @@
+use bson::{doc, oid::ObjectId, Bson};
+use udigest::encoding::EncodeValue;
+use udigest::Bytes;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn object_id() {
+    let id = ObjectId::from_bytes([7; 12]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&id)),
+        hex::encode(encode_to_vec(&Bytes([7_u8; 12])))
+    );
+}
+
+#[test]
+fn document_keys_are_sorted() {
+    let a = doc! { "b": 1, "a": "x", "c": [true, Bson::Null] };
+    let b = doc! { "c": [true, Bson::Null], "a": "x", "b": 1 };
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+
+    let c = doc! { "c": [Bson::Null, true], "a": "x", "b": 1 };
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&c));
+}
+
+#[test]
+fn bson_value() {
+    let value = Bson::Int32(5);
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer)
+        .encode_enum()
+        .with_variant("Int32");
+    encoder.add_field_with("0", |field| field.encode(&5_i32));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&value)), hex::encode(buffer.0));
+
+    // different types holding the same number have different encodings
+    assert_ne!(
+        encode_to_vec(&Bson::Int32(5)),
+        encode_to_vec(&Bson::Int64(5))
+    );
+    assert_ne!(
+        encode_to_vec(&Bson::Int64(5)),
+        encode_to_vec(&Bson::Double(5.0))
+    );
+}
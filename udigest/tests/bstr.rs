@@ -0,0 +1,25 @@
+use bstr::{BStr, BString};
+use udigest::Bytes;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn bstr_is_encoded_as_bytes() {
+    let bytes: &[u8] = b"not utf-8: \xff\xfe";
+    assert_eq!(
+        hex::encode(encode_to_vec(&BStr::new(bytes))),
+        hex::encode(encode_to_vec(&Bytes(bytes)))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&BString::from(bytes))),
+        hex::encode(encode_to_vec(&Bytes(bytes)))
+    );
+
+    // valid UTF-8 is encoded in the same way as `str`
+    assert_eq!(
+        hex::encode(encode_to_vec(&BStr::new("abc"))),
+        hex::encode(encode_to_vec(&"abc"))
+    );
+}
@@ -0,0 +1,18 @@
+use camino::{Utf8Path, Utf8PathBuf};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn paths_are_encoded_as_str() {
+    let path = "some/dir/file.txt";
+    assert_eq!(
+        hex::encode(encode_to_vec(&Utf8Path::new(path))),
+        hex::encode(encode_to_vec(&path))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&Utf8PathBuf::from(path))),
+        hex::encode(encode_to_vec(&path))
+    );
+}
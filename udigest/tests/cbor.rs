@@ -0,0 +1,83 @@
+#[test]
+fn export_leaves_and_lists() {
+    assert_eq!(udigest::cbor::encode_to_vec(&"ab"), [0x42, b'a', b'b']);
+    assert_eq!(
+        udigest::cbor::encode_to_vec(&vec!["a", ""]),
+        [0x82, 0x41, b'a', 0x40]
+    );
+    assert_eq!(udigest::cbor::encode_to_vec(&Vec::<&str>::new()), [0x80]);
+}
+
+#[test]
+fn export_tagged_values() {
+    #[derive(udigest::Digestable)]
+    #[udigest(tag = "t")]
+    struct Unit {}
+
+    assert_eq!(
+        udigest::cbor::encode_to_vec(&Unit {}),
+        [0xa1, 0x41, b't', 0x80]
+    );
+}
+
+#[test]
+fn lengths_in_shortest_form() {
+    let cases: &[(usize, &[u8])] = &[
+        (23, &[0x57]),
+        (24, &[0x58, 24]),
+        (255, &[0x58, 0xff]),
+        (256, &[0x59, 0x01, 0x00]),
+        (65535, &[0x59, 0xff, 0xff]),
+        (65536, &[0x5a, 0x00, 0x01, 0x00, 0x00]),
+    ];
+    for (len, head) in cases {
+        let bytes = udigest::Bytes(vec![0u8; *len]);
+        let cbor = udigest::cbor::encode_to_vec(&bytes);
+        assert_eq!(&cbor[..head.len()], *head, "len = {len}");
+        assert_eq!(cbor.len(), head.len() + len);
+    }
+}
+
+#[test]
+fn export_decoded_value() {
+    let value = ("a", vec!["b", "c"]);
+    let encoding = udigest::encode_to_vec(&value);
+    let decoded = udigest::decoding::decode(&encoding).unwrap();
+
+    let mut cbor = Vec::new();
+    udigest::cbor::encode_decoded(&decoded, &mut cbor);
+    assert_eq!(cbor, udigest::cbor::encode_to_vec(&value));
+}
+
+#[cfg(feature = "ciborium")]
+#[test]
+fn parsed_by_ciborium() {
+    use ciborium::Value;
+
+    #[derive(udigest::Digestable)]
+    #[udigest(tag = "person")]
+    struct Person {
+        name: &'static str,
+        skills: Vec<&'static str>,
+    }
+
+    let cbor = udigest::cbor::encode_to_vec(&Person {
+        name: "Alice",
+        skills: vec!["math"],
+    });
+    let parsed: Value = ciborium::from_reader(cbor.as_slice()).unwrap();
+
+    let bytes = |b: &[u8]| Value::Bytes(b.to_vec());
+    assert_eq!(
+        parsed,
+        Value::Map(vec![(
+            bytes(b"person"),
+            Value::Array(vec![
+                bytes(b"name"),
+                bytes(b"Alice"),
+                bytes(b"skills"),
+                Value::Array(vec![bytes(b"math")]),
+            ]),
+        )])
+    );
+}
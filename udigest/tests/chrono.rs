@@ -0,0 +1,70 @@
+use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
+use udigest::encoding::{EncodeStruct, EncodeValue};
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+fn encode_struct(f: impl FnOnce(&mut EncodeStruct<VecBuf>)) -> Vec<u8> {
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    f(&mut encoder);
+    encoder.finish();
+    buffer.0
+}
+
+#[test]
+fn datetime() {
+    let utc = Utc.with_ymd_and_hms(2024, 5, 17, 12, 30, 0).unwrap() + TimeDelta::nanoseconds(123);
+    let expected = encode_struct(|s| {
+        s.add_field_with("secs", |field| field.encode(&1715949000_i64));
+        s.add_field_with("nanos", |field| field.encode(&123_u32));
+        s.add_field_with("offset", |field| field.encode(&0_i32));
+    });
+    assert_eq!(hex::encode(encode_to_vec(&utc)), hex::encode(&expected));
+    assert_eq!(encode_to_vec(&utc.fixed_offset()), expected);
+
+    let moscow = utc.with_timezone(&FixedOffset::east_opt(3 * 3600).unwrap());
+    let expected = encode_struct(|s| {
+        s.add_field_with("secs", |field| field.encode(&1715949000_i64));
+        s.add_field_with("nanos", |field| field.encode(&123_u32));
+        s.add_field_with("offset", |field| field.encode(&10800_i32));
+    });
+    assert_eq!(hex::encode(encode_to_vec(&moscow)), hex::encode(expected));
+
+    let naive = utc.naive_utc();
+    let expected = encode_struct(|s| {
+        s.add_field_with("secs", |field| field.encode(&1715949000_i64));
+        s.add_field_with("nanos", |field| field.encode(&123_u32));
+    });
+    assert_eq!(hex::encode(encode_to_vec(&naive)), hex::encode(expected));
+}
+
+#[test]
+fn date_and_time() {
+    let date = NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+    let expected = encode_struct(|s| {
+        s.add_field_with("year", |field| field.encode(&2024_i32));
+        s.add_field_with("month", |field| field.encode(&5_u32));
+        s.add_field_with("day", |field| field.encode(&17_u32));
+    });
+    assert_eq!(hex::encode(encode_to_vec(&date)), hex::encode(expected));
+
+    // leap second
+    let time = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+    let expected = encode_struct(|s| {
+        s.add_field_with("secs", |field| field.encode(&86399_u32));
+        s.add_field_with("nanos", |field| field.encode(&1_500_000_000_u32));
+    });
+    assert_eq!(hex::encode(encode_to_vec(&time)), hex::encode(expected));
+}
+
+#[test]
+fn time_delta() {
+    let delta = -TimeDelta::milliseconds(1500);
+    let expected = encode_struct(|s| {
+        s.add_field_with("secs", |field| field.encode(&(-1_i64)));
+        s.add_field_with("nanos", |field| field.encode(&(-500_000_000_i32)));
+    });
+    assert_eq!(hex::encode(encode_to_vec(&delta)), hex::encode(expected));
+}
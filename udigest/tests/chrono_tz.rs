@@ -0,0 +1,47 @@
+use chrono::{Offset, TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
+use udigest::encoding::{EncodeStruct, EncodeValue};
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+fn encode_struct(f: impl FnOnce(&mut EncodeStruct<VecBuf>)) -> Vec<u8> {
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    f(&mut encoder);
+    encoder.finish();
+    buffer.0
+}
+
+#[test]
+fn time_zone() {
+    assert_eq!(
+        hex::encode(encode_to_vec(&Tz::Europe__London)),
+        hex::encode(encode_to_vec(&"Europe/London"))
+    );
+
+    let parsed: Tz = "America/New_York".parse().unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&parsed)),
+        hex::encode(encode_to_vec(&"America/New_York"))
+    );
+}
+
+#[test]
+fn datetime() {
+    let utc = Utc.with_ymd_and_hms(2024, 5, 17, 12, 30, 0).unwrap() + TimeDelta::nanoseconds(123);
+
+    let london = utc.with_timezone(&Tz::Europe__London);
+    let expected = encode_struct(|s| {
+        s.add_field_with("secs", |field| field.encode(&1715949000_i64));
+        s.add_field_with("nanos", |field| field.encode(&123_u32));
+        s.add_field_with("tz", |field| field.encode(&"Europe/London"));
+    });
+    assert_eq!(hex::encode(encode_to_vec(&london)), hex::encode(expected));
+
+    // zones having the same offset at this moment are still distinguished
+    let lisbon = utc.with_timezone(&Tz::Europe__Lisbon);
+    assert_eq!(london.offset().fix(), lisbon.offset().fix());
+    assert_ne!(encode_to_vec(&london), encode_to_vec(&lisbon));
+}
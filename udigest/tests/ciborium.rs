@@ -0,0 +1,59 @@
+use ciborium::{cbor, Value};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn map_keys_are_sorted() {
+    let a = cbor!({ "b" => 1, 1 => "x", "c" => [true, null] }).unwrap();
+    let b = cbor!({ "c" => [true, null], 1 => "x", "b" => 1 }).unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+
+    let c = cbor!({ "c" => [null, true], 1 => "x", "b" => 1 }).unwrap();
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&c));
+}
+
+#[test]
+fn cbor_value() {
+    let value = Value::Text("abc".into());
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer)
+        .encode_enum()
+        .with_variant("Text");
+    encoder.add_field_with("0", |field| field.encode(&"abc"));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&value)), hex::encode(buffer.0));
+
+    // text and bytes holding the same data have different encodings
+    assert_ne!(
+        encode_to_vec(&Value::Text("abc".into())),
+        encode_to_vec(&Value::Bytes(b"abc".to_vec()))
+    );
+}
+
+#[test]
+fn integers() {
+    let value = Value::Integer(5_u64.into());
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer)
+        .encode_enum()
+        .with_variant("Integer");
+    encoder.add_field_with("0", |field| field.encode(&5_i128));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&value)), hex::encode(buffer.0));
+
+    // tagged value differs from untagged one
+    assert_ne!(
+        encode_to_vec(&Value::Tag(2, Box::new(value.clone()))),
+        encode_to_vec(&value)
+    );
+}
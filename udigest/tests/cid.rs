@@ -0,0 +1,44 @@
+use cid::multihash::Multihash;
+use cid::Cid;
+use udigest::Bytes;
+
+use common::encode_to_vec;
+
+mod common;
+
+const SHA2_256: u64 = 0x12;
+const RAW: u64 = 0x55;
+
+#[test]
+fn multihash_is_encoded_in_binary_form() {
+    let hash = Multihash::<64>::wrap(SHA2_256, &[0xaa; 32]).unwrap();
+
+    let mut expected = vec![0x12, 0x20];
+    expected.extend_from_slice(&[0xaa; 32]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&hash)),
+        hex::encode(encode_to_vec(&Bytes(expected)))
+    );
+}
+
+#[test]
+fn cid_is_encoded_in_binary_form() {
+    let hash = Multihash::<64>::wrap(SHA2_256, &[0xaa; 32]).unwrap();
+
+    let v0 = Cid::new_v0(hash).unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&v0)),
+        hex::encode(encode_to_vec(&hash))
+    );
+
+    let v1 = Cid::new_v1(RAW, hash);
+    assert_eq!(
+        hex::encode(encode_to_vec(&v1)),
+        hex::encode(encode_to_vec(&Bytes(v1.to_bytes())))
+    );
+    assert_ne!(encode_to_vec(&v0), encode_to_vec(&v1));
+
+    // parsing from string doesn't affect the encoding
+    let parsed: Cid = v1.to_string().parse().unwrap();
+    assert_eq!(encode_to_vec(&parsed), encode_to_vec(&v1));
+}
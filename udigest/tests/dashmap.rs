@@ -0,0 +1,76 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn dash_map_and_set() {
+    #[derive(udigest::Digestable)]
+    struct Attributes {
+        #[udigest(as = BTreeMap<_, udigest::Bytes>)]
+        map: dashmap::DashMap<String, Vec<u8>>,
+        #[udigest(as = BTreeSet<_>)]
+        set: dashmap::DashSet<u32>,
+    }
+
+    impl Attributes {
+        fn digest_expected(&self) -> impl udigest::Digestable {
+            udigest::inline_struct!({
+                map: self
+                    .map
+                    .iter()
+                    .map(|entry| (entry.key().clone(), udigest::Bytes(entry.value().clone())))
+                    .collect::<BTreeMap<_, _>>(),
+                set: self.set.iter().map(|entry| *entry).collect::<BTreeSet<_>>(),
+            })
+        }
+    }
+
+    let attrs = Attributes {
+        map: FromIterator::from_iter([
+            ("some_attr".to_string(), b"value1".to_vec()),
+            ("attr".to_string(), b"value2".to_vec()),
+            ("some_other_attr".to_string(), b"value3".to_vec()),
+        ]),
+        set: FromIterator::from_iter([5, 1, 100, 42]),
+    };
+
+    let expected = encode_to_vec(&attrs.digest_expected());
+    let actual = encode_to_vec(&attrs);
+
+    assert_eq!(hex::encode(expected), hex::encode(actual));
+}
+
+#[test]
+fn concurrent_insertions() {
+    use udigest::decoding::Value;
+
+    // Entries are inserted in order, so the set always contains `0..len`. A snapshot that
+    // isn't taken at a single point in time could contain a gap.
+    let set = dashmap::DashSet::<u32>::new();
+    let done = std::sync::atomic::AtomicBool::new(false);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            for i in 0..20_000 {
+                set.insert(i);
+            }
+            done.store(true, std::sync::atomic::Ordering::Relaxed);
+        });
+
+        while !done.load(std::sync::atomic::Ordering::Relaxed) {
+            let encoding = encode_to_vec(
+                &udigest::as_::As::<_, &BTreeSet<udigest::as_::Same>>::new(&set),
+            );
+            let Value::List(list) = udigest::decoding::decode(&encoding).unwrap() else {
+                panic!("set must be encoded as a list")
+            };
+            let Some(last) = list.items().last() else {
+                continue;
+            };
+            // set is sorted, so `last` is the largest element
+            let expected = encode_to_vec(&(list.items().len() as u32 - 1));
+            assert_eq!(*last, udigest::decoding::decode(&expected).unwrap());
+        }
+    });
+}
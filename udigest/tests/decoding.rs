@@ -0,0 +1,183 @@
+use udigest::decoding::{decode, DecodeError, Value};
+use udigest::encoding::*;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+fn leaf_bytes<'a>(value: &Value<'a>) -> &'a [u8] {
+    value.as_leaf().unwrap().bytes()
+}
+
+#[test]
+fn decode_struct() {
+    #[derive(udigest::Digestable)]
+    #[udigest(tag = "udigest.example.Person.v1")]
+    struct Person {
+        name: &'static str,
+        skills: Vec<&'static str>,
+    }
+
+    let bytes = encode_to_vec(&Person {
+        name: "Alice",
+        skills: vec!["math", "crypto"],
+    });
+    let decoded = decode(&bytes).unwrap();
+
+    assert_eq!(decoded.tag(), Some(&b"udigest.example.Person.v1"[..]));
+    let fields = decoded.as_list().unwrap().items();
+    assert_eq!(fields.len(), 4);
+    assert_eq!(leaf_bytes(&fields[0]), b"name");
+    assert_eq!(leaf_bytes(&fields[1]), b"Alice");
+    assert_eq!(leaf_bytes(&fields[2]), b"skills");
+
+    let skills = fields[3].as_list().unwrap();
+    assert_eq!(skills.tag(), None);
+    assert_eq!(skills.items().len(), 2);
+    assert_eq!(leaf_bytes(&skills.items()[0]), b"math");
+    assert_eq!(leaf_bytes(&skills.items()[1]), b"crypto");
+}
+
+#[test]
+fn decode_leaves_and_empty_lists() {
+    let mut buffer = VecBuf(vec![]);
+    let mut list = EncodeList::new(&mut buffer);
+    list.add_leaf().with_tag(b"tag").chain(b"value");
+    list.add_leaf();
+    list.add_list();
+    list.add_list().with_tag(b"empty");
+    list.finish();
+
+    let decoded = decode(&buffer.0).unwrap();
+    let items = decoded.as_list().unwrap().items();
+    assert_eq!(items.len(), 4);
+
+    assert_eq!(leaf_bytes(&items[0]), b"value");
+    assert_eq!(items[0].tag(), Some(&b"tag"[..]));
+    assert_eq!(leaf_bytes(&items[1]), b"");
+    assert_eq!(items[1].tag(), None);
+    assert!(items[2].as_list().unwrap().items().is_empty());
+    assert_eq!(items[3].tag(), Some(&b"empty"[..]));
+    assert!(items[3].as_list().unwrap().items().is_empty());
+}
+
+#[test]
+fn deeply_nested_lists() {
+    let depth = 100_000;
+    let mut bytes = vec![];
+    // the innermost list is empty, every other list contains a single item
+    bytes.extend_from_slice(&0_u32.to_be_bytes());
+    bytes.extend_from_slice(&[LEN_32, LIST]);
+    for _ in 1..depth {
+        bytes.extend_from_slice(&1_u32.to_be_bytes());
+        bytes.extend_from_slice(&[LEN_32, LIST]);
+    }
+
+    let mut value = &decode(&bytes).unwrap();
+    let mut actual_depth = 1;
+    while let [item] = value.as_list().unwrap().items() {
+        value = item;
+        actual_depth += 1;
+    }
+    assert_eq!(actual_depth, depth);
+}
+
+#[test]
+fn malformed_input() {
+    let valid = encode_to_vec(&("abc", 5_u32));
+    assert!(decode(&valid).is_ok());
+
+    assert_eq!(decode(&[]), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(
+        decode(&valid[1..]),
+        Err(DecodeError::UnexpectedEnd),
+        "truncated input"
+    );
+
+    let mut with_prefix = b"xyz".to_vec();
+    with_prefix.extend_from_slice(&valid);
+    assert_eq!(
+        decode(&with_prefix),
+        Err(DecodeError::UnexpectedBytes { len: 3 })
+    );
+
+    let mut unknown_symbol = valid.clone();
+    *unknown_symbol.last_mut().unwrap() = 0xff;
+    assert_eq!(
+        decode(&unknown_symbol),
+        Err(DecodeError::UnknownControlSymbol {
+            offset: valid.len() - 1,
+            symbol: 0xff
+        })
+    );
+
+    // length `3` encoded as BIGLEN instead of LEN_32
+    let non_canonical = [b'a', b'b', b'c', 3, 1, BIGLEN, LEAF];
+    assert_eq!(
+        decode(&non_canonical),
+        Err(DecodeError::NonCanonicalLength { offset: 5 })
+    );
+}
+
+#[test]
+fn display_decoded_value() {
+    #[derive(udigest::Digestable)]
+    #[udigest(tag = "udigest.example")]
+    enum Shape {
+        Circle { radius: u8 },
+        Polygon { points: Vec<(u8, u8)> },
+    }
+
+    let circle = encode_to_vec(&Shape::Circle { radius: 5 });
+    let circle = decode(&circle).unwrap();
+    assert_eq!(
+        circle.to_string(),
+        r#"#"udigest.example" {variant: "Circle", radius: 0x05}"#
+    );
+
+    let polygon = encode_to_vec(&Shape::Polygon {
+        points: vec![(0x01, 0x02), (0x03, 0x04)],
+    });
+    let polygon = decode(&polygon).unwrap();
+    assert_eq!(
+        format!("{polygon:#}"),
+        r#"#"udigest.example" {
+    variant: "Polygon",
+    points: [
+        [
+            0x01,
+            0x02,
+        ],
+        [
+            0x03,
+            0x04,
+        ],
+    ],
+}"#
+    );
+}
+
+#[test]
+fn inspect() {
+    #[derive(udigest::Digestable)]
+    struct Person {
+        name: &'static str,
+        skills: Vec<&'static str>,
+        #[udigest(as_bytes)]
+        key: [u8; 2],
+    }
+
+    let alice = Person {
+        name: "Alice",
+        skills: vec![],
+        key: [0xab, 0xcd],
+    };
+    assert_eq!(
+        udigest::decoding::inspect(&alice),
+        r#"{
+    name: "Alice",
+    skills: [],
+    key: 0xabcd,
+}"#
+    );
+}
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+mod common;
+
 #[derive(udigest::Digestable)]
 #[udigest(tag = concat!("udigest.example", ".v1"))]
 pub struct DigestableExample {
@@ -112,3 +114,319 @@ pub struct OptionalBytes {
     #[udigest(as = std::collections::BTreeMap<_, udigest::Bytes>)]
     hash_map: std::collections::HashMap<String, Vec<u8>>,
 }
+
+#[derive(udigest::Digestable)]
+pub struct ChunkedBytes {
+    #[udigest(as_bytes_iter = <[_]>::iter)]
+    body: Vec<Vec<u8>>,
+    #[udigest(as_bytes_iter = lines_as_bytes)]
+    lines: Vec<String>,
+}
+
+fn lines_as_bytes(lines: &[String]) -> impl Iterator<Item = &[u8]> {
+    lines.iter().map(String::as_bytes)
+}
+
+#[test]
+fn as_bytes_iter_is_same_as_concatenated_bytes() {
+    #[derive(udigest::Digestable)]
+    struct Chunked {
+        #[udigest(as_bytes_iter = <[_]>::iter)]
+        body: Vec<Vec<u8>>,
+    }
+    #[derive(udigest::Digestable)]
+    struct Contiguous {
+        #[udigest(as_bytes)]
+        body: Vec<u8>,
+    }
+
+    let chunked = Chunked {
+        body: vec![
+            b"Hello".to_vec(),
+            vec![],
+            b", ".to_vec(),
+            b"world!".to_vec(),
+        ],
+    };
+    let contiguous = Contiguous {
+        body: b"Hello, world!".to_vec(),
+    };
+
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&chunked)),
+        hex::encode(common::encode_to_vec(&contiguous)),
+    );
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(with = encode_container_with)]
+pub struct ContainerWith {
+    foo: Bar,
+}
+
+fn encode_container_with<B: udigest::Buffer>(
+    value: &ContainerWith,
+    encoder: udigest::encoding::EncodeValue<B>,
+) {
+    encoding::encode_bar(&value.foo, encoder)
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(with = encode_union)]
+pub union UnionWith {
+    int: u32,
+    bytes: [u8; 4],
+}
+
+fn encode_union<B: udigest::Buffer>(value: &UnionWith, encoder: udigest::encoding::EncodeValue<B>) {
+    encoder.encode_leaf_value(unsafe { value.bytes })
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(as_bytes = UnionAsBytes::to_bytes)]
+#[udigest(tag = "udigest.example.v1")]
+pub union UnionAsBytes {
+    int: u32,
+    bytes: [u8; 4],
+}
+
+impl UnionAsBytes {
+    fn to_bytes(&self) -> [u8; 4] {
+        unsafe { self.bytes }
+    }
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(as_bytes)]
+pub struct ContainerAsBytes(Vec<u8>);
+
+impl AsRef<[u8]> for ContainerAsBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[test]
+fn union_as_bytes() {
+    let union = UnionAsBytes {
+        int: u32::from_ne_bytes(*b"abcd"),
+    };
+
+    let mut expected = common::VecBuf(vec![]);
+    udigest::encoding::EncodeLeaf::new(&mut expected)
+        .with_tag(b"udigest.example.v1")
+        .chain(b"abcd");
+
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&union)),
+        hex::encode(expected.0),
+    );
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(tag = "udigest.example.v1")]
+pub enum VariantWith {
+    Regular {
+        int: u32,
+    },
+    #[udigest(with = encode_legacy_variant)]
+    Legacy(u32, Bar),
+    #[udigest(with = encode_unit_variant)]
+    Unit,
+}
+
+fn encode_legacy_variant<B: udigest::Buffer>(
+    int: &u32,
+    _bar: &Bar,
+    mut encoder: udigest::encoding::EncodeStruct<B>,
+) {
+    encoder.add_leaf_field("legacy_int", int.to_be_bytes());
+}
+
+fn encode_unit_variant<B: udigest::Buffer>(encoder: udigest::encoding::EncodeStruct<B>) {
+    encoder.finish()
+}
+
+#[test]
+fn variant_with() {
+    let mut expected = common::VecBuf(vec![]);
+    let mut encoder = udigest::encoding::EncodeEnum::new(&mut expected)
+        .with_tag(b"udigest.example.v1")
+        .with_variant("Legacy");
+    encoder.add_leaf_field("legacy_int", 5_u32.to_be_bytes());
+    encoder.finish();
+
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&VariantWith::Legacy(5, Bar))),
+        hex::encode(expected.0),
+    );
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(untagged)]
+pub enum Untagged {
+    Newtype(#[udigest(as_bytes)] Vec<u8>),
+    Tuple(u32, String),
+    Struct {
+        int: u32,
+    },
+    Unit,
+    #[udigest(with = encode_unit_variant)]
+    With,
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(untagged)]
+pub enum EmptyUntagged {}
+
+#[test]
+fn untagged_enum() {
+    #[derive(udigest::Digestable)]
+    struct Tuple(u32, String);
+    #[derive(udigest::Digestable)]
+    struct Struct {
+        int: u32,
+    }
+    #[derive(udigest::Digestable)]
+    struct Unit;
+
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&Untagged::Newtype(b"abc".to_vec()))),
+        hex::encode(common::encode_to_vec(&udigest::Bytes(b"abc"))),
+    );
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&Untagged::Tuple(1, "a".into()))),
+        hex::encode(common::encode_to_vec(&Tuple(1, "a".into()))),
+    );
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&Untagged::Struct { int: 1 })),
+        hex::encode(common::encode_to_vec(&Struct { int: 1 })),
+    );
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&Untagged::Unit)),
+        hex::encode(common::encode_to_vec(&Unit)),
+    );
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(tag = "udigest.example.v1", bound = "")]
+pub struct CommaSeparatedAttrs<T> {
+    #[udigest(rename = "bytes", as_bytes)]
+    field1: Vec<u8>,
+    #[udigest(skip)]
+    field2: std::marker::PhantomData<T>,
+}
+
+#[derive(udigest::Digestable)]
+#[udigest(rename_fields = ["x", "y", "z"])]
+pub struct RenameFields(u32, #[udigest(as_bytes)] Vec<u8>, #[udigest(skip)] Empty);
+
+#[test]
+fn rename_fields() {
+    #[derive(udigest::Digestable)]
+    struct Named {
+        x: u32,
+        #[udigest(as_bytes)]
+        y: Vec<u8>,
+    }
+
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&RenameFields(
+            1,
+            b"abc".to_vec(),
+            Empty
+        ))),
+        hex::encode(common::encode_to_vec(&Named {
+            x: 1,
+            y: b"abc".to_vec()
+        })),
+    );
+}
+
+#[test]
+fn compat_v0_2() {
+    #[derive(udigest::Digestable)]
+    #[udigest(compat = "0.2", tag = "udigest.compat.v0_2")]
+    struct Record {
+        name: String,
+        #[udigest(rename = "years")]
+        age: u32,
+        #[udigest(as_bytes)]
+        key: Vec<u8>,
+        #[udigest(skip)]
+        #[allow(dead_code)]
+        cache: u8,
+        shapes: Vec<Shape>,
+        nickname: Option<&'static str>,
+    }
+
+    #[derive(udigest::Digestable)]
+    #[udigest(compat = "0.2", tag = "udigest.compat.Shape.v0_2")]
+    enum Shape {
+        Circle { radius: u8 },
+        Square(u16),
+        Empty,
+    }
+
+    let record = Record {
+        name: "Alice".into(),
+        age: 24,
+        key: vec![1, 2, 3],
+        cache: 7,
+        shapes: vec![
+            Shape::Circle { radius: 5 },
+            Shape::Square(300),
+            Shape::Empty,
+        ],
+        nickname: Some("al"),
+    };
+    // Same types were digested using udigest v0.2.2 with udigest-derive v0.3.1
+    assert_eq!(
+        hex::encode(udigest::hash::<sha2::Sha256>(&record)),
+        "14e7cb56fdc8ad39ad84ada64ee3f3afcb102ed0cb74a227acf8345a00edd2ee",
+    );
+}
+
+#[derive(Clone, udigest::Digestable)]
+#[udigest(from = u64)]
+pub struct Seconds(u64);
+
+impl From<Seconds> for u64 {
+    fn from(s: Seconds) -> u64 {
+        s.0
+    }
+}
+
+#[derive(udigest::Digestable)]
+pub enum FieldsFrom {
+    Variant {
+        #[udigest(from = u32)]
+        port: std::num::NonZeroU32,
+        #[udigest(from = String)]
+        name: &'static str,
+    },
+}
+
+#[test]
+fn from() {
+    #[derive(udigest::Digestable)]
+    struct Timeout {
+        #[udigest(from = u64)]
+        timeout: Seconds,
+    }
+    #[derive(udigest::Digestable)]
+    struct Expected {
+        timeout: u64,
+    }
+
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&Timeout {
+            timeout: Seconds(5)
+        })),
+        hex::encode(common::encode_to_vec(&Expected { timeout: 5 })),
+    );
+    assert_eq!(
+        hex::encode(common::encode_to_vec(&Seconds(5))),
+        hex::encode(common::encode_to_vec(&5_u64)),
+    );
+}
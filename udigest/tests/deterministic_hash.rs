@@ -1,16 +1,53 @@
 #[derive(udigest::Digestable)]
+#[udigest(test_vector(
+    value = "ALICE",
+    expected = "49c43095eaffb3e3232dd23940686d3c6fb80e5ff82b5a09d336ad32369ca9df",
+))]
+#[udigest(test_vector(
+    value = "Self::bob()",
+    expected = "3537c188336cb93f58df79149fb035fd132f23fac58a6e94d014178aeaa1c88e",
+))]
+#[udigest(test_vector(
+    value = "ALICE",
+    hash = sha3::Sha3_256,
+    expected = "ca1a844ce85ce3f5c98e3eda34ef904761c8acef54d07614e5e424fd861b2018",
+))]
+#[udigest(test_vector(
+    value = "ALICE",
+    hash = sha2::Sha512,
+    expected = "002c2ebf030a5e5bb7660e1eb2317766d46337a210098143a2e12ae73ba91160\
+                8a57b8c3d0ede6eab54e550ba272da0f5457271a2d4f3b88f33afb53e9f7108f",
+))]
 pub struct Person {
     name: &'static str,
     age: u16,
     job_title: &'static str,
 }
 
+mod deprecated_sha256_key {
+    #![allow(deprecated)]
+
+    // `sha256` is a deprecated alias to `expected`
+    #[derive(udigest::Digestable)]
+    #[udigest(test_vector(
+        value = "Self(super::ALICE.name)",
+        sha256 = "55f58979463e445ba3e1c2f86608c2c3b879b65bde05adc9cf3f4aa1ce104ec1",
+    ))]
+    pub struct Name(pub &'static str);
+}
+
 const ALICE: Person = Person {
     name: "Alice",
     age: 24,
     job_title: "cryptographer",
 };
 
+impl Person {
+    const fn bob() -> Self {
+        BOB
+    }
+}
+
 const BOB: Person = Person {
     name: "Bob",
     age: 25,
@@ -56,6 +93,12 @@ fn shake256() {
         cb010249754a0c7f263d14c7a284da134ca133df84c62d80adfdb0ec0d5c3f0a\
         50e479dd025b27fb875c34ba72d9abc7a5990ce8c7f3c282dd6a0c"
     );
+
+    let alice_hash = udigest::hash_xof_n::<sha3::Shake256, 32>(&ALICE);
+    assert_eq!(
+        hex::encode(alice_hash),
+        "ee629bcc426422887fe6f9a9a3384128bd5efc3c623a4599c8526c24a97972be"
+    );
 }
 
 #[test]
@@ -130,3 +130,54 @@ fn array() {
 
     assert_eq!(hex::encode(expected), hex::encode(actual));
 }
+
+#[test]
+fn binary_heap() {
+    #[derive(udigest::Digestable)]
+    struct Queue {
+        #[udigest(as = Vec<_>)]
+        tasks: std::collections::BinaryHeap<u32>,
+    }
+
+    impl Queue {
+        fn digest_expected(&self) -> impl udigest::Digestable + '_ {
+            udigest::inline_struct!({
+                tasks: self.tasks.clone().into_sorted_vec(),
+            })
+        }
+    }
+
+    let queue = Queue {
+        tasks: FromIterator::from_iter([5, 1, 8, 3, 1, 9]),
+    };
+
+    let expected = common::encode_to_vec(&queue.digest_expected());
+    let actual = common::encode_to_vec(&queue);
+
+    assert_eq!(hex::encode(expected), hex::encode(actual));
+}
+
+#[test]
+fn display_error() {
+    struct Broken;
+    impl std::fmt::Display for Broken {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("partial")?;
+            Err(std::fmt::Error)
+        }
+    }
+
+    let mut buffer = common::VecBuf(vec![]);
+    <udigest::as_::Display as udigest::DigestAs<Broken>>::digest_as(
+        &Broken,
+        udigest::encoding::EncodeValue::new(&mut buffer),
+    );
+    // the error never collides with a successfully formatted string
+    let mut expected = common::VecBuf(vec![]);
+    udigest::encoding::EncodeValue::new(&mut expected)
+        .encode_leaf()
+        .with_tag(b"udigest.display_error")
+        .chain("partial");
+
+    assert_eq!(hex::encode(expected.0), hex::encode(buffer.0));
+}
@@ -0,0 +1,22 @@
+use ed25519_dalek::{Signer, SigningKey};
+use udigest::Bytes;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn verifying_key_and_signature() {
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    let verifying_key = signing_key.verifying_key();
+    let signature = signing_key.sign(b"message");
+
+    assert_eq!(
+        hex::encode(encode_to_vec(&verifying_key)),
+        hex::encode(encode_to_vec(&Bytes(verifying_key.to_bytes())))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&signature)),
+        hex::encode(encode_to_vec(&Bytes(signature.to_bytes())))
+    );
+}
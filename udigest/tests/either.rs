@@ -0,0 +1,42 @@
+use either::Either;
+use udigest::encoding::EncodeValue;
+use udigest::{Bytes, DigestAs, Digestable};
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+fn encode_variant(variant: &str, value: impl Digestable) -> Vec<u8> {
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer)
+        .encode_enum()
+        .with_variant(variant);
+    encoder.add_field_with("0", |field| field.encode(&value));
+    encoder.finish();
+    buffer.0
+}
+
+#[test]
+fn either() {
+    assert_eq!(
+        hex::encode(encode_to_vec(&Either::<u32, &str>::Left(5))),
+        hex::encode(encode_variant("Left", 5_u32))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&Either::<u32, &str>::Right("five"))),
+        hex::encode(encode_variant("Right", "five"))
+    );
+}
+
+#[test]
+fn either_digest_as() {
+    let value = Either::<u32, [u8; 3]>::Right([1, 2, 3]);
+
+    let mut buffer = VecBuf(vec![]);
+    <Either<udigest::as_::Same, Bytes>>::digest_as(&value, EncodeValue::new(&mut buffer));
+
+    assert_eq!(
+        hex::encode(buffer.0),
+        hex::encode(encode_variant("Right", Bytes([1_u8, 2, 3])))
+    );
+}
@@ -0,0 +1,76 @@
+use elliptic_curve::{sec1::ToEncodedPoint, NonZeroScalar, PublicKey};
+use udigest::Bytes;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn public_key_and_scalar() {
+    fn test_curve<C>()
+    where
+        C: elliptic_curve::CurveArithmetic,
+        elliptic_curve::AffinePoint<C>:
+            elliptic_curve::sec1::FromEncodedPoint<C> + elliptic_curve::sec1::ToEncodedPoint<C>,
+        elliptic_curve::FieldBytesSize<C>: elliptic_curve::sec1::ModulusSize,
+    {
+        let scalar = NonZeroScalar::<C>::new(C::Scalar::from(42)).unwrap();
+        let public_key = PublicKey::<C>::from_secret_scalar(&scalar);
+
+        assert_eq!(
+            hex::encode(encode_to_vec(&scalar)),
+            hex::encode(encode_to_vec(&Bytes(
+                elliptic_curve::FieldBytes::<C>::from(&scalar)
+            )))
+        );
+        assert_eq!(
+            hex::encode(encode_to_vec(&public_key)),
+            hex::encode(encode_to_vec(&Bytes(
+                public_key.to_encoded_point(true).as_bytes()
+            )))
+        );
+    }
+
+    test_curve::<k256::Secp256k1>();
+    test_curve::<p256::NistP256>();
+}
+
+#[test]
+fn points_and_scalars_via_rules() {
+    #[derive(udigest::Digestable)]
+    struct Message {
+        #[udigest(as = udigest::as_::Sec1<k256::Secp256k1>)]
+        point: k256::AffinePoint,
+        #[udigest(as = udigest::as_::FieldRepr)]
+        scalar: k256::Scalar,
+    }
+
+    #[derive(udigest::Digestable)]
+    struct Expected {
+        point: PublicKey<k256::Secp256k1>,
+        scalar: NonZeroScalar<k256::Secp256k1>,
+    }
+
+    let scalar = NonZeroScalar::<k256::Secp256k1>::new(k256::Scalar::from(42_u64)).unwrap();
+    let public_key = PublicKey::from_secret_scalar(&scalar);
+
+    let message = Message {
+        point: *public_key.as_affine(),
+        scalar: *scalar,
+    };
+    let expected = Expected {
+        point: public_key,
+        scalar,
+    };
+
+    assert_eq!(
+        hex::encode(encode_to_vec(&message)),
+        hex::encode(encode_to_vec(&expected))
+    );
+
+    // zero scalar is digestable via the rule
+    let _ = encode_to_vec(&Message {
+        point: *public_key.as_affine(),
+        scalar: k256::Scalar::ZERO,
+    });
+}
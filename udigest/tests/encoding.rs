@@ -98,16 +98,14 @@ fn encode_struct() {
     let mut buffer = VecBuf(vec![]);
 
     let mut s = EncodeStruct::new(&mut buffer);
-    s.add_field("name").encode_leaf().chain("Alice");
+    s.add_leaf_field("name", "Alice");
 
-    let mut skills = s.add_field("skills").encode_list();
+    let mut skills = s.add_list_field("skills");
     skills.add_leaf().chain("math");
     skills.add_leaf().chain("crypto");
     skills.finish();
 
-    s.add_field("job_title")
-        .encode_leaf()
-        .chain("cryptographer");
+    s.add_leaf_field("job_title", "cryptographer");
 
     s.finish();
 
@@ -217,3 +215,856 @@ fn encode_integers() {
     expect_eq(1000_i16, 1000_isize);
     expect_eq(1_000_000_isize, 1_000_000_i64);
 }
+
+#[cfg(feature = "float")]
+#[test]
+fn encode_floats() {
+    fn encoding(value: impl udigest::Digestable) -> String {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        hex::encode(buf.0)
+    }
+    fn expect(value: impl udigest::Digestable, bits: u64) {
+        let expected =
+            concat_bytes_into_vec!(bits.to_be_bytes(), 8_u32.to_be_bytes(), [LEN_32, LEAF]);
+        assert_eq!(encoding(value), hex::encode(expected));
+    }
+
+    expect(1.5_f64, 1.5_f64.to_bits());
+    expect(1.5_f32, 1.5_f64.to_bits());
+    expect(f64::INFINITY, f64::INFINITY.to_bits());
+    expect(f32::NEG_INFINITY, f64::NEG_INFINITY.to_bits());
+
+    // zeroes are canonicalized
+    expect(0.0_f64, 0);
+    expect(-0.0_f64, 0);
+    expect(-0.0_f32, 0);
+
+    // NaNs are canonicalized
+    expect(f64::NAN, 0x7ff8_0000_0000_0000);
+    expect(-f64::NAN, 0x7ff8_0000_0000_0000);
+    expect(f64::from_bits(0x7ff0_0000_0000_0001), 0x7ff8_0000_0000_0000);
+    expect(f32::NAN, 0x7ff8_0000_0000_0000);
+    expect(f32::from_bits(0xffc0_0001), 0x7ff8_0000_0000_0000);
+}
+
+#[test]
+fn encode_atomics() {
+    use std::sync::atomic::*;
+
+    fn encoding(value: impl udigest::Digestable) -> String {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        hex::encode(buf.0)
+    }
+
+    assert_eq!(encoding(AtomicBool::new(true)), encoding(true));
+    assert_eq!(encoding(AtomicU8::new(200)), encoding(200_u8));
+    assert_eq!(encoding(AtomicI16::new(-300)), encoding(-300_i16));
+    assert_eq!(encoding(AtomicU32::new(70000)), encoding(70000_u32));
+    assert_eq!(encoding(AtomicI64::new(-1 << 40)), encoding(-1_i64 << 40));
+    assert_eq!(encoding(AtomicUsize::new(12345)), encoding(12345_usize));
+}
+
+#[test]
+fn encode_cells() {
+    use std::cell::{Cell, RefCell};
+
+    fn encoding(value: impl udigest::Digestable) -> String {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        hex::encode(buf.0)
+    }
+
+    assert_eq!(encoding(Cell::new(5_u32)), encoding(5_u32));
+    assert_eq!(encoding(RefCell::new("abc")), encoding("abc"));
+
+    let cell = RefCell::new([1_u8, 2, 3]);
+    let _borrow = cell.borrow();
+    assert_eq!(encoding(&cell), encoding([1_u8, 2, 3]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_locks() {
+    use std::sync::{Arc, Mutex, RwLock};
+
+    fn encoding(value: impl udigest::Digestable) -> String {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        hex::encode(buf.0)
+    }
+
+    assert_eq!(encoding(Mutex::new(5_u32)), encoding(5_u32));
+    assert_eq!(encoding(RwLock::new("abc")), encoding("abc"));
+
+    // poisoned lock is still digested
+    let mutex = Arc::new(Mutex::new(5_u32));
+    let _ = std::thread::spawn({
+        let mutex = mutex.clone();
+        move || {
+            let _guard = mutex.lock().unwrap();
+            panic!("poison the mutex")
+        }
+    })
+    .join();
+    assert!(mutex.is_poisoned());
+    assert_eq!(encoding(&*mutex), encoding(5_u32));
+}
+
+#[test]
+fn encode_unit_and_markers() {
+    fn encoding(value: impl udigest::Digestable) -> Vec<u8> {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        buf.0
+    }
+
+    let empty_list = encoding([0_u8; 0]);
+    assert_eq!(encoding(()), empty_list);
+    assert_eq!(encoding(core::marker::PhantomData::<u8>), empty_list);
+    assert_eq!(encoding(core::marker::PhantomPinned), empty_list);
+}
+
+#[test]
+fn encode_infallible_result() {
+    fn encoding(value: impl udigest::Digestable) -> Vec<u8> {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        buf.0
+    }
+
+    assert_eq!(
+        encoding(Ok::<u32, core::convert::Infallible>(5)),
+        encoding(Ok::<u32, u32>(5))
+    );
+}
+
+#[test]
+fn encode_long_tuple() {
+    fn encoding(value: impl udigest::Digestable) -> Vec<u8> {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        buf.0
+    }
+
+    let tuple = (
+        0_u8, 1_u8, 2_u8, 3_u8, 4_u8, 5_u8, 6_u8, 7_u8, 8_u8, 9_u8, 10_u8, 11_u8, 12_u8, 13_u8,
+        14_u8, 15_u8, 16_u8, 17_u8, 18_u8, 19_u8, 20_u8, 21_u8, 22_u8, 23_u8, 24_u8, 25_u8, 26_u8,
+        27_u8, 28_u8, 29_u8, 30_u8, 31_u8,
+    );
+    let list: [u8; 32] = core::array::from_fn(|i| i as u8);
+    assert_eq!(encoding(tuple), encoding(list));
+}
+
+#[test]
+fn encode_control_flow() {
+    use core::ops::ControlFlow;
+
+    fn encoding(value: impl udigest::Digestable) -> Vec<u8> {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        buf.0
+    }
+    fn encode_variant(variant: &str, value: impl udigest::Digestable) -> Vec<u8> {
+        let mut buf = VecBuf(vec![]);
+        let mut encoder = EncodeValue::new(&mut buf)
+            .encode_enum()
+            .with_variant(variant);
+        encoder.add_field_with("0", |field| field.encode(&value));
+        encoder.finish();
+        buf.0
+    }
+
+    assert_eq!(
+        encoding(ControlFlow::<&str, u32>::Continue(5)),
+        encode_variant("Continue", 5_u32)
+    );
+    assert_eq!(
+        encoding(ControlFlow::<&str, u32>::Break("stop")),
+        encode_variant("Break", "stop")
+    );
+}
+
+#[test]
+fn encode_mut_refs_and_pins() {
+    fn encoding(value: impl udigest::Digestable) -> Vec<u8> {
+        let mut buf = VecBuf(vec![]);
+        value.unambiguously_encode(EncodeValue::new(&mut buf));
+        buf.0
+    }
+
+    let mut value = 5_u32;
+    let mut_ref: &mut u32 = &mut value;
+    assert_eq!(encoding(mut_ref), encoding(5_u32));
+    assert_eq!(encoding(core::pin::Pin::new(&value)), encoding(5_u32));
+    assert_eq!(encoding(core::pin::pin!("abc")), encoding("abc"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_to_vec() {
+    let alice = ("Alice", 24_u8, vec![true, false]);
+    assert_eq!(
+        udigest::encode_to_vec(&alice),
+        common::encode_to_vec(&alice)
+    );
+
+    let mut buffer = vec![];
+    udigest::Digestable::unambiguously_encode(&alice, EncodeValue::new(&mut buffer));
+    assert_eq!(buffer, udigest::encode_to_vec(&alice));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn encode_to_io_writer() {
+    let value = ("Alice", 24_u8, vec![true, false]);
+    let expected = common::encode_to_vec(&value);
+
+    let mut writer = IoWriter::new(Vec::new());
+    udigest::Digestable::unambiguously_encode(&value, EncodeValue::new(&mut writer));
+    assert!(writer.error().is_none());
+    assert_eq!(writer.finish().unwrap(), expected);
+
+    // Writer has insufficient capacity
+    let mut output = [0u8; 10];
+    let mut writer = IoWriter::new(&mut output[..]);
+    udigest::Digestable::unambiguously_encode(&value, EncodeValue::new(&mut writer));
+    assert_eq!(
+        writer.error().map(|err| err.kind()),
+        Some(std::io::ErrorKind::WriteZero)
+    );
+    assert!(writer.finish().is_err());
+    assert_eq!(output, expected[..10]);
+}
+
+#[test]
+fn encode_to_try_buffer() {
+    /// Buffer that accepts at most `capacity` bytes
+    struct LimitedBuf {
+        bytes: Vec<u8>,
+        capacity: usize,
+        failed_writes: usize,
+    }
+    #[derive(Debug, PartialEq)]
+    struct Overflow;
+
+    impl TryBuffer for LimitedBuf {
+        type Error = Overflow;
+        fn try_write(&mut self, bytes: &[u8]) -> Result<(), Overflow> {
+            if self.bytes.len() + bytes.len() > self.capacity {
+                self.failed_writes += 1;
+                return Err(Overflow);
+            }
+            self.bytes.extend_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    let value = ("Alice", 24_u8, [true, false]);
+    let expected = common::encode_to_vec(&value);
+
+    let mut buffer = LimitedBuf {
+        bytes: vec![],
+        capacity: expected.len(),
+        failed_writes: 0,
+    };
+    assert_eq!(udigest::try_encode(&value, &mut buffer), Ok(()));
+    assert_eq!(buffer.bytes, expected);
+
+    let mut buffer = LimitedBuf {
+        bytes: vec![],
+        capacity: expected.len() - 1,
+        failed_writes: 0,
+    };
+    assert_eq!(udigest::try_encode(&value, &mut buffer), Err(Overflow));
+    assert!(expected.starts_with(&buffer.bytes));
+    // Once the write fails, no more writes are attempted
+    assert_eq!(buffer.failed_writes, 1);
+}
+
+#[test]
+fn encode_to_slice_buffer() {
+    let value = ("Alice", 24_u8, [true, false]);
+    let expected = common::encode_to_vec(&value);
+
+    let mut bytes = [0xff; 100];
+    let mut buffer = SliceBuffer::new(&mut bytes);
+    assert!(buffer.is_empty());
+    udigest::try_encode(&value, &mut buffer).unwrap();
+    assert_eq!(buffer.len(), expected.len());
+    assert_eq!(buffer.remaining(), 100 - expected.len());
+    assert_eq!(buffer.into_filled(), &expected[..]);
+    // Bytes beyond the encoding are untouched
+    assert!(bytes[expected.len()..].iter().all(|b| *b == 0xff));
+
+    let mut bytes = vec![0; expected.len()];
+    let mut buffer = SliceBuffer::new(&mut bytes);
+    udigest::try_encode(&value, &mut buffer).unwrap();
+    assert_eq!(buffer.remaining(), 0);
+    assert_eq!(buffer.filled(), expected);
+
+    let mut bytes = vec![0; expected.len() - 1];
+    let mut buffer = SliceBuffer::new(&mut bytes);
+    assert_eq!(
+        udigest::try_encode(&value, &mut buffer),
+        Err(BufferOverflow)
+    );
+    assert!(expected.starts_with(buffer.filled()));
+}
+
+#[test]
+fn encode_into_slice() {
+    let value = ("Alice", 24_u8, [true, false]);
+    let expected = common::encode_to_vec(&value);
+
+    let mut bytes = [0xff; 100];
+    let len = udigest::encode_into(&value, &mut bytes).unwrap();
+    assert_eq!(len, expected.len());
+    assert_eq!(&bytes[..len], &expected[..]);
+    assert!(bytes[len..].iter().all(|b| *b == 0xff));
+
+    let mut bytes = vec![0; expected.len()];
+    assert_eq!(udigest::encode_into(&value, &mut bytes), Ok(expected.len()));
+    assert_eq!(bytes, expected);
+
+    // Output is left untouched if the encoding doesn't fit
+    let mut bytes = vec![0xff; expected.len() - 1];
+    let err = udigest::encode_into(&value, &mut bytes).unwrap_err();
+    assert_eq!(err.required(), expected.len());
+    assert_eq!(err.available(), expected.len() - 1);
+    assert!(bytes.iter().all(|b| *b == 0xff));
+}
+
+#[test]
+fn extend_list() {
+    let items = ["Alice", "Bob"];
+
+    let mut buffer = VecBuf(vec![]);
+    let mut list = EncodeList::new(&mut buffer);
+    list.extend(items);
+    list.extend_leaves([b"\x00\x01"]);
+    list.finish();
+
+    let mut expected = VecBuf(vec![]);
+    let mut list = EncodeList::new(&mut expected);
+    list.add_leaf().chain("Alice");
+    list.add_leaf().chain("Bob");
+    list.add_leaf().chain(b"\x00\x01");
+    list.finish();
+
+    assert_eq!(hex::encode(buffer.0), hex::encode(expected.0));
+}
+
+#[test]
+fn struct_iter_field() {
+    let mut buffer = VecBuf(vec![]);
+    let mut s = EncodeStruct::new(&mut buffer);
+    s.add_iter_field("skills", ["math", "crypto"]);
+    s.finish();
+
+    let mut expected = VecBuf(vec![]);
+    let mut s = EncodeStruct::new(&mut expected);
+    let mut list = s.add_list_field("skills");
+    list.add_leaf().chain("math");
+    list.add_leaf().chain("crypto");
+    list.finish();
+    s.finish();
+
+    assert_eq!(hex::encode(buffer.0), hex::encode(expected.0));
+}
+
+#[test]
+fn struct_nested_fields() {
+    let mut buffer = VecBuf(vec![]);
+    let mut s = EncodeStruct::new(&mut buffer);
+    s.add_struct_field("address")
+        .with_tag(b"address")
+        .add_leaf_field("city", "Paris");
+    s.add_list_field("skills").add_leaf().chain("math");
+    s.finish();
+
+    let mut expected = VecBuf(vec![]);
+    let mut s = EncodeStruct::new(&mut expected);
+    s.add_struct_field("address")
+        .with_tag(b"address")
+        .add_leaf_field("city", "Paris");
+    s.add_list_field("skills").add_leaf().chain("math");
+    s.finish();
+
+    assert_eq!(hex::encode(buffer.0), hex::encode(expected.0));
+}
+
+#[test]
+fn limit_encoding_size() {
+    let value = ("Alice", ["math", "crypto"]);
+    let expected = common::encode_to_vec(&value);
+
+    let mut buffer = LimitedBuffer::new(VecBuf(vec![])).with_max_len(expected.len());
+    udigest::Digestable::unambiguously_encode(&value, EncodeValue::new(&mut buffer));
+    assert_eq!(buffer.error(), None);
+    assert_eq!(buffer.finish().unwrap().0, expected);
+
+    let mut buffer = LimitedBuffer::new(VecBuf(vec![])).with_max_len(expected.len() - 1);
+    udigest::Digestable::unambiguously_encode(&value, EncodeValue::new(&mut buffer));
+    assert_eq!(
+        buffer.finish().err(),
+        Some(LimitExceeded::Len {
+            limit: expected.len() - 1
+        })
+    );
+}
+
+#[test]
+fn limit_nesting_depth() {
+    let value = ([["a"]], ());
+    let expected = common::encode_to_vec(&value);
+
+    let mut buffer = LimitedBuffer::new(VecBuf(vec![])).with_max_depth(3);
+    udigest::Digestable::unambiguously_encode(&value, EncodeValue::new(&mut buffer));
+    assert_eq!(buffer.finish().unwrap().0, expected);
+
+    let mut inner = VecBuf(vec![]);
+    let mut written = LengthCounter::default();
+    let mut buffer = LimitedBuffer::new(Tee(&mut inner, &mut written)).with_max_depth(2);
+    udigest::Digestable::unambiguously_encode(&value, EncodeValue::new(&mut buffer));
+    assert_eq!(buffer.error(), Some(&LimitExceeded::Depth { limit: 2 }));
+    // nothing is written after the limit is exceeded
+    assert!(inner.0.is_empty());
+    assert_eq!(written.0, 0);
+
+    // leaves don't count towards depth
+    let mut buffer = LimitedBuffer::new(VecBuf(vec![])).with_max_depth(0);
+    udigest::Digestable::unambiguously_encode(&"leaf", EncodeValue::new(&mut buffer));
+    assert!(buffer.finish().is_ok());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn trace_encoding() {
+    fn encode_person<B: Buffer>(name: &str, skills: &[&str], buffer: &mut B) {
+        let mut s = EncodeStruct::new(buffer).with_tag(b"person");
+        s.add_leaf_field("name", name);
+        s.add_iter_field("skills", skills);
+        s.finish();
+    }
+
+    let mut buffer = TraceBuffer::new(VecBuf(vec![]));
+    encode_person("Alice", &["math", "crypto"], &mut buffer);
+    let (bytes, trace) = buffer.finish();
+    let bytes = bytes.0;
+
+    let entries = trace.entries();
+    let summary = entries
+        .iter()
+        .map(|e| (e.path.as_str(), e.kind, e.len, e.bytes.as_slice()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            ("$", TraceKind::List, 4, &b""[..]),
+            ("$.name", TraceKind::Leaf, 5, b"Alice"),
+            ("$.skills", TraceKind::List, 2, b""),
+            ("$.skills[0]", TraceKind::Leaf, 4, b"math"),
+            ("$.skills[1]", TraceKind::Leaf, 6, b"crypto"),
+        ]
+    );
+    assert_eq!(entries[0].tag.as_deref(), Some(&b"person"[..]));
+    assert_eq!((entries[0].start, entries[0].end), (0, bytes.len()));
+    // every value can be cut out of the encoding by its offsets
+    for entry in &entries[1..] {
+        let mut expected = vec![];
+        let value = EncodeValue::new(&mut expected);
+        match entry.kind {
+            TraceKind::Leaf => value.encode_leaf_value(&entry.bytes),
+            TraceKind::List => {
+                let mut list = value.encode_list();
+                list.extend(["math", "crypto"]);
+            }
+        }
+        assert_eq!(&bytes[entry.start..entry.end], expected, "{entry}");
+    }
+
+    let mut buffer = TraceBuffer::new(VecBuf(vec![]));
+    encode_person("Alice", &["math", "cooking"], &mut buffer);
+    let (_, other) = buffer.finish();
+    let (a, b) = trace.first_difference(&other).unwrap();
+    assert_eq!(a.unwrap().path, "$.skills[1]");
+    assert_eq!(b.unwrap().bytes, b"cooking");
+    assert_eq!(trace.first_difference(&trace), None);
+
+    assert_eq!(
+        trace.to_string(),
+        "$: #\"person\" list of 4 items @ 0..78\n\
+         $.name: \"Alice\" @ 10..21\n\
+         $.skills: list of 2 items @ 33..61\n\
+         $.skills[0]: \"math\" @ 33..43\n\
+         $.skills[1]: \"crypto\" @ 43..55\n"
+    );
+}
+
+#[test]
+fn count_encoded_len() {
+    let value = ("Alice", 24_u8, [true, false]);
+    let expected = common::encode_to_vec(&value);
+    assert_eq!(udigest::encoded_len(&value), expected.len());
+
+    let mut counter = LengthCounter::default();
+    udigest::Digestable::unambiguously_encode(&"abc", EncodeValue::new(&mut counter));
+    udigest::Digestable::unambiguously_encode(&value, EncodeValue::new(&mut counter));
+    assert_eq!(
+        counter,
+        LengthCounter(common::encode_to_vec(&"abc").len() + expected.len())
+    );
+
+    let mut counter = LengthCounter(usize::MAX - 1);
+    counter.write(b"abc");
+    assert_eq!(counter.0, usize::MAX);
+}
+
+#[test]
+fn encode_to_tee() {
+    let value = ("Alice", 24_u8, [true, false]);
+    let expected = common::encode_to_vec(&value);
+
+    let mut a = VecBuf(vec![]);
+    let mut b = VecBuf(vec![]);
+    let mut counter = LengthCounter::default();
+    let mut buffer = Tee(&mut a, &mut Tee(&mut b, &mut counter));
+    udigest::Digestable::unambiguously_encode(&value, EncodeValue::new(&mut buffer));
+
+    assert_eq!(a.0, expected);
+    assert_eq!(b.0, expected);
+    assert_eq!(counter.0, expected.len());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn compare_encodings() {
+    let value = ("Alice", 24_u8, vec![true, false]);
+    assert!(udigest::eq(&value, &value));
+    assert!(udigest::eq(
+        &value,
+        &(String::from("Alice"), 24_u64, [true, false])
+    ));
+
+    // Values differ
+    assert!(!udigest::eq(&value, &("Alice", 25_u8, vec![true, false])));
+    // One encoding is a prefix of another
+    assert!(!udigest::eq(&value, &"Alice"));
+    assert!(!udigest::eq(&"Alice", &value));
+    assert!(!udigest::eq(&"Alice", &"Alice, Bob"));
+    assert!(!udigest::eq(&"Alice, Bob", &"Alice"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn compare_long_encodings() {
+    use core::cmp::Ordering;
+
+    // encodings span several windows, and differ at or near the window boundaries
+    let lens = [255, 256, 257, 511, 512, 513, 1000];
+    for len in lens {
+        let a = vec![7_u8; len];
+        assert!(udigest::eq(&udigest::Bytes(&a), &udigest::Bytes(&a)));
+        for other_len in lens {
+            let b = vec![7_u8; other_len];
+            assert_eq!(
+                udigest::cmp(&udigest::Bytes(&a), &udigest::Bytes(&b)),
+                common::encode_to_vec(&udigest::Bytes(&a))
+                    .cmp(&common::encode_to_vec(&udigest::Bytes(&b)))
+            );
+        }
+        for i in [0, 255, 256, 511, 512, len - 1] {
+            if i >= len {
+                continue;
+            }
+            let mut b = a.clone();
+            b[i] = 8;
+            assert!(!udigest::eq(&udigest::Bytes(&a), &udigest::Bytes(&b)));
+            assert_eq!(
+                udigest::cmp(&udigest::Bytes(&a), &udigest::Bytes(&b)),
+                Ordering::Less
+            );
+            assert_eq!(
+                udigest::cmp(&udigest::Bytes(&b), &udigest::Bytes(&a)),
+                Ordering::Greater
+            );
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn order_by_encoding() {
+    use core::cmp::Ordering;
+
+    fn expected(a: &impl udigest::Digestable, b: &impl udigest::Digestable) -> Ordering {
+        common::encode_to_vec(a).cmp(&common::encode_to_vec(b))
+    }
+
+    let values = [
+        ("Alice", 24_u8),
+        ("Alice", 25_u8),
+        ("Bob", 24_u8),
+        ("Al", 24_u8),
+        ("Alice, Bob", 24_u8),
+    ];
+    for a in &values {
+        for b in &values {
+            assert_eq!(udigest::cmp(a, b), expected(a, b));
+        }
+        assert_eq!(udigest::cmp(a, &"Alice"), expected(a, &"Alice"));
+        assert_eq!(udigest::cmp(&"Alice", a), expected(&"Alice", a));
+    }
+
+    let mut sorted = values.map(udigest::ByEncoding);
+    sorted.sort();
+    let mut expected_order = values;
+    expected_order.sort_by_key(common::encode_to_vec);
+    assert_eq!(sorted.map(|v| v.0), expected_order);
+
+    assert_eq!(
+        common::encode_to_vec(&udigest::ByEncoding(values[0])),
+        common::encode_to_vec(&values[0])
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn struct_fields_with() {
+    let mut expected = VecBuf(vec![]);
+    let mut s = EncodeStruct::new(&mut expected);
+    s.add_field("name").encode_leaf_value("Alice");
+    udigest::Digestable::unambiguously_encode(&24_u32, s.add_field("age"));
+    s.add_field("langs").encode_list().extend(["en", "fr"]);
+    // unused field encoder encodes an empty leaf
+    let _ = s.add_field("nickname");
+    s.finish();
+
+    let mut buffer = VecBuf(vec![]);
+    let mut s = EncodeStruct::new(&mut buffer);
+    s.add_field_with("name", |value| {
+        value.encode_leaf_with(|leaf| leaf.update(b"Alice"))
+    });
+    s.add_field_with("age", |value| value.encode(&24_u32));
+    s.add_field_with("langs", |value| {
+        value.encode_list_with(|list| list.extend(["en", "fr"]))
+    });
+    s.add_leaf_field("nickname", "");
+    s.finish();
+
+    assert_eq!(buffer.0, expected.0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn detached_encoding() {
+    let value = ("Alice", 24_u32, vec![["en", "fr"]]);
+    let detached = Detached::new(&value);
+    assert_eq!(
+        common::encode_to_vec(&detached),
+        common::encode_to_vec(&value)
+    );
+
+    // Compact format is used when spliced into compact encoder
+    let mut compact = VecBuf(vec![]);
+    udigest::Digestable::unambiguously_encode(
+        &detached,
+        EncodeValue::new_with_format(&mut compact, Format::V2),
+    );
+    let mut expected = VecBuf(vec![]);
+    udigest::Digestable::unambiguously_encode(
+        &value,
+        EncodeValue::new_with_format(&mut expected, Format::V2),
+    );
+    assert_eq!(compact.0, expected.0);
+
+    // Children encoded out of order
+    let (second, first) = (Detached::new(&[1_u8, 2]), Detached::new("first"));
+    let mut buffer = VecBuf(vec![]);
+    let mut s = EncodeStruct::new(&mut buffer).with_tag(b"udigest.test");
+    s.add_field_with("first", |field| field.encode(&first));
+    s.add_field_with("second", |field| field.encode(&second));
+    s.finish();
+    let mut expected = VecBuf(vec![]);
+    let mut s = EncodeStruct::new(&mut expected).with_tag(b"udigest.test");
+    s.add_leaf_field("first", "first");
+    s.add_field_with("second", |field| field.encode(&[1_u8, 2]));
+    s.finish();
+    assert_eq!(buffer.0, expected.0);
+
+    // Events are reported as if the value was encoded directly
+    assert_eq!(
+        udigest::encoding::Trace::of(&detached).to_string(),
+        udigest::encoding::Trace::of(&value).to_string(),
+    );
+
+    // Unused encoder is an empty leaf
+    let empty = Detached::encode_with(|_encoder| {});
+    assert_eq!(common::encode_to_vec(&empty), common::encode_to_vec(&""));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn leaf_from_reader() {
+    let data = (0..20_000_u32).map(|i| i as u8).collect::<Vec<_>>();
+
+    let mut buffer = VecBuf(vec![]);
+    EncodeLeaf::new(&mut buffer)
+        .with_tag(b"udigest.test")
+        .chain(b"prefix")
+        .chain_reader(&data[..])
+        .unwrap();
+    let mut expected = VecBuf(vec![]);
+    EncodeLeaf::new(&mut expected)
+        .with_tag(b"udigest.test")
+        .chain(b"prefix")
+        .chain(&data);
+    assert_eq!(buffer.0, expected.0);
+
+    let mut buffer = VecBuf(vec![]);
+    let mut leaf = EncodeLeaf::new(&mut buffer);
+    assert_eq!(leaf.update_from_reader(&data[..]).unwrap(), 20_000);
+    assert_eq!(leaf.update_from_reader(std::io::empty()).unwrap(), 0);
+    leaf.finish();
+    assert_eq!(buffer.0, common::encode_to_vec(&udigest::Bytes(&data)));
+
+    /// Fails after the first read
+    struct Failing(bool);
+    impl std::io::Read for Failing {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if core::mem::replace(&mut self.0, true) {
+                Err(std::io::Error::other("failed"))
+            } else {
+                buf[0] = 1;
+                Ok(1)
+            }
+        }
+    }
+    let mut buffer = VecBuf(vec![]);
+    let err = EncodeLeaf::new(&mut buffer)
+        .chain_reader(Failing(false))
+        .err()
+        .unwrap();
+    assert_eq!(err.to_string(), "failed");
+}
+
+#[test]
+fn std_hash_adapter() {
+    /// Records every write separately
+    #[derive(Default)]
+    struct Writes(Vec<Vec<u8>>);
+    impl core::hash::Hasher for Writes {
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.push(bytes.to_vec())
+        }
+        fn finish(&self) -> u64 {
+            0
+        }
+    }
+
+    let name = "Alice".repeat(20);
+    let value = (name.as_str(), 24_u32, [true, false]);
+    let mut writes = Writes::default();
+    udigest::StdHashAdapter::new(&mut writes).write_value(&value);
+
+    assert_eq!(writes.0.concat(), common::encode_to_vec(&value));
+    let (last, full) = writes.0.split_last().unwrap();
+    assert!(full.iter().all(|chunk| chunk.len() == 64));
+    assert!(!last.is_empty() && last.len() <= 64);
+
+    let mut empty = Writes::default();
+    drop(udigest::StdHashAdapter::new(&mut empty));
+    assert!(empty.0.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn hash_by_encoding() {
+    use core::hash::BuildHasher;
+
+    let state = std::collections::hash_map::RandomState::new();
+    assert_eq!(
+        state.hash_one(udigest::ByEncoding(("Alice", vec![1_u8, 2]))),
+        state.hash_one(udigest::ByEncoding((String::from("Alice"), [1_u8, 2]))),
+    );
+
+    let mut set = std::collections::HashSet::new();
+    assert!(set.insert(udigest::ByEncoding(("Alice", 24_u32))));
+    assert!(set.insert(udigest::ByEncoding(("Bob", 24_u32))));
+    assert!(!set.insert(udigest::ByEncoding(("Alice", 24_u32))));
+}
+
+#[test]
+fn compact_encoding() {
+    // Encode:
+    // ["1234", [], tagged("abc", "ctx"), ""]
+    let mut buffer = VecBuf(vec![]);
+
+    let mut list = EncodeList::new_with_format(&mut buffer, Format::V2);
+    list.add_leaf().chain(b"1234");
+    list.add_list().finish();
+    list.add_leaf().with_tag(b"ctx").chain(b"abc");
+    let _ = list.add_item();
+    list.finish();
+
+    let expected = concat_bytes_into_vec!(
+        // "1234" 4_u8 1_u8 LEAF
+        b"1234",
+        [4, 1, V2_LEAF],
+        // 0 elements: empty length, 0_u8 LIST
+        [0, V2_LIST],
+        // "abc" 3_u8 1_u8 "ctx" 3_u8 1_u8 LEAF_CTX
+        b"abc",
+        [3, 1],
+        b"ctx",
+        [3, 1, V2_LEAF_CTX],
+        // empty leaf
+        [0, V2_LEAF],
+        // 4_u8 1_u8 LIST
+        [4, 1, V2_LIST],
+    );
+    assert_eq!(buffer.0, expected);
+}
+
+#[test]
+fn compact_encoding_of_long_values() {
+    let value = vec![0xab_u8; 0x1234];
+    let mut buffer = VecBuf(vec![]);
+    EncodeValue::new_with_format(&mut buffer, Format::V2).encode_leaf_value(&value);
+
+    let expected = concat_bytes_into_vec!(&value, [0x12, 0x34, 2, V2_LEAF]);
+    assert_eq!(buffer.0, expected);
+}
+
+#[test]
+fn compact_format_is_inherited() {
+    use udigest::Digestable;
+
+    let value = ("Alice", ["Bob", "Carol"]);
+
+    let mut v2 = VecBuf(vec![]);
+    value.unambiguously_encode(EncodeValue::new_with_format(&mut v2, Format::V2));
+
+    let mut expected = VecBuf(vec![]);
+    let mut list = EncodeList::new_with_format(&mut expected, Format::V2);
+    list.add_leaf().chain("Alice");
+    let mut sublist = list.add_list();
+    sublist.add_leaf().chain("Bob");
+    sublist.add_leaf().chain("Carol");
+    sublist.finish();
+    list.finish();
+
+    assert_eq!(v2.0, expected.0);
+    assert!(v2.0.len() < common::encode_to_vec(&value).len());
+
+    #[cfg(feature = "digest")]
+    {
+        use sha2::Digest;
+        assert_eq!(
+            udigest::hash_v2::<sha2::Sha256>(&value),
+            sha2::Sha256::digest(&v2.0)
+        );
+    }
+}
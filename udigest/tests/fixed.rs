@@ -0,0 +1,40 @@
+use fixed::types::{I16F16, I48F16, I8F24, U16F16};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn fixed_point_number() {
+    let number = I16F16::from_num(-1.5);
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("bits", |field| field.encode(&(-0x18000_i32)));
+    encoder.add_field_with("frac_bits", |field| field.encode(&16_u32));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&number)), hex::encode(buffer.0));
+}
+
+#[test]
+fn encoding_depends_on_bits_and_frac_count() {
+    // same bits and frac count, different width
+    assert_eq!(
+        hex::encode(encode_to_vec(&I16F16::from_num(1.5))),
+        hex::encode(encode_to_vec(&I48F16::from_num(1.5)))
+    );
+
+    // signed and unsigned integers have different encodings
+    assert_ne!(
+        encode_to_vec(&I16F16::from_num(1.5)),
+        encode_to_vec(&U16F16::from_num(1.5))
+    );
+
+    // same value, different frac count
+    assert_ne!(
+        encode_to_vec(&I16F16::from_num(1.5)),
+        encode_to_vec(&I8F24::from_num(1.5))
+    );
+}
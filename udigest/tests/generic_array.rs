@@ -0,0 +1,40 @@
+use generic_array::{typenum::U3, GenericArray};
+use udigest::encoding::EncodeValue;
+use udigest::{Bytes, DigestAs};
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn generic_array_is_encoded_as_array() {
+    let array = GenericArray::<u32, U3>::from([1, 2, 3]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&array)),
+        hex::encode(encode_to_vec(&[1_u32, 2, 3]))
+    );
+
+    // bytes rule
+    let bytes = GenericArray::<u8, U3>::from([1, 2, 3]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&Bytes(bytes))),
+        hex::encode(encode_to_vec(&Bytes([1_u8, 2, 3])))
+    );
+}
+
+#[test]
+fn digest_as() {
+    let array = GenericArray::<[u8; 2], U3>::from([[1, 2], [3, 4], [5, 6]]);
+
+    let mut buffer = VecBuf(vec![]);
+    <GenericArray<Bytes, U3>>::digest_as(&array, EncodeValue::new(&mut buffer));
+
+    assert_eq!(
+        hex::encode(buffer.0),
+        hex::encode(encode_to_vec(&[
+            Bytes([1_u8, 2]),
+            Bytes([3, 4]),
+            Bytes([5, 6])
+        ]))
+    );
+}
@@ -0,0 +1,62 @@
+use geo_types::{coord, line_string, point, polygon, Geometry, Point};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn point() {
+    let point: Point<i32> = point!(x: 1, y: -2);
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("x", |field| field.encode(&1_i32));
+    encoder.add_field_with("y", |field| field.encode(&(-2_i32)));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&point)), hex::encode(&buffer.0));
+    assert_eq!(
+        hex::encode(encode_to_vec(&coord!(x: 1_i32, y: -2))),
+        hex::encode(&buffer.0)
+    );
+}
+
+#[test]
+fn polygon() {
+    let exterior = line_string![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 0)];
+    let interior = line_string![(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2), (x: 1, y: 1)];
+    let polygon = polygon!(
+        exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 0)],
+        interiors: [[(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2), (x: 1, y: 1)]],
+    );
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("exterior", |field| field.encode(&exterior));
+    encoder.add_field_with("interiors", |field| field.encode(&[interior]));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&polygon)), hex::encode(buffer.0));
+}
+
+#[test]
+fn geometry() {
+    let point: Point<i32> = point!(x: 1, y: 2);
+    let geometry = Geometry::Point(point);
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer)
+        .encode_enum()
+        .with_variant("Point");
+    encoder.add_field_with("0", |field| field.encode(&point));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&geometry)), hex::encode(buffer.0));
+
+    // a point and a multi-point holding the same point have different encodings
+    assert_ne!(
+        encode_to_vec(&geometry),
+        encode_to_vec(&Geometry::MultiPoint(vec![point].into()))
+    );
+}
@@ -0,0 +1,31 @@
+use half::{bf16, f16};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn half_floats_are_encoded_as_f64() {
+    for x in [0.0, 1.5, -2.25, 256.0, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(
+            hex::encode(encode_to_vec(&f16::from_f64(x))),
+            hex::encode(encode_to_vec(&x))
+        );
+        assert_eq!(
+            hex::encode(encode_to_vec(&bf16::from_f64(x))),
+            hex::encode(encode_to_vec(&x))
+        );
+    }
+}
+
+#[test]
+fn nans_and_zeroes_are_canonicalized() {
+    assert_eq!(encode_to_vec(&f16::NAN), encode_to_vec(&f64::NAN));
+    assert_eq!(
+        encode_to_vec(&f16::from_bits(0xfe01)),
+        encode_to_vec(&f16::NAN)
+    );
+    assert_eq!(encode_to_vec(&bf16::NAN), encode_to_vec(&f64::NAN));
+    assert_eq!(encode_to_vec(&f16::NEG_ZERO), encode_to_vec(&f16::ZERO));
+    assert_eq!(encode_to_vec(&bf16::NEG_ZERO), encode_to_vec(&bf16::ZERO));
+}
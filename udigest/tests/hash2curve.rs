@@ -0,0 +1,57 @@
+use elliptic_curve::hash2curve::{ExpandMsgXmd, ExpandMsgXof, GroupDigest};
+
+mod common;
+
+const DST: &[u8] = b"udigest-test-V01-CS01-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+
+#[derive(udigest::Digestable)]
+struct Message {
+    session_id: u64,
+    text: &'static str,
+}
+
+const MESSAGE: Message = Message {
+    session_id: 1,
+    text: "Hello, world!",
+};
+
+#[test]
+fn hash_to_curve() {
+    type X = ExpandMsgXmd<sha2::Sha256>;
+    let msg = common::encode_to_vec(&MESSAGE);
+
+    let point = udigest::hash2curve::hash_to_curve::<k256::Secp256k1, X>(&MESSAGE, DST).unwrap();
+    let expected = k256::Secp256k1::hash_from_bytes::<X>(&[&msg], &[DST]).unwrap();
+    assert_eq!(point, expected);
+
+    let another_dst =
+        udigest::hash2curve::hash_to_curve::<k256::Secp256k1, X>(&MESSAGE, b"another dst").unwrap();
+    assert_ne!(another_dst, expected);
+
+    let point = udigest::hash2curve::encode_to_curve::<p256::NistP256, X>(&MESSAGE, DST).unwrap();
+    let expected = p256::NistP256::encode_from_bytes::<X>(&[&msg], &[DST]).unwrap();
+    assert_eq!(point, expected);
+}
+
+#[test]
+fn hash_to_scalar() {
+    type X = ExpandMsgXof<sha3::Shake256>;
+    let msg = common::encode_to_vec(&MESSAGE);
+
+    let scalar = udigest::hash2curve::hash_to_scalar::<k256::Secp256k1, X>(&MESSAGE, DST).unwrap();
+    let expected = k256::Secp256k1::hash_to_scalar::<X>(&[&msg], &[DST]).unwrap();
+    assert_eq!(scalar, expected);
+}
+
+#[test]
+fn hash_to_field() {
+    type X = ExpandMsgXmd<sha2::Sha256>;
+    let msg = common::encode_to_vec(&MESSAGE);
+
+    let mut elements = [p256::Scalar::default(); 3];
+    udigest::hash2curve::hash_to_field::<X, _>(&MESSAGE, DST, &mut elements).unwrap();
+
+    let mut expected = [p256::Scalar::default(); 3];
+    elliptic_curve::hash2curve::hash_to_field::<X, _>(&[&msg], &[DST], &mut expected).unwrap();
+    assert_eq!(elements, expected);
+}
@@ -0,0 +1,43 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn hash_map_and_set() {
+    #[derive(udigest::Digestable)]
+    struct Attributes {
+        #[udigest(as = BTreeMap<_, udigest::Bytes>)]
+        map: hashbrown::HashMap<String, Vec<u8>>,
+        #[udigest(as = BTreeSet<_>)]
+        set: hashbrown::HashSet<u32>,
+    }
+
+    impl Attributes {
+        fn digest_expected(&self) -> impl udigest::Digestable + '_ {
+            udigest::inline_struct!({
+                map: self
+                    .map
+                    .iter()
+                    .map(|(k, v)| (k, udigest::Bytes(v)))
+                    .collect::<BTreeMap<_, _>>(),
+                set: self.set.iter().collect::<BTreeSet<_>>(),
+            })
+        }
+    }
+
+    let attrs = Attributes {
+        map: FromIterator::from_iter([
+            ("some_attr".to_string(), b"value1".to_vec()),
+            ("attr".to_string(), b"value2".to_vec()),
+            ("some_other_attr".to_string(), b"value3".to_vec()),
+        ]),
+        set: FromIterator::from_iter([5, 1, 100, 42]),
+    };
+
+    let expected = encode_to_vec(&attrs.digest_expected());
+    let actual = encode_to_vec(&attrs);
+
+    assert_eq!(hex::encode(expected), hex::encode(actual));
+}
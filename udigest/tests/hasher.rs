@@ -0,0 +1,144 @@
+use udigest::encoding::{BufferDigest, EncodeList};
+
+#[test]
+fn same_as_hash_iter() {
+    let values = [("Alice", 24_u32), ("Bob", 25), ("Carol", 26)];
+
+    let mut hasher = udigest::Hasher::<sha2::Sha256>::default();
+    assert!(hasher.is_empty());
+    for value in &values {
+        hasher.push(value);
+    }
+    assert_eq!(hasher.len(), 3);
+    assert_eq!(
+        hasher.finalize(),
+        udigest::hash_iter::<sha2::Sha256>(&values)
+    );
+
+    let hasher = udigest::Hasher::<sha2::Sha256>::default();
+    assert_eq!(
+        hasher.finalize(),
+        udigest::hash_iter::<sha2::Sha256>(core::iter::empty::<u32>())
+    );
+}
+
+#[test]
+fn custom_tag() {
+    use sha2::Digest;
+
+    let mut hasher = udigest::Hasher::<sha2::Sha256>::new(b"udigest.test");
+    hasher.push(&"Alice");
+    hasher.push(&[1_u8, 2, 3]);
+
+    let mut expected = BufferDigest(sha2::Sha256::new());
+    let mut list = EncodeList::new(&mut expected).with_tag(b"udigest.test");
+    udigest::Digestable::unambiguously_encode(&"Alice", list.add_item());
+    udigest::Digestable::unambiguously_encode(&[1_u8, 2, 3], list.add_item());
+    list.finish();
+
+    assert_eq!(hasher.finalize(), expected.0.finalize());
+}
+
+#[test]
+fn digester_chaining() {
+    use sha2::Digest;
+
+    let digester = udigest::Digester::<sha2::Sha256>::new()
+        .chain(&"Alice")
+        .chain(&24_u32);
+    assert_eq!(digester.len(), 2);
+    let mut hasher = udigest::Hasher::<sha2::Sha256>::default();
+    hasher.push(&"Alice");
+    hasher.push(&24_u32);
+    assert_eq!(digester.finalize(), hasher.finalize());
+
+    assert!(udigest::Digester::<sha2::Sha256>::default().is_empty());
+    assert_eq!(
+        udigest::Digester::<sha2::Sha256>::new().finalize(),
+        udigest::hash_iter::<sha2::Sha256>(core::iter::empty::<u32>())
+    );
+
+    // Tag can be set at any point
+    let hash = udigest::Digester::<sha2::Sha256>::new()
+        .chain(&"Alice")
+        .tag(b"udigest.test")
+        .chain(&[1_u8, 2, 3])
+        .finalize();
+    let mut expected = BufferDigest(sha2::Sha256::new());
+    let mut list = EncodeList::new(&mut expected).with_tag(b"udigest.test");
+    udigest::Digestable::unambiguously_encode(&"Alice", list.add_item());
+    udigest::Digestable::unambiguously_encode(&[1_u8, 2, 3], list.add_item());
+    list.finish();
+    assert_eq!(hash, expected.0.finalize());
+}
+
+#[test]
+fn hash_tagged() {
+    use sha2::Digest;
+
+    let value = ("Alice", 24_u32);
+    let hash = udigest::hash_tagged::<sha2::Sha256>(b"udigest.test", &value);
+
+    let mut expected = BufferDigest(sha2::Sha256::new());
+    let mut list = EncodeList::new(&mut expected).with_tag(b"udigest.test");
+    udigest::Digestable::unambiguously_encode(&value, list.add_item());
+    list.finish();
+    assert_eq!(hash, expected.0.finalize());
+
+    assert_ne!(hash, udigest::hash::<sha2::Sha256>(&value));
+    assert_ne!(
+        hash,
+        udigest::hash_tagged::<sha2::Sha256>(b"udigest.test2", &value)
+    );
+}
+
+#[test]
+fn hash_map_iter() {
+    let pairs = [(1_u32, "Alice"), (2, "Bob"), (300, "Carol")];
+    let hash = udigest::hash_map_iter::<sha2::Sha256, _, _>(pairs);
+    assert_eq!(hash, udigest::hash::<sha2::Sha256>(&pairs));
+    assert_ne!(hash, udigest::hash_iter::<sha2::Sha256>(pairs));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn hash_map_iter_sorted() {
+    let pairs = [(300_u32, "Carol"), (1, "Alice"), (2, "Bob")];
+    let hash = udigest::hash_map_iter_sorted::<sha2::Sha256, _, _>(pairs);
+
+    let mut sorted = pairs;
+    sorted.sort_by_key(|(k, _)| udigest::encode_to_vec(k));
+    assert_eq!(hash, udigest::hash_map_iter::<sha2::Sha256, _, _>(sorted));
+
+    let mut reversed = pairs;
+    reversed.reverse();
+    assert_eq!(
+        hash,
+        udigest::hash_map_iter_sorted::<sha2::Sha256, _, _>(reversed)
+    );
+    assert_eq!(
+        hash,
+        udigest::hash_map_iter_sorted::<sha2::Sha256, _, _>(std::collections::HashMap::from(pairs))
+    );
+}
+
+#[test]
+fn hash_into() {
+    use sha2::Digest;
+
+    let value = ("Alice", 24_u32);
+
+    let mut hasher = sha2::Sha256::new();
+    udigest::hash_into(&mut hasher, &value);
+    assert_eq!(hasher.finalize(), udigest::hash::<sha2::Sha256>(&value));
+
+    // Prefix fed into the hasher beforehand
+    let mut hasher = sha2::Sha256::new_with_prefix(b"protocol v1");
+    udigest::hash_into(&mut hasher, &value);
+    let mut expected = BufferDigest(sha2::Sha256::new_with_prefix(b"protocol v1"));
+    udigest::Digestable::unambiguously_encode(
+        &value,
+        udigest::encoding::EncodeValue::new(&mut expected),
+    );
+    assert_eq!(hasher.finalize(), expected.0.finalize());
+}
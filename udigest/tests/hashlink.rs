@@ -0,0 +1,71 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use hashlink::{LinkedHashMap, LinkedHashSet, LruCache};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn insertion_order_is_preserved() {
+    let map = LinkedHashMap::<_, _>::from_iter([("b", 1_u32), ("a", 2), ("c", 3)]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&map)),
+        hex::encode(encode_to_vec(&vec![("b", 1_u32), ("a", 2), ("c", 3)]))
+    );
+
+    let set = LinkedHashSet::<_>::from_iter([3_u32, 1, 2]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&set)),
+        hex::encode(encode_to_vec(&vec![3_u32, 1, 2]))
+    );
+
+    let mut cache = LruCache::new(2);
+    cache.insert("b", 1_u32);
+    cache.insert("a", 2);
+    cache.insert("c", 3);
+    // `b` is evicted, `a` becomes the most recently used
+    cache.get(&"a");
+    assert_eq!(
+        hex::encode(encode_to_vec(&cache)),
+        hex::encode(encode_to_vec(&vec![("c", 3_u32), ("a", 2)]))
+    );
+}
+
+#[test]
+fn sorted_rules() {
+    #[derive(udigest::Digestable)]
+    struct Attributes {
+        #[udigest(as = BTreeMap<_, udigest::Bytes>)]
+        map: LinkedHashMap<String, Vec<u8>>,
+        #[udigest(as = BTreeSet<_>)]
+        set: LinkedHashSet<u32>,
+    }
+
+    impl Attributes {
+        fn digest_expected(&self) -> impl udigest::Digestable + '_ {
+            udigest::inline_struct!({
+                map: self
+                    .map
+                    .iter()
+                    .map(|(k, v)| (k, udigest::Bytes(v)))
+                    .collect::<BTreeMap<_, _>>(),
+                set: self.set.iter().collect::<BTreeSet<_>>(),
+            })
+        }
+    }
+
+    let attrs = Attributes {
+        map: FromIterator::from_iter([
+            ("some_attr".to_string(), b"value1".to_vec()),
+            ("attr".to_string(), b"value2".to_vec()),
+            ("some_other_attr".to_string(), b"value3".to_vec()),
+        ]),
+        set: FromIterator::from_iter([5, 1, 100, 42]),
+    };
+
+    let expected = encode_to_vec(&attrs.digest_expected());
+    let actual = encode_to_vec(&attrs);
+
+    assert_eq!(hex::encode(expected), hex::encode(actual));
+}
@@ -0,0 +1,42 @@
+mod common;
+
+type Hkdf = hkdf::Hkdf<sha2::Sha256>;
+
+#[test]
+fn derive_key() {
+    let salt = b"salt";
+    let handshake = ("udigest.test.handshake", [42u8; 32], 1_u32);
+
+    let mut key = [0u8; 42];
+    udigest::derive_key::<sha2::Sha256>(Some(salt), &handshake, b"info", &mut key).unwrap();
+
+    let mut expected = [0u8; 42];
+    Hkdf::new(Some(salt), &common::encode_to_vec(&handshake))
+        .expand(b"info", &mut expected)
+        .unwrap();
+    assert_eq!(hex::encode(key), hex::encode(expected));
+
+    let mut another_key = [0u8; 42];
+    udigest::derive_key::<sha2::Sha256>(None, &handshake, b"info", &mut another_key).unwrap();
+    assert_ne!(key, another_key);
+
+    // Output is too long
+    let mut too_long = vec![0u8; 255 * 32 + 1];
+    assert!(udigest::derive_key::<sha2::Sha256>(None, &handshake, b"", &mut too_long).is_err());
+}
+
+#[test]
+fn derive_key_with_info() {
+    let shared_secret = [42u8; 32];
+    let session = ("udigest.test.session", 1_u32, ["Alice", "Bob"]);
+
+    let mut key = [0u8; 32];
+    udigest::derive_key_with_info::<sha2::Sha256>(None, &shared_secret, &session, &mut key)
+        .unwrap();
+
+    let mut expected = [0u8; 32];
+    Hkdf::new(None, &shared_secret)
+        .expand(&common::encode_to_vec(&session), &mut expected)
+        .unwrap();
+    assert_eq!(hex::encode(key), hex::encode(expected));
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use http::{HeaderMap, HeaderValue, Method, StatusCode, Uri};
+use udigest::Bytes;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn simple_types() {
+    assert_eq!(
+        hex::encode(encode_to_vec(&Method::GET)),
+        hex::encode(encode_to_vec(&"GET"))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&StatusCode::NOT_FOUND)),
+        hex::encode(encode_to_vec(&404_u16))
+    );
+
+    let uri: Uri = "https://example.com/path?query=1".parse().unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&uri)),
+        hex::encode(encode_to_vec(&"https://example.com/path?query=1"))
+    );
+
+    let value = HeaderValue::from_bytes(b"\xffvalue").unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&value)),
+        hex::encode(encode_to_vec(&Bytes(b"\xffvalue")))
+    );
+}
+
+#[test]
+fn header_map_is_sorted_by_name() {
+    let mut a = HeaderMap::new();
+    a.insert("x-b", HeaderValue::from_static("1"));
+    a.append("Accept", HeaderValue::from_static("text/html"));
+    a.append("Accept", HeaderValue::from_static("text/plain"));
+
+    let mut b = HeaderMap::new();
+    b.append("accept", HeaderValue::from_static("text/html"));
+    b.append("accept", HeaderValue::from_static("text/plain"));
+    b.insert("X-B", HeaderValue::from_static("1"));
+
+    let expected = BTreeMap::from([
+        ("accept", vec![Bytes("text/html"), Bytes("text/plain")]),
+        ("x-b", vec![Bytes("1")]),
+    ]);
+
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&expected))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&b)),
+        hex::encode(encode_to_vec(&expected))
+    );
+
+    // order of values of the same header matters
+    let mut c = HeaderMap::new();
+    c.append("accept", HeaderValue::from_static("text/plain"));
+    c.append("accept", HeaderValue::from_static("text/html"));
+    c.insert("x-b", HeaderValue::from_static("1"));
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&c));
+}
@@ -0,0 +1,28 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn persistent_collections_are_encoded_as_std_ones() {
+    let vector: im::Vector<_> = [3_u32, 1, 2].into_iter().collect();
+    assert_eq!(
+        hex::encode(encode_to_vec(&vector)),
+        hex::encode(encode_to_vec(&vec![3_u32, 1, 2]))
+    );
+
+    let map: im::OrdMap<&str, u32> = [("b", 2_u32), ("a", 1), ("c", 3)].into_iter().collect();
+    let expected = BTreeMap::from([("b", 2_u32), ("a", 1), ("c", 3)]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&map)),
+        hex::encode(encode_to_vec(&expected))
+    );
+
+    let set: im::OrdSet<u32> = [5_u32, 1, 3].into_iter().collect();
+    let expected = BTreeSet::from([5_u32, 1, 3]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&set)),
+        hex::encode(encode_to_vec(&expected))
+    );
+}
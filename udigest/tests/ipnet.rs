@@ -0,0 +1,35 @@
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+use udigest::encoding::EncodeValue;
+use udigest::Bytes;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn networks() {
+    let net: Ipv4Net = "10.1.0.0/16".parse().unwrap();
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("addr", |field| field.encode(&Bytes([10_u8, 1, 0, 0])));
+    encoder.add_field_with("prefix_len", |field| field.encode(&16_u8));
+    encoder.finish();
+    assert_eq!(hex::encode(encode_to_vec(&net)), hex::encode(&buffer.0));
+
+    let mut buffer2 = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer2)
+        .encode_enum()
+        .with_variant("V4");
+    encoder.add_field_with("0", |field| field.encode(&net));
+    encoder.finish();
+    assert_eq!(
+        hex::encode(encode_to_vec(&IpNet::V4(net))),
+        hex::encode(buffer2.0)
+    );
+
+    // the same address with different prefix lengths
+    let a: Ipv6Net = "fd00::/8".parse().unwrap();
+    let b: Ipv6Net = "fd00::/16".parse().unwrap();
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&b));
+}
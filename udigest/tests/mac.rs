@@ -0,0 +1,34 @@
+use digest::Mac;
+
+mod common;
+
+type HmacSha256 = hmac::Hmac<sha2::Sha256>;
+
+#[test]
+fn hmac() {
+    let key = b"secret key";
+    let message = ("transfer", 100_u64, ["Alice", "Bob"]);
+
+    let tag = udigest::hash_mac::<HmacSha256>(key, &message)
+        .unwrap()
+        .into_bytes();
+
+    let mut expected = HmacSha256::new_from_slice(key).unwrap();
+    expected.update(&common::encode_to_vec(&message));
+    let expected = expected.finalize().into_bytes();
+    assert_eq!(hex::encode(tag), hex::encode(expected));
+
+    let mut verifier = HmacSha256::new_from_slice(key).unwrap();
+    verifier.update(&common::encode_to_vec(&message));
+    verifier.verify(&tag).unwrap();
+
+    let another_key = udigest::hash_mac::<HmacSha256>(b"another key", &message)
+        .unwrap()
+        .into_bytes();
+    assert_ne!(another_key, expected);
+    let another_message =
+        udigest::hash_mac::<HmacSha256>(key, &("transfer", 101_u64, ["Alice", "Bob"]))
+            .unwrap()
+            .into_bytes();
+    assert_ne!(another_message, expected);
+}
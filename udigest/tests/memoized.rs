@@ -0,0 +1,88 @@
+use core::cell::Cell;
+
+use udigest::Memoized;
+
+mod common;
+
+/// Counts how many times the value was encoded
+struct Counting<'a> {
+    value: u32,
+    encoded: &'a Cell<usize>,
+}
+
+impl udigest::Digestable for Counting<'_> {
+    fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: udigest::encoding::EncodeValue<B>) {
+        self.encoded.set(self.encoded.get() + 1);
+        self.value.unambiguously_encode(encoder)
+    }
+}
+
+#[derive(udigest::Digestable)]
+struct Message<'a> {
+    params: &'a Memoized<Counting<'a>, sha2::Sha256>,
+    nonce: u64,
+}
+
+#[test]
+fn encodes_value_once() {
+    let encoded = Cell::new(0);
+    let params = Memoized::new(Counting {
+        value: 42,
+        encoded: &encoded,
+    });
+
+    let a = udigest::hash::<sha2::Sha256>(&Message {
+        params: &params,
+        nonce: 1,
+    });
+    let b = udigest::hash::<sha2::Sha256>(&Message {
+        params: &params,
+        nonce: 2,
+    });
+    assert_ne!(a, b);
+    assert_eq!(encoded.get(), 1);
+}
+
+#[test]
+fn encoding_is_tagged_digest() {
+    let memoized = Memoized::<_, sha2::Sha256>::new("Alice");
+    assert_eq!(*memoized.digest(), udigest::hash::<sha2::Sha256>(&"Alice"));
+
+    let mut buffer = common::VecBuf(vec![]);
+    udigest::encoding::EncodeValue::new(&mut buffer)
+        .encode_leaf()
+        .with_tag(b"udigest.memoized")
+        .chain(udigest::hash::<sha2::Sha256>(&"Alice"));
+    assert_eq!(common::encode_to_vec(&memoized), buffer.0);
+}
+
+#[test]
+fn get_mut_invalidates_digest() {
+    let encoded = Cell::new(0);
+    let mut memoized = Memoized::<_, sha2::Sha256>::new(Counting {
+        value: 1,
+        encoded: &encoded,
+    });
+    let before = *memoized.digest();
+    let _ = memoized.digest();
+    let _ = udigest::hash::<sha2::Sha256>(&memoized);
+    assert_eq!(encoded.get(), 1);
+
+    memoized.get_mut().value = 2;
+    let after = *memoized.digest();
+    assert_eq!(encoded.get(), 2);
+    assert_ne!(before, after);
+    assert_eq!(memoized.get().value, 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn memoized_is_sync() {
+    fn assert_sync<T: Sync>(_: &T) {}
+    let memoized = Memoized::<_, sha2::Sha256>::new(42_u32);
+    let _ = memoized.digest();
+    assert_sync(&memoized);
+    std::thread::scope(|s| {
+        s.spawn(|| assert_eq!(*memoized.digest(), udigest::hash::<sha2::Sha256>(&42_u32)));
+    });
+}
@@ -0,0 +1,31 @@
+use multiaddr::{Multiaddr, PeerId};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+const PEER_ID: &str = "12D3KooWD3eckifWpRn9wQpMG9R9hX3sD158z7EqHWmweQAJU5SA";
+
+#[test]
+fn multiaddr() {
+    let addr: Multiaddr = "/ip4/127.0.0.1/tcp/8080".parse().unwrap();
+
+    let mut buffer = VecBuf(vec![]);
+    EncodeValue::new(&mut buffer).encode_leaf_value(addr.to_vec());
+
+    assert_eq!(hex::encode(encode_to_vec(&addr)), hex::encode(buffer.0));
+
+    let other_addr: Multiaddr = "/ip4/127.0.0.1/udp/8080".parse().unwrap();
+    assert_ne!(encode_to_vec(&addr), encode_to_vec(&other_addr));
+}
+
+#[test]
+fn peer_id() {
+    let peer_id: PeerId = PEER_ID.parse().unwrap();
+
+    let mut buffer = VecBuf(vec![]);
+    EncodeValue::new(&mut buffer).encode_leaf_value(peer_id.to_bytes());
+
+    assert_eq!(hex::encode(encode_to_vec(&peer_id)), hex::encode(&buffer.0));
+}
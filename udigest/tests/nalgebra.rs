@@ -0,0 +1,62 @@
+use nalgebra::{DMatrix, DVector, Matrix2x3, Vector3};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+fn encode_matrix(rows: usize, cols: usize, elements: &[u32]) -> Vec<u8> {
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("rows", |field| field.encode(&rows));
+    encoder.add_field_with("cols", |field| field.encode(&cols));
+    encoder.add_field_with("elements", |field| field.encode(&elements));
+    encoder.finish();
+    buffer.0
+}
+
+#[test]
+fn matrices_are_encoded_in_row_major_order() {
+    let expected = encode_matrix(2, 3, &[1, 2, 3, 4, 5, 6]);
+
+    let static_matrix = Matrix2x3::new(1_u32, 2, 3, 4, 5, 6);
+    assert_eq!(
+        hex::encode(encode_to_vec(&static_matrix)),
+        hex::encode(&expected)
+    );
+
+    let dynamic_matrix = DMatrix::from_row_slice(2, 3, &[1_u32, 2, 3, 4, 5, 6]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&dynamic_matrix)),
+        hex::encode(&expected)
+    );
+
+    // transposed matrix has different dimensions
+    assert_ne!(
+        encode_to_vec(&static_matrix.transpose()),
+        encode_to_vec(&static_matrix)
+    );
+}
+
+#[test]
+fn vectors_and_views() {
+    let expected = encode_matrix(3, 1, &[1, 2, 3]);
+
+    let static_vector = Vector3::new(1_u32, 2, 3);
+    assert_eq!(
+        hex::encode(encode_to_vec(&static_vector)),
+        hex::encode(&expected)
+    );
+
+    let dynamic_vector = DVector::from_vec(vec![1_u32, 2, 3]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&dynamic_vector)),
+        hex::encode(&expected)
+    );
+
+    let matrix = Matrix2x3::new(1_u32, 2, 3, 4, 5, 6);
+    assert_eq!(
+        hex::encode(encode_to_vec(&matrix.row(1))),
+        hex::encode(encode_matrix(1, 3, &[4, 5, 6]))
+    );
+}
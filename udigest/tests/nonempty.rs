@@ -0,0 +1,20 @@
+use nonempty::{nonempty, NonEmpty};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn encoded_as_vec() {
+    let list: NonEmpty<u32> = nonempty![1, 2, 3];
+    assert_eq!(
+        hex::encode(encode_to_vec(&list)),
+        hex::encode(encode_to_vec(&vec![1_u32, 2, 3]))
+    );
+
+    let singleton = NonEmpty::new("abc");
+    assert_eq!(
+        hex::encode(encode_to_vec(&singleton)),
+        hex::encode(encode_to_vec(&vec!["abc"]))
+    );
+}
@@ -0,0 +1,33 @@
+use num_bigint::{BigInt, BigUint};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn big_integers_are_encoded_as_primitive_integers() {
+    let unsigned = [0_u128, 1, 255, 256, u64::MAX.into(), u128::MAX];
+    for x in unsigned {
+        assert_eq!(
+            hex::encode(encode_to_vec(&BigUint::from(x))),
+            hex::encode(encode_to_vec(&x)),
+        );
+    }
+
+    let signed = [
+        0_i128,
+        1,
+        -1,
+        255,
+        -256,
+        i64::MIN.into(),
+        i128::MIN,
+        i128::MAX,
+    ];
+    for x in signed {
+        assert_eq!(
+            hex::encode(encode_to_vec(&BigInt::from(x))),
+            hex::encode(encode_to_vec(&x)),
+        );
+    }
+}
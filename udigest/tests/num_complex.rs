@@ -0,0 +1,14 @@
+use num_complex::Complex;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn complex_is_encoded_as_tuple() {
+    let x = Complex::new(3_i32, -4_i32);
+    assert_eq!(
+        hex::encode(encode_to_vec(&x)),
+        hex::encode(encode_to_vec(&(3_i32, -4_i32)))
+    );
+}
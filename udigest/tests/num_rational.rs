@@ -0,0 +1,41 @@
+use num_rational::Ratio;
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn ratio() {
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("numer", |field| field.encode(&(-2_i64)));
+    encoder.add_field_with("denom", |field| field.encode(&3_i64));
+    encoder.finish();
+    let expected = buffer.0;
+
+    assert_eq!(
+        hex::encode(encode_to_vec(&Ratio::new(-2_i64, 3))),
+        hex::encode(&expected)
+    );
+    // not normalized ratio is encoded in the same way
+    assert_eq!(
+        hex::encode(encode_to_vec(&Ratio::new_raw(4_i64, -6))),
+        hex::encode(&expected)
+    );
+}
+
+#[test]
+fn zero_denominator() {
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("numer", |field| field.encode(&4_i64));
+    encoder.add_field_with("denom", |field| field.encode(&0_i64));
+    encoder.finish();
+
+    // such ratio can't be normalized, it's encoded as is
+    assert_eq!(
+        hex::encode(encode_to_vec(&Ratio::new_raw(4_i64, 0))),
+        hex::encode(buffer.0)
+    );
+}
@@ -0,0 +1,139 @@
+use udigest::decoding::DecodeError;
+use udigest::prefixed::{self, END, LEAF, LEAF_CTX, LIST, LIST_CTX};
+
+#[test]
+fn encode_leaf_and_list() {
+    assert_eq!(
+        prefixed::encode_to_vec(&"ab"),
+        [LEAF, 1, 2, b'a', b'b', END]
+    );
+    assert_eq!(
+        prefixed::encode_to_vec(&vec!["a", "bc"]),
+        [LIST, 1, 2, LEAF, 1, 1, b'a', LEAF, 1, 2, b'b', b'c', END]
+    );
+    assert_eq!(prefixed::encode_to_vec(&Vec::<&str>::new()), [LIST, 0, END]);
+}
+
+#[test]
+fn encode_tagged_values() {
+    #[derive(udigest::Digestable)]
+    #[udigest(tag = "t")]
+    struct Unit {}
+
+    assert_eq!(
+        prefixed::encode_to_vec(&Unit {}),
+        [LIST_CTX, 1, 1, b't', 0, END]
+    );
+
+    let leaf = [LEAF_CTX, 1, 1, b't', 1, 1, b'x', END];
+    let decoded = prefixed::decode(&leaf).unwrap();
+    assert_eq!(decoded.tag(), Some(&b"t"[..]));
+    assert_eq!(decoded.as_leaf().unwrap().bytes(), b"x");
+}
+
+#[test]
+fn roundtrip() {
+    #[derive(udigest::Digestable)]
+    #[udigest(tag = "udigest.example.Person.v1")]
+    struct Person {
+        name: &'static str,
+        #[udigest(as_bytes)]
+        photo: Vec<u8>,
+        skills: Vec<&'static str>,
+    }
+
+    let alice = Person {
+        name: "Alice",
+        photo: vec![0; 300],
+        skills: vec!["math", "crypto"],
+    };
+
+    let bytes = prefixed::encode_to_vec(&alice);
+    let expected_bytes = udigest::encode_to_vec(&alice);
+    let expected = udigest::decoding::decode(&expected_bytes).unwrap();
+    assert_eq!(prefixed::decode(&bytes).unwrap(), expected);
+}
+
+#[test]
+fn deeply_nested_values() {
+    // `[[[...[]...]]]` with 100k nested lists
+    let depth = 100_000;
+    let mut bytes = [LIST, 1, 1].repeat(depth - 1);
+    bytes.extend_from_slice(&[LIST, 0, END]);
+
+    let mut value = &prefixed::decode(&bytes).unwrap();
+    let mut actual_depth = 1;
+    while let [item] = value.as_list().unwrap().items() {
+        value = item;
+        actual_depth += 1;
+    }
+    assert_eq!(actual_depth, depth);
+}
+
+#[test]
+fn differs_from_default_encoding() {
+    let value = vec!["a", "b"];
+    assert_ne!(
+        prefixed::encode_to_vec(&value),
+        udigest::encode_to_vec(&value)
+    );
+    assert!(prefixed::decode(&udigest::encode_to_vec(&value)).is_err());
+}
+
+#[test]
+fn malformed_input() {
+    assert_eq!(prefixed::decode(&[]), Err(DecodeError::UnexpectedEnd));
+    // missing END symbol
+    assert_eq!(
+        prefixed::decode(&[LEAF, 1, 1, b'a']),
+        Err(DecodeError::UnexpectedEnd)
+    );
+    // list has fewer items than announced
+    assert_eq!(
+        prefixed::decode(&[LIST, 1, 2, LEAF, 0, END]),
+        Err(DecodeError::UnknownControlSymbol {
+            offset: 5,
+            symbol: END
+        })
+    );
+    // length with leading zero
+    assert_eq!(
+        prefixed::decode(&[LEAF, 2, 0, 1, b'a', END]),
+        Err(DecodeError::NonCanonicalLength { offset: 1 })
+    );
+    // length doesn't fit into usize
+    assert_eq!(
+        prefixed::decode(&[LEAF, 9, 1, 0, 0, 0, 0, 0, 0, 0, 0, END]),
+        Err(DecodeError::LengthOverflow { offset: 1 })
+    );
+    // huge length
+    assert_eq!(
+        prefixed::decode(&[LEAF, 8, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+        Err(DecodeError::UnexpectedEnd)
+    );
+    assert_eq!(
+        prefixed::decode(&[LEAF, 0, 42, END]),
+        Err(DecodeError::UnknownControlSymbol {
+            offset: 2,
+            symbol: 42
+        })
+    );
+    assert_eq!(
+        prefixed::decode(&[LEAF, 0, END, 1, 2]),
+        Err(DecodeError::TrailingBytes { len: 2 })
+    );
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn hash_differs_from_default() {
+    let value = ("a", "b");
+    assert_eq!(
+        prefixed::hash::<sha2::Sha256>(&value),
+        <sha2::Sha256 as sha2::Digest>::digest(prefixed::encode_to_vec(&value))
+    );
+    assert_ne!(
+        prefixed::hash::<sha2::Sha256>(&value),
+        udigest::hash::<sha2::Sha256>(&value)
+    );
+}
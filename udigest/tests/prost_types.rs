@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+use prost_types::{value::Kind, Any, Duration, ListValue, Struct, Timestamp, Value};
+use udigest::encoding::EncodeValue;
+use udigest::Bytes;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn timestamp_and_duration_are_normalized() {
+    let a = Timestamp {
+        seconds: 10,
+        nanos: 1_500_000_000,
+    };
+    let b = Timestamp {
+        seconds: 11,
+        nanos: 500_000_000,
+    };
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+
+    let a = Duration {
+        seconds: 1,
+        nanos: -500_000_000,
+    };
+    let b = Duration {
+        seconds: 0,
+        nanos: 500_000_000,
+    };
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+}
+
+#[test]
+fn any() {
+    let any = Any {
+        type_url: "type.googleapis.com/foo.Bar".into(),
+        value: vec![1, 2, 3],
+    };
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("type_url", |field| {
+        field.encode(&"type.googleapis.com/foo.Bar")
+    });
+    encoder.add_field_with("value", |field| field.encode(&Bytes([1_u8, 2, 3])));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&any)), hex::encode(buffer.0));
+}
+
+#[test]
+fn struct_value() {
+    let number = Value {
+        kind: Some(Kind::NumberValue(1.0)),
+    };
+    let list = Value {
+        kind: Some(Kind::ListValue(ListValue {
+            values: vec![number.clone(), Value { kind: None }],
+        })),
+    };
+    let value = Struct {
+        fields: BTreeMap::from([
+            ("b".to_string(), number.clone()),
+            ("a".to_string(), list.clone()),
+        ]),
+    };
+
+    let expected = BTreeMap::from([("b", &number), ("a", &list)]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&value)),
+        hex::encode(encode_to_vec(&expected))
+    );
+
+    // value without kind is encoded as `None`
+    assert_eq!(
+        hex::encode(encode_to_vec(&Value { kind: None })),
+        hex::encode(encode_to_vec(&Option::<Value>::None))
+    );
+
+    // number and string holding the same value have different encodings
+    assert_ne!(
+        encode_to_vec(&number),
+        encode_to_vec(&Value {
+            kind: Some(Kind::StringValue("1".into()))
+        })
+    );
+}
@@ -0,0 +1,48 @@
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+use udigest::encoding::{BufferDigest, EncodeList};
+
+fn sequential(items: &[(u64, String)]) -> sha2::digest::Output<Sha256> {
+    let mut hash = BufferDigest(Sha256::new());
+    let mut list = EncodeList::new(&mut hash).with_tag(b"udigest.par_list");
+    for item in items {
+        list.add_leaf().chain(udigest::hash::<Sha256>(item));
+    }
+    list.finish();
+    hash.0.finalize()
+}
+
+#[test]
+fn parallel_hash_is_deterministic() {
+    let records: Vec<(u64, String)> = (0..10_000).map(|i| (i, format!("record {i}"))).collect();
+
+    let hash = udigest::hash_iter_par::<Sha256>(&records);
+    assert_eq!(hash, sequential(&records));
+    assert_eq!(hash, udigest::hash_iter_par::<Sha256>(records.par_iter()));
+    assert_eq!(hash, udigest::hash_iter_par::<Sha256>(records.clone()));
+
+    // Order of the items matters
+    let mut reversed = records.clone();
+    reversed.reverse();
+    assert_ne!(hash, udigest::hash_iter_par::<Sha256>(&reversed));
+}
+
+#[test]
+fn empty_collection() {
+    assert_eq!(
+        udigest::hash_iter_par::<Sha256>(Vec::<(u64, String)>::new()),
+        sequential(&[])
+    );
+}
+
+#[test]
+fn parallel_list_encoding() {
+    let records: Vec<(u64, String)> = (0..10_000).map(|i| (i, format!("record {i}"))).collect();
+
+    let mut bytes = vec![];
+    let mut list = EncodeList::new(&mut bytes);
+    list.extend_par(records.par_iter());
+    list.finish();
+
+    assert_eq!(bytes, udigest::encode_to_vec(&records));
+}
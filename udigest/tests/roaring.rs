@@ -0,0 +1,43 @@
+use std::collections::BTreeSet;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn bitmap_is_encoded_as_btree_set() {
+    let values = [100_000_u32, 5, 1, 70_000, 3];
+
+    let bitmap: roaring::RoaringBitmap = values.into_iter().collect();
+    let expected: BTreeSet<u32> = values.into_iter().collect();
+    assert_eq!(
+        hex::encode(encode_to_vec(&bitmap)),
+        hex::encode(encode_to_vec(&expected))
+    );
+}
+
+#[test]
+fn treemap_is_encoded_as_btree_set() {
+    let values = [u64::MAX, 5, 1, 1 << 40, 3];
+
+    let treemap: roaring::RoaringTreemap = values.into_iter().collect();
+    let expected: BTreeSet<u64> = values.into_iter().collect();
+    assert_eq!(
+        hex::encode(encode_to_vec(&treemap)),
+        hex::encode(encode_to_vec(&expected))
+    );
+}
+
+#[test]
+fn encoding_does_not_depend_on_construction() {
+    // first bitmap goes through a dense container before most of the values are removed
+    let mut shrunk: roaring::RoaringBitmap = (0..10_000_u32).collect();
+    shrunk.remove_range(100..10_000);
+    let direct: roaring::RoaringBitmap = (0..100_u32).rev().collect();
+
+    assert_eq!(shrunk, direct);
+    assert_eq!(
+        hex::encode(encode_to_vec(&shrunk)),
+        hex::encode(encode_to_vec(&direct))
+    );
+}
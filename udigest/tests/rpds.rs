@@ -0,0 +1,40 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn sequences_are_encoded_as_vec() {
+    let expected = encode_to_vec(&vec![1_u32, 2, 3]);
+
+    let list: rpds::List<u32> = [1, 2, 3].into_iter().collect();
+    assert_eq!(hex::encode(encode_to_vec(&list)), hex::encode(&expected));
+
+    let vector: rpds::Vector<u32> = [1, 2, 3].into_iter().collect();
+    assert_eq!(hex::encode(encode_to_vec(&vector)), hex::encode(&expected));
+
+    let stack = rpds::Stack::new().push(3_u32).push(2).push(1);
+    assert_eq!(hex::encode(encode_to_vec(&stack)), hex::encode(&expected));
+
+    let queue = rpds::Queue::new().enqueue(1_u32).enqueue(2).enqueue(3);
+    assert_eq!(hex::encode(encode_to_vec(&queue)), hex::encode(&expected));
+}
+
+#[test]
+fn trees_are_encoded_as_btree_collections() {
+    let map: rpds::RedBlackTreeMap<&str, u32> =
+        [("b", 2), ("a", 1), ("c", 3)].into_iter().collect();
+    let expected = BTreeMap::from([("b", 2_u32), ("a", 1), ("c", 3)]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&map)),
+        hex::encode(encode_to_vec(&expected))
+    );
+
+    let set: rpds::RedBlackTreeSetSync<u32> = [5, 1, 3].into_iter().collect();
+    let expected = BTreeSet::from([5_u32, 1, 3]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&set)),
+        hex::encode(encode_to_vec(&expected))
+    );
+}
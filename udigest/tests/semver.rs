@@ -0,0 +1,59 @@
+use semver::{Version, VersionReq};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn version() {
+    let version: Version = "1.2.0-alpha.1+build.5".parse().unwrap();
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("major", |field| field.encode(&1_u64));
+    encoder.add_field_with("minor", |field| field.encode(&2_u64));
+    encoder.add_field_with("patch", |field| field.encode(&0_u64));
+    encoder.add_field_with("pre", |field| field.encode(&"alpha.1"));
+    encoder.add_field_with("build", |field| field.encode(&"build.5"));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&version)), hex::encode(buffer.0));
+
+    let without_build: Version = "1.2.0".parse().unwrap();
+    let with_build: Version = "1.2.0+build".parse().unwrap();
+    assert_ne!(encode_to_vec(&without_build), encode_to_vec(&with_build));
+}
+
+#[test]
+fn version_req() {
+    let req: VersionReq = "^1.2, <2.0.0-rc".parse().unwrap();
+
+    let mut buffer = VecBuf(vec![]);
+    let mut list = EncodeValue::new(&mut buffer).encode_list();
+    {
+        let mut comparator = list.add_item().encode_struct();
+        // unit variant of an enum is encoded as a struct with a single `variant` field
+        comparator
+            .add_struct_field("op")
+            .add_leaf_field("variant", "Caret");
+        comparator.add_field_with("major", |field| field.encode(&1_u64));
+        comparator.add_field_with("minor", |field| field.encode(&Some(2_u64)));
+        comparator.add_field_with("patch", |field| field.encode(&None::<u64>));
+        comparator.add_field_with("pre", |field| field.encode(&""));
+    }
+    {
+        let mut comparator = list.add_item().encode_struct();
+        // unit variant of an enum is encoded as a struct with a single `variant` field
+        comparator
+            .add_struct_field("op")
+            .add_leaf_field("variant", "Less");
+        comparator.add_field_with("major", |field| field.encode(&2_u64));
+        comparator.add_field_with("minor", |field| field.encode(&Some(0_u64)));
+        comparator.add_field_with("patch", |field| field.encode(&Some(0_u64)));
+        comparator.add_field_with("pre", |field| field.encode(&"rc"));
+    }
+    list.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&req)), hex::encode(buffer.0));
+}
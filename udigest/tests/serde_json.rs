@@ -0,0 +1,67 @@
+use serde_json::{json, Number, Value};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn object_keys_are_sorted() {
+    let a: Value = serde_json::from_str(r#"{"b": 1, "a": "x", "c": [true, null]}"#).unwrap();
+    let b: Value = serde_json::from_str(r#"{"c": [true, null], "a": "x", "b": 1}"#).unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+
+    let c = json!({ "c": [null, true], "a": "x", "b": 1 });
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&c));
+}
+
+#[test]
+fn json_value() {
+    let value = json!("abc");
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer)
+        .encode_enum()
+        .with_variant("String");
+    encoder.add_field_with("0", |field| field.encode(&"abc"));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&value)), hex::encode(buffer.0));
+
+    // string holding a number is not the same as a number
+    assert_ne!(encode_to_vec(&json!("5")), encode_to_vec(&json!(5)));
+}
+
+#[test]
+fn numbers_are_canonicalized() {
+    let integer = {
+        let mut buffer = VecBuf(vec![]);
+        let mut encoder = EncodeValue::new(&mut buffer)
+            .encode_enum()
+            .with_variant("Integer");
+        encoder.add_field_with("0", |field| field.encode(&5_i128));
+        encoder.finish();
+        buffer.0
+    };
+
+    for number in [
+        Number::from(5_u64),
+        Number::from(5_i64),
+        Number::from_f64(5.0).unwrap(),
+        serde_json::from_str("5e0").unwrap(),
+    ] {
+        assert_eq!(hex::encode(encode_to_vec(&number)), hex::encode(&integer));
+    }
+
+    assert_eq!(
+        encode_to_vec(&Number::from_f64(-0.0).unwrap()),
+        encode_to_vec(&Number::from(0))
+    );
+    assert_ne!(
+        encode_to_vec(&Number::from_f64(5.5).unwrap()),
+        encode_to_vec(&Number::from(5))
+    );
+}
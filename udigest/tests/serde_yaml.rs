@@ -0,0 +1,79 @@
+use serde_yaml::{Number, Value};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn mapping_keys_are_sorted() {
+    let a: Value = serde_yaml::from_str("b: 1\na: x\nc: [true, null]").unwrap();
+    let b: Value = serde_yaml::from_str("c:\n  - true\n  - null\na: x\nb: 1").unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+
+    let c: Value = serde_yaml::from_str("c: [null, true]\na: x\nb: 1").unwrap();
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&c));
+}
+
+#[test]
+fn yaml_value() {
+    let value: Value = serde_yaml::from_str("abc").unwrap();
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer)
+        .encode_enum()
+        .with_variant("String");
+    encoder.add_field_with("0", |field| field.encode(&"abc"));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&value)), hex::encode(buffer.0));
+
+    // string holding a number is not the same as a number
+    let string: Value = serde_yaml::from_str("'5'").unwrap();
+    let number: Value = serde_yaml::from_str("5").unwrap();
+    assert_ne!(encode_to_vec(&string), encode_to_vec(&number));
+}
+
+#[test]
+fn numbers_are_canonicalized() {
+    let integer = {
+        let mut buffer = VecBuf(vec![]);
+        let mut encoder = EncodeValue::new(&mut buffer)
+            .encode_enum()
+            .with_variant("Integer");
+        encoder.add_field_with("0", |field| field.encode(&5_i128));
+        encoder.finish();
+        buffer.0
+    };
+
+    for number in [
+        Number::from(5_u64),
+        Number::from(5_i64),
+        Number::from(5.0),
+        serde_yaml::from_str("5.0").unwrap(),
+        serde_yaml::from_str("0x5").unwrap(),
+    ] {
+        assert_eq!(hex::encode(encode_to_vec(&number)), hex::encode(&integer));
+    }
+
+    assert_ne!(
+        encode_to_vec(&Number::from(5.5)),
+        encode_to_vec(&Number::from(5_u64))
+    );
+}
+
+#[test]
+fn tags_are_canonicalized() {
+    let a: Value = serde_yaml::from_str("!Thing 5").unwrap();
+    let b: Value = serde_yaml::from_str("!<!Thing> 5").unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+
+    let untagged: Value = serde_yaml::from_str("5").unwrap();
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&untagged));
+}
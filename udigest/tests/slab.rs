@@ -0,0 +1,22 @@
+use std::collections::BTreeMap;
+
+use slab::Slab;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn slab_is_encoded_as_btree_map() {
+    let mut slab = Slab::new();
+    let a = slab.insert("a");
+    let b = slab.insert("b");
+    let c = slab.insert("c");
+    slab.remove(b);
+
+    let expected = BTreeMap::from([(a, "a"), (c, "c")]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&slab)),
+        hex::encode(encode_to_vec(&expected))
+    );
+}
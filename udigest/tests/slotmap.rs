@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+use slotmap::{DenseSlotMap, Key, SecondaryMap, SlotMap};
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn slot_maps_are_encoded_as_btree_map() {
+    let mut slot_map = SlotMap::new();
+    let mut dense_slot_map = DenseSlotMap::new();
+    let mut secondary_map = SecondaryMap::new();
+    let mut expected = BTreeMap::new();
+
+    for value in ["a", "b", "c"] {
+        let key = slot_map.insert(value);
+        let dense_key = dense_slot_map.insert(value);
+        assert_eq!(key, dense_key);
+        secondary_map.insert(key, value);
+        expected.insert(key.data().as_ffi(), value);
+    }
+
+    // removing an element moves the last element in place of the removed one in the dense
+    // storage, which must not affect the encoding
+    let removed = slot_map.keys().next().unwrap();
+    slot_map.remove(removed);
+    dense_slot_map.remove(removed);
+    secondary_map.remove(removed);
+    expected.remove(&removed.data().as_ffi());
+
+    let expected = hex::encode(encode_to_vec(&expected));
+    assert_eq!(hex::encode(encode_to_vec(&slot_map)), expected);
+    assert_eq!(hex::encode(encode_to_vec(&dense_slot_map)), expected);
+    assert_eq!(hex::encode(encode_to_vec(&secondary_map)), expected);
+}
@@ -0,0 +1,15 @@
+use smol_str::SmolStr;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn smol_str_is_encoded_as_str() {
+    for s in ["", "short", "a string that is too long to be stored inline"] {
+        assert_eq!(
+            hex::encode(encode_to_vec(&SmolStr::new(s))),
+            hex::encode(encode_to_vec(&s))
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use ssh_key::{HashAlg, PublicKey};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+const OPENSSH_KEY: &str =
+    "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAILM+rvN+ot98qgEN796jTiQfZfG1KaT0PtFDJ/XFSqti";
+const WIRE_KEY: &str = "0000000b7373682d6564323535313900000020b33eaef37ea2df7caa010defdea34e241f65f1b529a4f43ed14327f5c54aab62";
+
+#[test]
+fn public_key() {
+    let key = PublicKey::from_openssh(OPENSSH_KEY).unwrap();
+
+    let mut buffer = VecBuf(vec![]);
+    EncodeValue::new(&mut buffer).encode_leaf_value(hex::decode(WIRE_KEY).unwrap());
+
+    assert_eq!(hex::encode(encode_to_vec(&key)), hex::encode(&buffer.0));
+    assert_eq!(
+        hex::encode(encode_to_vec(key.key_data())),
+        hex::encode(&buffer.0)
+    );
+
+    // comment is not digested
+    let commented_key =
+        PublicKey::from_openssh(&format!("{OPENSSH_KEY} user@example.com")).unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&commented_key)),
+        hex::encode(&buffer.0)
+    );
+}
+
+#[test]
+fn fingerprint() {
+    let key = PublicKey::from_openssh(OPENSSH_KEY).unwrap();
+    let fingerprint = key.fingerprint(HashAlg::Sha256);
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_leaf_field("algorithm", "sha256");
+    encoder.add_leaf_field("hash", fingerprint.as_bytes());
+    encoder.finish();
+
+    assert_eq!(
+        hex::encode(encode_to_vec(&fingerprint)),
+        hex::encode(buffer.0)
+    );
+    assert_ne!(
+        encode_to_vec(&fingerprint),
+        encode_to_vec(&key.fingerprint(HashAlg::Sha512))
+    );
+}
@@ -0,0 +1,90 @@
+use udigest::testing::vectors::{to_json, TestVector};
+
+#[derive(udigest::Digestable)]
+#[udigest(tag = "t")]
+struct Person {
+    name: &'static str,
+}
+
+#[test]
+fn export_test_vector() {
+    let alice = Person {
+        name: "Alice Smith",
+    };
+    let vector = TestVector::new("alice", &alice).with_digest::<sha2::Sha256>("sha256");
+
+    assert_eq!(vector.name(), "alice");
+    assert_eq!(vector.encoding(), udigest::encode_to_vec(&alice));
+    let digests = vector.digests().collect::<Vec<_>>();
+    assert_eq!(digests.len(), 1);
+    assert_eq!(digests[0].0, "sha256");
+    assert_eq!(
+        digests[0].1,
+        udigest::hash::<sha2::Sha256>(&alice).as_slice()
+    );
+
+    let expected = format!(
+        r#"{{
+  "name": "alice",
+  "encoding": "{encoding}",
+  "digests": {{
+    "sha256": "{digest}"
+  }},
+  "tree": {{"tag": "74", "list": [{{"leaf": "6e616d65"}}, {{"leaf": "416c69636520536d697468"}}]}},
+  "inspect": "{inspect}"
+}}"#,
+        encoding = hex::encode(udigest::encode_to_vec(&alice)),
+        digest = hex::encode(udigest::hash::<sha2::Sha256>(&alice)),
+        inspect = r#"#\"t\" {name: \"Alice Smith\"}"#,
+    );
+    assert_eq!(vector.to_json(), expected);
+}
+
+#[test]
+fn export_several_test_vectors() {
+    let vectors = [
+        TestVector::new("a", &"a").with_digest_bytes("custom", vec![1, 2]),
+        TestVector::new("list", &Vec::<&str>::new()),
+    ];
+    let expected = r#"[
+  {
+    "name": "a",
+    "encoding": "61000000010503",
+    "digests": {
+      "custom": "0102"
+    },
+    "tree": {"leaf": "61"},
+    "inspect": "\"a\""
+  },
+  {
+    "name": "list",
+    "encoding": "000000000501",
+    "digests": {},
+    "tree": {"list": []},
+    "inspect": "[]"
+  }
+]
+"#;
+    assert_eq!(to_json(&vectors), expected);
+    assert_eq!(to_json(&[]), "[]\n");
+}
+
+#[test]
+fn escape_strings() {
+    let json = TestVector::new("\"quoted\"\n\\ \u{1}", &"").to_json();
+    assert!(json.contains(r#""name": "\"quoted\"\n\\ \u0001""#));
+}
+
+#[test]
+fn nested_lists_tree() {
+    let value: Vec<Vec<&str>> = vec![vec!["a", "b"], vec![], vec!["c"]];
+    let json = TestVector::new("nested", &value).to_json();
+    let tree = json
+        .lines()
+        .find_map(|line| line.strip_prefix("  \"tree\": "))
+        .unwrap();
+    assert_eq!(
+        tree,
+        r#"{"list": [{"list": [{"leaf": "61"}, {"leaf": "62"}]}, {"list": []}, {"list": [{"leaf": "63"}]}]},"#
+    );
+}
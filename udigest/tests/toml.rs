@@ -0,0 +1,56 @@
+use toml::{Table, Value};
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn table_keys_are_sorted() {
+    let a: Table = toml::from_str("b = 1\na = 'x'\n[c]\nd = [true, false]").unwrap();
+    let b: Table = toml::from_str("a = 'x'\nc = { d = [true, false] }\nb = 1").unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+
+    let c: Table = toml::from_str("a = 'x'\nc = { d = [false, true] }\nb = 1").unwrap();
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&c));
+}
+
+#[test]
+fn toml_value() {
+    let value = Value::Integer(5);
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer)
+        .encode_enum()
+        .with_variant("Integer");
+    encoder.add_field_with("0", |field| field.encode(&5_i64));
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&value)), hex::encode(buffer.0));
+
+    // integers and floats holding the same number have different encodings
+    assert_ne!(
+        encode_to_vec(&Value::Integer(5)),
+        encode_to_vec(&Value::Float(5.0))
+    );
+}
+
+#[test]
+fn datetime_is_canonicalized() {
+    let a: Table = toml::from_str("t = 1979-05-27T07:32:00.5Z").unwrap();
+    let b: Table = toml::from_str("t = 1979-05-27 07:32:00.500+00:00").unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&a)),
+        hex::encode(encode_to_vec(&b))
+    );
+
+    // local datetime is not the same as datetime in UTC
+    let c: Table = toml::from_str("t = 1979-05-27T07:32:00.5").unwrap();
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&c));
+
+    let d: Table = toml::from_str("t = 1979-05-27T07:32:00.5+01:00").unwrap();
+    assert_ne!(encode_to_vec(&a), encode_to_vec(&d));
+}
@@ -0,0 +1,23 @@
+use udigest::encoding::EncodeValue;
+use udigest::{Bytes, DigestAs};
+use ulid::Ulid;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+#[test]
+fn ulid() {
+    let id: Ulid = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse().unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(&id)),
+        hex::encode(encode_to_vec(&Bytes(id.0.to_be_bytes())))
+    );
+
+    let mut buffer = VecBuf(vec![]);
+    udigest::as_::Display::digest_as(&id, EncodeValue::new(&mut buffer));
+    assert_eq!(
+        hex::encode(buffer.0),
+        hex::encode(encode_to_vec(&"01ARZ3NDEKTSV4RRFFQ69G5FAV"))
+    );
+}
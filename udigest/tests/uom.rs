@@ -0,0 +1,56 @@
+use udigest::encoding::EncodeValue;
+use uom::si::f64::{Length, Time, Velocity};
+use uom::si::{length, time, velocity};
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+fn encode_quantity(value: f64, dimension: [i64; 7]) -> Vec<u8> {
+    let [length, mass, time, electric_current, temperature, amount, intensity] = dimension;
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_field_with("value", |field| field.encode(&value));
+    let mut dimension = encoder.add_struct_field("dimension");
+    dimension.add_field_with("length", |field| field.encode(&length));
+    dimension.add_field_with("mass", |field| field.encode(&mass));
+    dimension.add_field_with("time", |field| field.encode(&time));
+    dimension.add_field_with("electric_current", |field| field.encode(&electric_current));
+    dimension.add_field_with("thermodynamic_temperature", |field| {
+        field.encode(&temperature)
+    });
+    dimension.add_field_with("amount_of_substance", |field| field.encode(&amount));
+    dimension.add_field_with("luminous_intensity", |field| field.encode(&intensity));
+    dimension.finish();
+    encoder.finish();
+    buffer.0
+}
+
+#[test]
+fn quantity() {
+    let length = Length::new::<length::kilometer>(3.0);
+    assert_eq!(
+        hex::encode(encode_to_vec(&length)),
+        hex::encode(encode_quantity(3000.0, [1, 0, 0, 0, 0, 0, 0]))
+    );
+
+    let velocity = Velocity::new::<velocity::meter_per_second>(2.0);
+    assert_eq!(
+        hex::encode(encode_to_vec(&velocity)),
+        hex::encode(encode_quantity(2.0, [1, 0, -1, 0, 0, 0, 0]))
+    );
+}
+
+#[test]
+fn dimensions_do_not_collide() {
+    let length = Length::new::<length::meter>(3.0);
+    let time = Time::new::<time::second>(3.0);
+    assert_ne!(encode_to_vec(&length), encode_to_vec(&time));
+
+    // the same quantity written in different units
+    assert_eq!(
+        hex::encode(encode_to_vec(&Length::new::<length::kilometer>(1.0))),
+        hex::encode(encode_to_vec(&Length::new::<length::meter>(1000.0)))
+    );
+}
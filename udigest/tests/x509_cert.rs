@@ -0,0 +1,101 @@
+use der::Decode;
+use udigest::encoding::EncodeValue;
+use x509_cert::{spki::SubjectPublicKeyInfoOwned, Certificate};
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+/// Self-signed Ed25519 certificate with `CN=udigest`
+const CERTIFICATE: &str = "308201383081eba003020102021467aae566a7d03bdb050e038c611b0bf98b317ec9300506032b657030123110300e06035504030c0775646967657374301e170d3236313031363135333432325a170d3336313031333135333432325a30123110300e06035504030c0775646967657374302a300506032b6570032100882f022952b346c5f03341cc07c229024e93f510cdd464478f8d0c276fe7ee9ba3533051301d0603551d0e04160414c54e5be981b7a2e5dd5e7dd759e07c4069eb32f8301f0603551d23041830168014c54e5be981b7a2e5dd5e7dd759e07c4069eb32f8300f0603551d130101ff040530030101ff300506032b6570034100a24b5bc35fd870b34c3a577b7dbfe8bfd258439815baf5eacafb85703aa6161bbbb5cb08c2e2fbb9137e7a83faa4ab1f6a762f9994e23cc2e61844545053600c";
+/// Public key info of the certificate above
+const PUBLIC_KEY_INFO: &str =
+    "302a300506032b6570032100882f022952b346c5f03341cc07c229024e93f510cdd464478f8d0c276fe7ee9b";
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut buffer = VecBuf(vec![]);
+    EncodeValue::new(&mut buffer).encode_leaf_value(bytes);
+    buffer.0
+}
+
+#[test]
+fn certificate() {
+    let der = hex::decode(CERTIFICATE).unwrap();
+    let certificate = Certificate::from_der(&der).unwrap();
+
+    assert_eq!(
+        hex::encode(encode_to_vec(&certificate)),
+        hex::encode(encode_bytes(&der))
+    );
+}
+
+#[test]
+fn public_key_info() {
+    let certificate = Certificate::from_der(&hex::decode(CERTIFICATE).unwrap()).unwrap();
+    let spki = &certificate.tbs_certificate.subject_public_key_info;
+
+    let der = hex::decode(PUBLIC_KEY_INFO).unwrap();
+    assert_eq!(
+        hex::encode(encode_to_vec(spki)),
+        hex::encode(encode_bytes(&der))
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(
+            &SubjectPublicKeyInfoOwned::from_der(&der).unwrap()
+        )),
+        hex::encode(encode_bytes(&der))
+    );
+}
+
+#[test]
+fn der_rule() {
+    #[derive(udigest::Digestable)]
+    struct Record {
+        #[udigest(as = udigest::as_::Der)]
+        serial_number: x509_cert::serial_number::SerialNumber,
+    }
+
+    let certificate = Certificate::from_der(&hex::decode(CERTIFICATE).unwrap()).unwrap();
+    let record = Record {
+        serial_number: certificate.tbs_certificate.serial_number.clone(),
+    };
+
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_leaf_field(
+        "serial_number",
+        der::Encode::to_der(&record.serial_number).unwrap(),
+    );
+    encoder.finish();
+
+    assert_eq!(hex::encode(encode_to_vec(&record)), hex::encode(buffer.0));
+}
+
+#[test]
+fn der_rule_error() {
+    /// Fails to encode after writing the first byte
+    struct Broken;
+    impl der::Encode for Broken {
+        fn encoded_len(&self) -> der::Result<der::Length> {
+            Ok(der::Length::new(2))
+        }
+        fn encode(&self, writer: &mut impl der::Writer) -> der::Result<()> {
+            writer.write_byte(0x05)?;
+            Err(der::ErrorKind::Failed.into())
+        }
+    }
+
+    let mut buffer = VecBuf(vec![]);
+    <udigest::as_::Der as udigest::DigestAs<Broken>>::digest_as(
+        &Broken,
+        EncodeValue::new(&mut buffer),
+    );
+    // the error never collides with a successfully encoded value
+    let mut expected = VecBuf(vec![]);
+    EncodeValue::new(&mut expected)
+        .encode_leaf()
+        .with_tag(b"udigest.der_error")
+        .chain([0x05]);
+
+    assert_eq!(hex::encode(buffer.0), hex::encode(expected.0));
+}
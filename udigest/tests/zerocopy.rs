@@ -0,0 +1,43 @@
+use udigest::encoding::EncodeValue;
+
+use common::{encode_to_vec, VecBuf};
+
+mod common;
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut buffer = VecBuf(vec![]);
+    let mut encoder = EncodeValue::new(&mut buffer).encode_struct();
+    encoder.add_leaf_field("values", bytes);
+    encoder.finish();
+    buffer.0
+}
+
+fn native_bytes(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_ne_bytes()).collect()
+}
+
+#[test]
+fn zerocopy_rules() {
+    #[derive(udigest::Digestable)]
+    struct Array {
+        #[udigest(as = udigest::as_::ZerocopyBytes)]
+        values: [u32; 3],
+    }
+    #[derive(udigest::Digestable)]
+    struct List {
+        #[udigest(as = udigest::as_::ZerocopySlice)]
+        values: Vec<u32>,
+    }
+
+    let expected = hex::encode(encode_bytes(&native_bytes(&[1, 2, 3])));
+    assert_eq!(
+        hex::encode(encode_to_vec(&Array { values: [1, 2, 3] })),
+        expected
+    );
+    assert_eq!(
+        hex::encode(encode_to_vec(&List {
+            values: vec![1, 2, 3]
+        })),
+        expected
+    );
+}
@@ -0,0 +1,105 @@
+use zeroize::Zeroizing;
+
+use common::encode_to_vec;
+
+mod common;
+
+#[test]
+fn zeroizing_is_transparent() {
+    let secret = Zeroizing::new([1_u8, 2, 3]);
+    assert_eq!(
+        hex::encode(encode_to_vec(&secret)),
+        hex::encode(encode_to_vec(&[1_u8, 2, 3]))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn zeroizing_vec() {
+    use udigest::encoding::{EncodeValue, ZeroizingVec};
+    use udigest::Digestable;
+
+    let value = ("key share", udigest::Bytes([42_u8; 100]), vec![1_u32, 2, 3]);
+
+    // small initial capacity forces the buffer to reallocate several times
+    let mut buffer = ZeroizingVec::with_capacity(1);
+    value.unambiguously_encode(EncodeValue::new(&mut buffer));
+    assert_eq!(buffer.as_slice(), encode_to_vec(&value));
+
+    let encoding: Zeroizing<Vec<u8>> = buffer.into_zeroizing();
+    assert_eq!(*encoding, encode_to_vec(&value));
+}
+
+#[test]
+fn zeroize_slice_buffer() {
+    use udigest::encoding::SliceBuffer;
+    use zeroize::Zeroize;
+
+    let mut bytes = [0xff_u8; 16];
+    {
+        let mut buffer = SliceBuffer::new(&mut bytes);
+        udigest::try_encode(&"abc", &mut buffer).unwrap();
+        buffer.zeroize();
+        assert!(buffer.is_empty());
+    }
+    assert_eq!(bytes[..9], [0; 9]);
+    // bytes beyond the encoding are untouched
+    assert_eq!(bytes[9..], [0xff; 7]);
+}
+
+/// FNV-1a hash function that wipes its state on drop
+#[cfg(feature = "digest")]
+struct WipedFnv(u64);
+
+#[cfg(feature = "digest")]
+mod wiped_fnv {
+    use super::WipedFnv;
+
+    impl Default for WipedFnv {
+        fn default() -> Self {
+            Self(0xcbf29ce484222325)
+        }
+    }
+
+    impl digest::HashMarker for WipedFnv {}
+
+    impl digest::OutputSizeUser for WipedFnv {
+        type OutputSize = digest::consts::U8;
+    }
+
+    impl digest::Update for WipedFnv {
+        fn update(&mut self, data: &[u8]) {
+            for &byte in data {
+                self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    impl digest::FixedOutput for WipedFnv {
+        fn finalize_into(self, out: &mut digest::Output<Self>) {
+            out.copy_from_slice(&self.0.to_be_bytes())
+        }
+    }
+
+    impl Drop for WipedFnv {
+        fn drop(&mut self) {
+            zeroize::Zeroize::zeroize(&mut self.0)
+        }
+    }
+
+    impl zeroize::ZeroizeOnDrop for WipedFnv {}
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn hash_secret() {
+    let secret = Zeroizing::new([1_u8, 2, 3]);
+    assert_eq!(
+        udigest::hash_secret::<WipedFnv>(&secret),
+        udigest::hash::<WipedFnv>(&[1_u8, 2, 3])
+    );
+    assert_ne!(
+        udigest::hash_secret::<WipedFnv>(&secret),
+        udigest::hash_secret::<WipedFnv>(&[1_u8, 2, 4])
+    );
+}